@@ -1,11 +1,18 @@
 use super::{
     config::{
         Config,
+        InlineKind,
         ReprKind,
     },
     field_config::{
+        AccessKind,
         FieldConfig,
+        FlattenedField,
+        OverflowKind,
         SkipWhich,
+        Validator,
+        Variant,
+        VariantsConfig,
     },
     BitfieldStruct,
 };
@@ -16,17 +23,20 @@ use std::collections::HashMap;
 use syn::{
     self,
     parse::Result,
+    punctuated::Punctuated,
     spanned::Spanned as _,
 };
 
 impl TryFrom<(&mut Config, syn::ItemStruct)> for BitfieldStruct {
     type Error = syn::Error;
 
-    fn try_from((config, item_struct): (&mut Config, syn::ItemStruct)) -> Result<Self> {
+    fn try_from((config, mut item_struct): (&mut Config, syn::ItemStruct)) -> Result<Self> {
         Self::ensure_has_fields(&item_struct)?;
         Self::ensure_no_generics(&item_struct)?;
+        Self::extract_marker_fields(&mut item_struct, config)?;
         Self::extract_attributes(&item_struct.attrs, config)?;
         Self::analyse_config_for_fields(&item_struct, config)?;
+        Self::narrow_primitive_fields(&mut item_struct, config);
         config.ensure_no_conflicts()?;
         Ok(Self { item_struct })
     }
@@ -44,6 +54,74 @@ impl BitfieldStruct {
         Ok(())
     }
 
+    /// Removes the zero-sized marker fields from the bit layout of the `#[bitfield]` struct.
+    ///
+    /// Marker fields are `PhantomData<T>` fields and named fields of unit struct
+    /// types annotated with `#[marker]`. They are re-expanded into the generated struct.
+    ///
+    /// # Errors
+    ///
+    /// - If a `#[marker]` field is not of a unit struct type.
+    /// - If no fields remain after removing the marker fields.
+    fn extract_marker_fields(item_struct: &mut syn::ItemStruct, config: &mut Config) -> Result<()> {
+        let fields = match &mut item_struct.fields {
+            syn::Fields::Named(fields) => fields,
+            _ => return Ok(()),
+        };
+        let mut retained = Punctuated::new();
+        for mut field in core::mem::take(&mut fields.named) {
+            let marker_attr = field.attrs.iter().position(|attr| attr.path.is_ident("marker"));
+            if let Some(index) = marker_attr {
+                let attr = field.attrs.remove(index);
+                if !attr.tokens.is_empty() {
+                    return Err(format_err_spanned!(
+                        attr,
+                        "encountered invalid #[marker] attribute with arguments"
+                    ))
+                }
+                let init = match &field.ty {
+                    syn::Type::Path(path) if path.qself.is_none() => {
+                        syn::Expr::Path(syn::ExprPath {
+                            attrs: Vec::new(),
+                            qself: None,
+                            path: path.path.clone(),
+                        })
+                    }
+                    ty => {
+                        return Err(format_err_spanned!(
+                            ty,
+                            "encountered invalid #[marker] field: expected a unit struct type"
+                        ))
+                    }
+                };
+                config.push_marker_field(field, init);
+                continue
+            }
+            let is_phantom_data = match &field.ty {
+                syn::Type::Path(path) if path.qself.is_none() => {
+                    path.path
+                        .segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == "PhantomData")
+                }
+                _ => false,
+            };
+            if is_phantom_data {
+                config.push_marker_field(field, syn::parse_quote!(::core::marker::PhantomData));
+                continue
+            }
+            retained.push(field);
+        }
+        if retained.is_empty() {
+            return Err(format_err_spanned!(
+                fields,
+                "encountered invalid bitfield struct without fields besides marker fields"
+            ))
+        }
+        fields.named = retained;
+        Ok(())
+    }
+
     /// Returns an error if the input struct is generic.
     fn ensure_no_generics(item_struct: &syn::ItemStruct) -> Result<()> {
         if !item_struct.generics.params.is_empty() {
@@ -121,6 +199,9 @@ impl BitfieldStruct {
                 syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
                     if path.is_ident("Debug") {
                         config.derive_debug(meta_span)?;
+                    } else if path.is_ident("Default") && config.default.is_some() {
+                        // The derived `Default` would not respect the reset value.
+                        config.derive_default(meta_span)?;
                     } else if path.is_ident("BitfieldSpecifier") {
                         config.derive_specifier(meta_span)?;
                     } else {
@@ -172,15 +253,408 @@ impl BitfieldStruct {
         item_struct: &syn::ItemStruct,
         config: &mut Config,
     ) -> Result<()> {
+        let mut names = HashMap::new();
         for (index, field) in Self::fields(item_struct) {
             let span = field.span();
-            let field_config = Self::extract_field_config(field)?;
+            let mut field_config = Self::extract_field_config(field)?;
+            if Self::is_wildcard(field) && field_config.skip.is_none() {
+                // Wildcard fields are anonymous padding and thus implicitly `#[skip]`.
+                field_config.skip(SkipWhich::All, span)?;
+            }
+            if let Some(target) = field_config.overlapped_field() {
+                Self::ensure_valid_overlap(target, &names)?;
+            }
+            Self::ensure_unsigned_on_overflow(field, &field_config)?;
+            if let Some(ident) = &field.ident {
+                names.insert(ident.to_string(), field_config.overlapped_field().is_some());
+            }
             config.field_config(index, span, field_config)?;
         }
+        Self::ensure_valid_variant_tags(item_struct, config)?;
+        Self::ensure_valid_flags_group(item_struct, config)?;
+        Self::ensure_no_groups_with_volatile_cell(config)?;
+        Self::ensure_valid_ord_by(item_struct, config)?;
+        Self::ensure_no_take_collisions(item_struct, config)?;
+        Self::ensure_valid_export_c(item_struct, config)?;
+        Ok(())
+    }
+
+    /// Ensures that all `pub` fields exported by `export_c` have FFI-safe in-out types.
+    ///
+    /// Only `bool`, `u8` to `u64` and `B1` to `B64` fields are supported since their in-out
+    /// types can be passed by value to and from C.
+    fn ensure_valid_export_c(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        if !config.export_c_enabled() {
+            return Ok(())
+        }
+        for (index, field) in Self::fields(item_struct) {
+            if !matches!(field.vis, syn::Visibility::Public(_)) {
+                continue
+            }
+            let skip_all = config
+                .field_configs
+                .get(&index)
+                .map(|field_config| field_config.value.skip_all())
+                .unwrap_or(false);
+            if skip_all {
+                continue
+            }
+            let ident = match &field.ty {
+                syn::Type::Path(path) if path.qself.is_none() => {
+                    path.path.get_ident().map(|ident| ident.to_string())
+                }
+                _ => None,
+            };
+            let ffi_safe = match ident.as_deref() {
+                Some("bool") | Some("u8") | Some("u16") | Some("u32") | Some("u64") => true,
+                Some(ident) if ident.len() > 1 && ident.starts_with('B') => {
+                    ident[1..].parse::<usize>().is_ok_and(|bits| (1..=64).contains(&bits))
+                }
+                _ => false,
+            };
+            if !ffi_safe {
+                return Err(format_err!(
+                    field.ty,
+                    "encountered `export_c` field of a type other than `bool`, `u8` to `u64` or `B1` to `B64`"
+                ))
+            }
+        }
         Ok(())
     }
 
-    /// Extracts the `#[bits = N]` and `#[skip(..)]` attributes for a given field.
+    /// Ensures that no field is named `take_f` if the `take_f` accessor is generated for
+    /// another field `f` since it would collide with the getter of the former.
+    fn ensure_no_take_collisions(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        if config.no_panic_enabled() {
+            return Ok(())
+        }
+        let field_config = |index: usize| config.field_configs.get(&index).map(|config| &config.value);
+        for (index, field) in Self::fields(item_struct) {
+            let ident = match &field.ident {
+                Some(ident) => ident,
+                None => continue,
+            };
+            if field_config(index).is_some_and(FieldConfig::skip_getters) {
+                continue
+            }
+            let name = ident.to_string();
+            let taken = match name.strip_prefix("take_") {
+                Some(taken) => taken,
+                None => continue,
+            };
+            let has_take_accessor = Self::fields(item_struct)
+                .find(|(_, field)| field.ident.as_ref().is_some_and(|ident| ident == taken))
+                .is_some_and(|(index, _)| {
+                    !field_config(index)
+                        .is_some_and(|config| config.skip_getters() || config.skip_setters())
+                });
+            if has_take_accessor {
+                return Err(format_err!(
+                    field,
+                    "encountered field `{}` colliding with the generated `take_{}` accessor of field `{}`",
+                    name,
+                    taken,
+                    taken
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that every field given to the `ord_by` parameter is a field of the struct
+    /// without a `#[cfg(..)]` attribute.
+    fn ensure_valid_ord_by(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let ord_by = match &config.ord_by {
+            Some(ord_by) => &ord_by.value,
+            None => return Ok(()),
+        };
+        for name in ord_by {
+            let field = Self::fields(item_struct)
+                .map(|(_, field)| field)
+                .find(|field| field.ident.as_ref() == Some(name));
+            match field {
+                None => {
+                    return Err(format_err!(
+                        name,
+                        "encountered `ord_by` referring to unknown field `{}`",
+                        name
+                    ))
+                }
+                Some(field) if field.attrs.iter().any(|attr| attr.path.is_ident("cfg")) => {
+                    return Err(format_err!(
+                        name,
+                        "encountered `ord_by` referring to field `{}` with a #[cfg(..)] attribute",
+                        name
+                    ))
+                }
+                Some(_) => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that no field has a `#[group = ".."]` attribute if `cell = "volatile"` is set.
+    fn ensure_no_groups_with_volatile_cell(config: &Config) -> Result<()> {
+        if !config.volatile_cell() {
+            return Ok(())
+        }
+        let group = config
+            .field_configs
+            .iter()
+            .filter_map(|(index, field_config)| Some((index, field_config.value.group.as_ref()?)))
+            .min_by_key(|(index, _)| **index)
+            .map(|(_, group)| group);
+        match group {
+            Some(group) => {
+                Err(format_err!(
+                    group.span,
+                    "encountered #[group = \"..\"] field in a bitfield with `cell = \"volatile\"`"
+                ))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Replaces the types of primitive integer fields annotated with a narrower `#[bits = N]`
+    /// by a specifier storing only `N` bits while keeping the primitive in the accessors.
+    ///
+    /// E.g. `#[bits = 3] f: u8` is treated like `f: NarrowedPrimitive<u8, 3>`.
+    fn narrow_primitive_fields(item_struct: &mut syn::ItemStruct, config: &Config) {
+        for (index, field) in item_struct.fields.iter_mut().enumerate() {
+            let bits = match config
+                .field_configs
+                .get(&index)
+                .and_then(|config| config.value.bits.as_ref())
+            {
+                Some(bits) => bits.value,
+                None => continue,
+            };
+            let prim_bits = match &field.ty {
+                syn::Type::Path(path) if path.qself.is_none() => {
+                    match path.path.get_ident().map(ToString::to_string).as_deref() {
+                        Some("u8") => 8,
+                        Some("u16") => 16,
+                        Some("u32") => 32,
+                        Some("u64") => 64,
+                        Some("u128") => 128,
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            if bits >= prim_bits {
+                // Wider `#[bits = N]` are reported by the regular bit width checks.
+                continue
+            }
+            let prim = &field.ty;
+            field.ty = syn::parse_quote!(
+                ::modular_bitfield::private::NarrowedPrimitive<#prim, #bits>
+            );
+        }
+    }
+
+    /// Ensures that the `#[flags]` fields are at most 128 named `bool` fields declared in a
+    /// contiguous run.
+    fn ensure_valid_flags_group(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let mut previous_index = None;
+        let mut count = 0;
+        for (index, field) in Self::fields(item_struct) {
+            let field_config = match config.field_configs.get(&index) {
+                Some(field_config) => &field_config.value,
+                None => continue,
+            };
+            let span = match &field_config.flags {
+                Some(flags) => flags.span,
+                None => continue,
+            };
+            if config.volatile_cell() {
+                return Err(format_err!(
+                    span,
+                    "encountered #[flags] field in a bitfield with `cell = \"volatile\"`"
+                ))
+            }
+            let is_bool = match &field.ty {
+                syn::Type::Path(path) => path.qself.is_none() && path.path.is_ident("bool"),
+                _ => false,
+            };
+            if !is_bool || field.ident.is_none() {
+                return Err(format_err!(
+                    field,
+                    "encountered #[flags] on a field that is not a named `bool` field"
+                ))
+            }
+            if field_config.overlapped_field().is_some() {
+                return Err(format_err!(
+                    span,
+                    "encountered #[flags] on a field that overlaps another field"
+                ))
+            }
+            if field.attrs.iter().any(|attr| attr.path.is_ident("cfg")) {
+                return Err(format_err!(
+                    span,
+                    "encountered #[flags] on a field with a #[cfg(..)] attribute"
+                ))
+            }
+            if previous_index.is_some_and(|previous| previous + 1 != index) {
+                return Err(format_err!(
+                    span,
+                    "encountered #[flags] field that is not adjacent to the other #[flags] fields"
+                ))
+            }
+            previous_index = Some(index);
+            count += 1;
+            if count > 128 {
+                return Err(format_err!(
+                    span,
+                    "encountered more than 128 #[flags] fields"
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that `#[on_overflow = "saturate"]` and `#[on_overflow = "truncate"]` are only
+    /// used on `B1` to `B128` or `u8` to `u128` fields since both clamp or mask the primitive
+    /// integer given to the setter.
+    fn ensure_unsigned_on_overflow(field: &syn::Field, field_config: &FieldConfig) -> Result<()> {
+        let (policy, span) = match &field_config.on_overflow {
+            Some(on_overflow) => {
+                match on_overflow.value {
+                    OverflowKind::Error => return Ok(()),
+                    OverflowKind::Saturate => ("saturate", on_overflow.span),
+                    OverflowKind::Truncate => ("truncate", on_overflow.span),
+                }
+            }
+            None => return Ok(()),
+        };
+        let ident = match &field.ty {
+            syn::Type::Path(path) if path.qself.is_none() => {
+                path.path.segments.last().map(|segment| segment.ident.to_string())
+            }
+            _ => None,
+        };
+        let unsigned = match ident.as_deref() {
+            Some("u8") | Some("u16") | Some("u32") | Some("u64") | Some("u128") => true,
+            Some(ident) if ident.len() > 1 && ident.starts_with('B') => {
+                ident[1..].parse::<usize>().is_ok_and(|bits| (1..=128).contains(&bits))
+            }
+            _ => false,
+        };
+        if !unsigned {
+            return Err(format_err!(
+                span,
+                "encountered #[on_overflow = \"{}\"] on a field that is not of an unsigned integer type",
+                policy,
+            ))
+        }
+        Ok(())
+    }
+
+    /// Ensures that the tag of every `#[variant(..)]` attribute names another field of the struct.
+    fn ensure_valid_variant_tags(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let names = Self::fields(item_struct)
+            .filter_map(|(_, field)| field.ident.as_ref().map(ToString::to_string))
+            .collect::<Vec<_>>();
+        for (index, field) in Self::fields(item_struct) {
+            let variants = match config
+                .field_configs
+                .get(&index)
+                .and_then(|config| config.value.variants.as_ref())
+            {
+                Some(variants) => &variants.value,
+                None => continue,
+            };
+            let tag = variants.tag.value();
+            if field.ident.as_ref().is_some_and(|ident| *ident == tag) {
+                return Err(format_err!(
+                    variants.tag,
+                    "encountered #[variant(..)] using its own field `{}` as tag",
+                    tag
+                ))
+            }
+            if !names.contains(&tag) {
+                return Err(format_err!(
+                    variants.tag,
+                    "encountered #[variant(..)] referring to unknown tag field `{}`",
+                    tag
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the arguments of a `#[variant(tag = "t", 0 => A, 1 => B)]` field attribute.
+    fn parse_variant_args(input: syn::parse::ParseStream) -> Result<VariantsConfig> {
+        let tag_ident: syn::Ident = input.parse()?;
+        if tag_ident != "tag" {
+            return Err(format_err!(
+                tag_ident,
+                "expected `tag = \"..\"` as first argument of #[variant(..)]"
+            ))
+        }
+        input.parse::<syn::Token![=]>()?;
+        let tag: syn::LitStr = input.parse()?;
+        let mut variants = Vec::new();
+        while !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+            if input.is_empty() {
+                break
+            }
+            let tag_value: syn::LitInt = input.parse()?;
+            input.parse::<syn::Token![=>]>()?;
+            let specifier: syn::Path = input.parse()?;
+            variants.push(Variant {
+                tag_value: tag_value.base10_parse::<u128>()?,
+                specifier,
+            });
+        }
+        if variants.is_empty() {
+            return Err(format_err!(
+                tag,
+                "expected at least one `N => Specifier` variant in #[variant(..)]"
+            ))
+        }
+        Ok(VariantsConfig { tag, variants })
+    }
+
+    /// Returns `true` if the name of the field consists only of underscores, e.g. `__`.
+    ///
+    /// Rust does not accept a single `_` as field name.
+    fn is_wildcard(field: &syn::Field) -> bool {
+        field
+            .ident
+            .as_ref()
+            .map(|ident| ident.to_string().chars().all(|c| c == '_'))
+            .unwrap_or(false)
+    }
+
+    /// Ensures that the target of an `#[overlaps(f)]` attribute is a previously declared
+    /// field that does not overlap another field itself.
+    ///
+    /// The given map stores for all previously declared named fields whether they overlap another field.
+    fn ensure_valid_overlap(target: &syn::Ident, names: &HashMap<String, bool>) -> Result<()> {
+        match names.get(&target.to_string()) {
+            Some(false) => Ok(()),
+            Some(true) => {
+                Err(format_err!(
+                    target,
+                    "encountered #[overlaps(..)] referring to field `{}` that overlaps another field itself",
+                    target
+                ))
+            }
+            None => {
+                Err(format_err!(
+                    target,
+                    "encountered #[overlaps(..)] referring to unknown or later declared field `{}`",
+                    target
+                ))
+            }
+        }
+    }
+
+    /// Extracts the `#[bits = N]`, `#[skip(..)]`, `#[inline(..)]`, `#[access(..)]`,
+    /// `#[validate(..)]`, `#[on_overflow = ".."]`, `#[overlaps(..)]`, `#[variant(..)]`,
+    /// `#[flatten(..)]`, `#[flags]` and `#[group = ".."]` attributes for a given field.
     fn extract_field_config(field: &syn::Field) -> Result<FieldConfig> {
         let mut config = FieldConfig::default();
         for attr in &field.attrs {
@@ -201,6 +675,237 @@ impl BitfieldStruct {
                         ))
                     }
                 }
+            } else if attr.path.is_ident("inline") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let meta: syn::Meta = syn::parse2::<_>(quote! { #path #args })?;
+                let span = meta.span();
+                let kind = match meta {
+                    syn::Meta::Path(_) => InlineKind::Hint,
+                    syn::Meta::List(meta_list) if meta_list.nested.len() == 1 => {
+                        match &meta_list.nested[0] {
+                            syn::NestedMeta::Meta(syn::Meta::Path(path))
+                                if path.is_ident("always") =>
+                            {
+                                InlineKind::Always
+                            }
+                            syn::NestedMeta::Meta(syn::Meta::Path(path))
+                                if path.is_ident("never") =>
+                            {
+                                InlineKind::Never
+                            }
+                            unsupported => {
+                                return Err(format_err!(
+                                    unsupported,
+                                    "encountered unknown or unsupported #[inline(..)] specifier"
+                                ))
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid format for #[inline] field attribute"
+                        ))
+                    }
+                };
+                config.inline(kind, span)?;
+            } else if attr.path.is_ident("access") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let meta: syn::Meta = syn::parse2::<_>(quote! { #path #args })?;
+                let span = meta.span();
+                let kind = match meta {
+                    syn::Meta::List(meta_list) if meta_list.nested.len() == 1 => {
+                        let kind = match &meta_list.nested[0] {
+                            syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                                path.get_ident().and_then(|ident| {
+                                    match ident.to_string().as_str() {
+                                        "read_write" => Some(AccessKind::ReadWrite),
+                                        "read_only" => Some(AccessKind::ReadOnly),
+                                        "write_only" => Some(AccessKind::WriteOnly),
+                                        "w1c" => Some(AccessKind::WriteOneToClear),
+                                        "w1s" => Some(AccessKind::WriteOneToSet),
+                                        _ => None,
+                                    }
+                                })
+                            }
+                            _ => None,
+                        };
+                        match kind {
+                            Some(kind) => kind,
+                            None => {
+                                return Err(format_err!(
+                                    meta_list.nested[0],
+                                    "encountered unknown or unsupported #[access(..)] specifier"
+                                ))
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid format for #[access] field attribute"
+                        ))
+                    }
+                };
+                config.access(kind, span)?;
+            } else if attr.path.is_ident("validate") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let meta: syn::Meta = syn::parse2::<_>(quote! { #path #args })?;
+                let span = meta.span();
+                match meta {
+                    syn::Meta::List(meta_list) if (1..=2).contains(&meta_list.nested.len()) => {
+                        let function = match &meta_list.nested[0] {
+                            syn::NestedMeta::Meta(syn::Meta::Path(function)) => function.clone(),
+                            unsupported => {
+                                return Err(format_err!(
+                                    unsupported,
+                                    "expected a path to a validation function in #[validate(..)]"
+                                ))
+                            }
+                        };
+                        let error = match meta_list.nested.iter().nth(1) {
+                            Some(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+                                if name_value.path.is_ident("error") =>
+                            {
+                                match &name_value.lit {
+                                    syn::Lit::Str(lit_str) => {
+                                        Some(lit_str.parse::<syn::Type>().map_err(|err| {
+                                            format_err!(
+                                                lit_str,
+                                                "failed to parse error type in #[validate(..)]: {}",
+                                                err
+                                            )
+                                        })?)
+                                    }
+                                    unsupported => {
+                                        return Err(format_err!(
+                                            unsupported,
+                                            "expected a string literal naming the error type in #[validate(..)]"
+                                        ))
+                                    }
+                                }
+                            }
+                            Some(unsupported) => {
+                                return Err(format_err!(
+                                    unsupported,
+                                    "expected `error = \"E\"` in #[validate(..)]"
+                                ))
+                            }
+                            None => None,
+                        };
+                        config.validate(Validator { function, error }, span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid format for #[validate] field attribute"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("overlaps") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let meta: syn::Meta = syn::parse2::<_>(quote! { #path #args })?;
+                let span = meta.span();
+                match meta {
+                    syn::Meta::List(meta_list) if meta_list.nested.len() == 1 => {
+                        let target = match &meta_list.nested[0] {
+                            syn::NestedMeta::Meta(syn::Meta::Path(path)) => path.get_ident(),
+                            _ => None,
+                        };
+                        match target {
+                            Some(target) => config.overlaps(target.clone(), span)?,
+                            None => {
+                                return Err(format_err!(
+                                    meta_list.nested[0],
+                                    "expected the name of a field in #[overlaps(..)]"
+                                ))
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid format for #[overlaps] field attribute"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("flatten") {
+                let fields = attr.parse_args_with(
+                    Punctuated::<FlattenedField, syn::Token![,]>::parse_terminated,
+                )?;
+                if fields.is_empty() {
+                    return Err(format_err!(
+                        attr,
+                        "expected at least one `field: Specifier` pair in #[flatten(..)]"
+                    ))
+                }
+                config.flatten(fields.into_iter().collect(), attr.span())?;
+            } else if attr.path.is_ident("flags") {
+                if !attr.tokens.is_empty() {
+                    return Err(format_err!(
+                        attr,
+                        "encountered invalid format for #[flags] field attribute"
+                    ))
+                }
+                config.flags(attr.span())?;
+            } else if attr.path.is_ident("group") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                let name = match &name_value.lit {
+                    syn::Lit::Str(lit_str) => {
+                        lit_str.parse::<syn::Ident>().map_err(|_| {
+                            format_err!(
+                                lit_str,
+                                "encountered invalid group name for #[group = \"..\"]: expected an identifier"
+                            )
+                        })?
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[group = \"..\"]"
+                        ))
+                    }
+                };
+                config.group(name, span)?;
+            } else if attr.path.is_ident("variant") {
+                let variants = attr.parse_args_with(Self::parse_variant_args)?;
+                config.variants(variants, attr.span())?;
+            } else if attr.path.is_ident("on_overflow") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                let kind = match &name_value.lit {
+                    syn::Lit::Str(lit_str) => {
+                        match lit_str.value().as_str() {
+                            "error" => OverflowKind::Error,
+                            "saturate" => OverflowKind::Saturate,
+                            "truncate" => OverflowKind::Truncate,
+                            _ => {
+                                return Err(format_err!(
+                                    lit_str,
+                                    "encountered invalid value for #[on_overflow]: expected \"error\", \"saturate\" or \"truncate\""
+                                ))
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[on_overflow = \"..\"]"
+                        ))
+                    }
+                };
+                config.on_overflow(kind, span)?;
             } else if attr.path.is_ident("skip") {
                 let path = &attr.path;
                 let args = &attr.tokens;
@@ -3,7 +3,11 @@
 use super::field_config::FieldConfig;
 use crate::errors::CombineError;
 use core::any::TypeId;
-use proc_macro2::Span;
+use proc_macro2::{
+    Span,
+    TokenStream as TokenStream2,
+};
+use quote::quote_spanned;
 use std::collections::{
     hash_map::Entry,
     HashMap,
@@ -16,11 +20,54 @@ pub struct Config {
     pub bytes: Option<ConfigValue<usize>>,
     pub bits: Option<ConfigValue<usize>>,
     pub filled: Option<ConfigValue<bool>>,
+    pub bytes_vis: Option<ConfigValue<syn::Visibility>>,
+    pub bytes_field: Option<ConfigValue<syn::Ident>>,
+    pub bytes_field_vis: Option<ConfigValue<syn::Visibility>>,
+    pub accessor_trait: Option<ConfigValue<syn::Ident>>,
+    pub default: Option<ConfigValue<Vec<u8>>>,
+    pub reset: Option<ConfigValue<Vec<u8>>>,
+    pub as_ref: Option<ConfigValue<bool>>,
+    pub unaligned: Option<ConfigValue<bool>>,
+    pub field_errors: Option<ConfigValue<bool>>,
+    pub text: Option<ConfigValue<bool>>,
+    pub gen_tests: Option<ConfigValue<bool>>,
+    pub bit_ops: Option<ConfigValue<bool>>,
+    pub views: Option<ConfigValue<bool>>,
+    pub ptr_view: Option<ConfigValue<bool>>,
+    pub inline: Option<ConfigValue<InlineKind>>,
+    pub min_code_size: Option<ConfigValue<bool>>,
+    pub accessors: Option<ConfigValue<AccessorsKind>>,
+    pub debug: Option<ConfigValue<DebugKind>>,
+    pub atomic: Option<ConfigValue<bool>>,
+    pub export_c: Option<ConfigValue<bool>>,
+    pub export_c_prefix: Option<ConfigValue<syn::Ident>>,
+    pub reader_writer: Option<ConfigValue<bool>>,
+    pub cell: Option<ConfigValue<CellKind>>,
+    pub no_panic: Option<ConfigValue<bool>>,
+    pub validated: Option<ConfigValue<bool>>,
+    pub check_reserved: Option<ConfigValue<bool>>,
+    pub reflect: Option<ConfigValue<bool>>,
+    pub layout_docs: Option<ConfigValue<bool>>,
+    pub reinterpret: Option<ConfigValue<Vec<syn::Path>>>,
+    pub migrate_from: Option<ConfigValue<Vec<syn::Path>>>,
+    pub impl_trait: Option<ConfigValue<Vec<syn::Path>>>,
+    pub ord_by: Option<ConfigValue<Vec<syn::Ident>>>,
+    pub byte_perm: Option<ConfigValue<BytePermKind>>,
     pub repr: Option<ConfigValue<ReprKind>>,
     pub derive_debug: Option<ConfigValue<()>>,
+    pub derive_default: Option<ConfigValue<()>>,
     pub derive_specifier: Option<ConfigValue<()>>,
     pub retained_attributes: Vec<syn::Attribute>,
     pub field_configs: HashMap<usize, ConfigValue<FieldConfig>>,
+    pub marker_fields: Vec<MarkerField>,
+}
+
+/// A zero-sized marker field of a `#[bitfield]` struct that is not part of its bit layout.
+pub struct MarkerField {
+    /// The marker field without its `#[marker]` attribute.
+    pub field: syn::Field,
+    /// The expression constructing the value of the marker field.
+    pub init: syn::Expr,
 }
 
 /// Kinds of `#[repr(uN)]` annotations for a `#[bitfield]` struct.
@@ -49,6 +96,19 @@ impl ReprKind {
             Self::U128 => 128,
         }
     }
+
+    /// Returns the amount of bits of the next narrower `#[repr(uN)]` or `0` for `#[repr(u8)]`.
+    ///
+    /// A bitfield with more bits than this fits into no narrower primitive.
+    pub fn narrower_bits(self) -> usize {
+        match self {
+            Self::U8 => 0,
+            Self::U16 => 8,
+            Self::U32 => 16,
+            Self::U64 => 32,
+            Self::U128 => 64,
+        }
+    }
 }
 
 impl core::fmt::Debug for ReprKind {
@@ -57,6 +117,121 @@ impl core::fmt::Debug for ReprKind {
     }
 }
 
+/// Kinds of `#[inline]` annotations for the generated accessors of a `#[bitfield]` struct.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum InlineKind {
+    /// Generates `#[inline]` annotations. This is the default.
+    Hint,
+    /// Generates `#[inline(always)]` annotations.
+    Always,
+    /// Generates `#[inline(never)]` annotations.
+    Never,
+}
+
+impl InlineKind {
+    /// Expands to the `#[inline]` annotation of the inline kind.
+    pub fn expand(self, span: Span) -> TokenStream2 {
+        match self {
+            Self::Hint => quote_spanned!(span=> #[inline]),
+            Self::Always => quote_spanned!(span=> #[inline(always)]),
+            Self::Never => quote_spanned!(span=> #[inline(never)]),
+        }
+    }
+}
+
+impl core::fmt::Debug for InlineKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Hint => write!(f, "\"hint\""),
+            Self::Always => write!(f, "\"always\""),
+            Self::Never => write!(f, "\"never\""),
+        }
+    }
+}
+
+/// Kinds of code generation for the bit access of field accessors of a `#[bitfield]` struct.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum AccessorsKind {
+    /// Generates the bit access code into every accessor. This is the default.
+    Inline,
+    /// Generates the bit access code into hidden `#[inline(never)]` functions
+    /// that are shared by all accessors of the same field.
+    Outline,
+}
+
+impl core::fmt::Debug for AccessorsKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Inline => write!(f, "\"inline\""),
+            Self::Outline => write!(f, "\"outline\""),
+        }
+    }
+}
+
+/// Kinds of `Debug` implementations for a `#[bitfield]` struct.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum DebugKind {
+    /// Prints the values of all fields. This is the default.
+    Fields,
+    /// Prints the underlying bytes in hexadecimal followed by the values of all fields.
+    Raw,
+    /// Prints the values of all fields together with their bit ranges and raw bits in binary.
+    Verbose,
+}
+
+impl core::fmt::Debug for DebugKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Fields => write!(f, "\"fields\""),
+            Self::Raw => write!(f, "\"raw\""),
+            Self::Verbose => write!(f, "\"verbose\""),
+        }
+    }
+}
+
+/// Kinds of cells storing the bytes of a `#[bitfield]` struct.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum CellKind {
+    /// Stores the bytes in a `VolatileCell` accessed through volatile reads and writes.
+    Volatile,
+}
+
+impl core::fmt::Debug for CellKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Volatile => write!(f, "\"volatile\""),
+        }
+    }
+}
+
+/// Kinds of byte permutations between a `#[bitfield]` struct and its stored bytes.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum BytePermKind {
+    /// Reverses the order of the little-endian 16-bit words.
+    Swap16,
+    /// Reverses the order of the little-endian 32-bit words.
+    Swap32,
+}
+
+impl BytePermKind {
+    /// Returns the number of bytes of the words whose order is reversed.
+    pub fn word_bytes(self) -> usize {
+        match self {
+            Self::Swap16 => 2,
+            Self::Swap32 => 4,
+        }
+    }
+}
+
+impl core::fmt::Debug for BytePermKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Swap16 => write!(f, "\"swap16\""),
+            Self::Swap32 => write!(f, "\"swap32\""),
+        }
+    }
+}
+
 /// A configuration value and its originating span.
 #[derive(Clone)]
 pub struct ConfigValue<T> {
@@ -82,9 +257,228 @@ impl Config {
             .unwrap_or(true)
     }
 
+    /// Returns the value of the `bit_ops` parameter if provided and otherwise `false`.
+    pub fn bit_ops_enabled(&self) -> bool {
+        self.bit_ops
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `as_ref` parameter if provided and otherwise `false`.
+    pub fn as_ref_enabled(&self) -> bool {
+        self.as_ref
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `unaligned` parameter if provided and otherwise `false`.
+    pub fn unaligned_enabled(&self) -> bool {
+        self.unaligned
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `field_errors` parameter if provided and otherwise `false`.
+    pub fn field_errors_enabled(&self) -> bool {
+        self.field_errors
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `text` parameter if provided and otherwise `false`.
+    pub fn text_enabled(&self) -> bool {
+        self.text.as_ref().map(|config| config.value).unwrap_or(false)
+    }
+
+    /// Returns the value of the `gen_tests` parameter if provided and otherwise `false`.
+    pub fn gen_tests_enabled(&self) -> bool {
+        self.gen_tests
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `views` parameter if provided and otherwise `false`.
+    pub fn views_enabled(&self) -> bool {
+        self.views
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `ptr_view` parameter if provided and otherwise `false`.
+    pub fn ptr_view_enabled(&self) -> bool {
+        self.ptr_view
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `atomic` parameter if provided and otherwise `false`.
+    pub fn atomic_enabled(&self) -> bool {
+        self.atomic
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `export_c` parameter if provided and otherwise `false`.
+    pub fn export_c_enabled(&self) -> bool {
+        self.export_c
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `reader_writer` parameter if provided and otherwise `false`.
+    pub fn reader_writer_enabled(&self) -> bool {
+        self.reader_writer
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `no_panic` parameter if provided and otherwise `false`.
+    pub fn no_panic_enabled(&self) -> bool {
+        self.no_panic
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `validated` parameter if provided and otherwise `false`.
+    pub fn validated_enabled(&self) -> bool {
+        self.validated
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `check_reserved` parameter if provided and otherwise `false`.
+    pub fn check_reserved_enabled(&self) -> bool {
+        self.check_reserved
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `reflect` parameter if provided and otherwise `true` if
+    /// the byte conversions of the bitfield are public.
+    pub fn reflect_enabled(&self) -> bool {
+        self.reflect
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or_else(|| self.bytes_public())
+    }
+
+    /// Returns the value of the `layout_docs` parameter if provided and otherwise `true`.
+    pub fn layout_docs_enabled(&self) -> bool {
+        self.layout_docs
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(true)
+    }
+
+    /// Returns `true` if the `cell` parameter is set to `"volatile"`.
+    pub fn volatile_cell(&self) -> bool {
+        self.cell
+            .as_ref()
+            .map(|config| config.value == CellKind::Volatile)
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `min_code_size` parameter if provided and otherwise `false`.
+    pub fn min_code_size_enabled(&self) -> bool {
+        self.min_code_size
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the `accessors` parameter is set to `"outline"`.
+    pub fn outline_accessors(&self) -> bool {
+        self.accessors
+            .as_ref()
+            .map(|config| config.value == AccessorsKind::Outline)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the `debug` parameter is set to `"raw"`.
+    pub fn raw_debug(&self) -> bool {
+        self.debug
+            .as_ref()
+            .map(|config| config.value == DebugKind::Raw)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the `debug` parameter is set to `"verbose"`.
+    pub fn verbose_debug(&self) -> bool {
+        self.debug
+            .as_ref()
+            .map(|config| config.value == DebugKind::Verbose)
+            .unwrap_or(false)
+    }
+
+    /// Returns the `#[inline]` annotation kind for the accessors of the given field.
+    ///
+    /// Prefers the field's `#[inline]` attribute over the `inline` parameter
+    /// and otherwise defaults to [`InlineKind::Hint`].
+    pub fn inline_for(&self, field: &FieldConfig) -> InlineKind {
+        field
+            .inline
+            .as_ref()
+            .or(self.inline.as_ref())
+            .map(|config| config.value)
+            .unwrap_or(InlineKind::Hint)
+    }
+
+    /// Returns the visibility of the generated byte conversion methods.
+    ///
+    /// Returns the visibility of the `bytes_vis` parameter if provided and otherwise `pub`.
+    pub fn bytes_visibility(&self) -> syn::Visibility {
+        self.bytes_vis
+            .as_ref()
+            .map(|config| config.value.clone())
+            .unwrap_or_else(|| syn::parse_quote!(pub))
+    }
+
+    /// Returns the name of the byte array field storing the bits of the bitfield.
+    ///
+    /// Returns the name of the `bytes_field` parameter if provided and otherwise `bytes`.
+    pub fn storage_ident(&self) -> syn::Ident {
+        self.bytes_field
+            .as_ref()
+            .map(|config| config.value.clone())
+            .unwrap_or_else(|| syn::Ident::new("bytes", Span::call_site()))
+    }
+
+    /// Returns the visibility of the byte array field storing the bits of the bitfield.
+    ///
+    /// Returns the visibility of the `bytes_field_vis` parameter if provided and otherwise
+    /// inherited visibility, i.e. a private field.
+    pub fn storage_visibility(&self) -> syn::Visibility {
+        self.bytes_field_vis
+            .as_ref()
+            .map(|config| config.value.clone())
+            .unwrap_or(syn::Visibility::Inherited)
+    }
+
+    /// Returns `true` if the generated byte conversion methods are publicly visible.
+    pub fn bytes_public(&self) -> bool {
+        matches!(self.bytes_visibility(), syn::Visibility::Public(_))
+    }
+
+    /// Ensures that the `bits` parameter fits the `#[repr(uN)]`.
+    ///
+    /// A `#[repr(uN)]` with more bits than the bitfield is allowed and zero-extends
+    /// as long as the bitfield fits into no narrower primitive.
     fn ensure_no_bits_and_repr_conflict(&self) -> Result<()> {
         if let (Some(bits), Some(repr)) = (self.bits.as_ref(), self.repr.as_ref()) {
-            if bits.value != repr.value.bits() {
+            if bits.value > repr.value.bits() || bits.value <= repr.value.narrower_bits() {
                 return Err(format_err!(
                     Span::call_site(),
                     "encountered conflicting `bits = {}` and {:?} parameters",
@@ -131,21 +525,123 @@ impl Config {
         Ok(())
     }
 
-    pub fn ensure_no_repr_and_filled_conflict(&self) -> Result<()> {
-        if let (Some(repr), Some(filled @ ConfigValue { value: false, .. })) =
-            (self.repr.as_ref(), self.filled.as_ref())
+    /// Ensures that `cell = "volatile"` is not combined with parameters that
+    /// require direct access to the underlying bytes.
+    fn ensure_no_volatile_cell_conflict(&self) -> Result<()> {
+        let cell = match &self.cell {
+            Some(cell) if cell.value == CellKind::Volatile => cell,
+            _ => return Ok(()),
+        };
+        fn enabled(config: &Option<ConfigValue<bool>>) -> Option<Span> {
+            config.as_ref().filter(|config| config.value).map(|config| config.span)
+        }
+        let conflicts = [
+            ("`views = true`", enabled(&self.views)),
+            ("`ptr_view = true`", enabled(&self.ptr_view)),
+            ("`atomic = true`", enabled(&self.atomic)),
+            ("`export_c = true`", enabled(&self.export_c)),
+            ("`reader_writer = true`", enabled(&self.reader_writer)),
+            ("`validated = true`", enabled(&self.validated)),
+            ("`check_reserved = true`", enabled(&self.check_reserved)),
+            ("`reflect = true`", enabled(&self.reflect)),
+            (
+                "`reinterpret`",
+                self.reinterpret.as_ref().map(|reinterpret| reinterpret.span),
+            ),
+            (
+                "`byte_perm`",
+                self.byte_perm.as_ref().map(|byte_perm| byte_perm.span),
+            ),
+            (
+                "`migrate_from`",
+                self.migrate_from.as_ref().map(|migrate_from| migrate_from.span),
+            ),
+            (
+                "`impl_trait`",
+                self.impl_trait.as_ref().map(|impl_trait| impl_trait.span),
+            ),
+            ("`ord_by`", self.ord_by.as_ref().map(|ord_by| ord_by.span)),
+            ("`as_ref = true`", enabled(&self.as_ref)),
+            ("`unaligned = true`", enabled(&self.unaligned)),
+            ("`field_errors = true`", enabled(&self.field_errors)),
+            ("`text = true`", enabled(&self.text)),
+            ("`gen_tests = true`", enabled(&self.gen_tests)),
+            ("`bit_ops = true`", enabled(&self.bit_ops)),
+            (
+                "`default`",
+                self.default.as_ref().map(|default| default.span),
+            ),
+            ("`reset`", self.reset.as_ref().map(|reset| reset.span)),
+            (
+                "`accessor_trait`",
+                self.accessor_trait.as_ref().map(|accessor_trait| accessor_trait.span),
+            ),
+            ("`#[repr(uN)]`", self.repr.as_ref().map(|repr| repr.span)),
+            (
+                "`#[derive(BitfieldSpecifier)]`",
+                self.derive_specifier.as_ref().map(|derive| derive.span),
+            ),
+        ];
+        for (name, span) in conflicts.iter() {
+            if let Some(span) = span {
+                return Err(format_err!(
+                    Span::call_site(),
+                    "encountered conflicting `cell = {:?}` and {} parameters",
+                    cell.value,
+                    name,
+                )
+                .into_combine(format_err!(cell.span, "conflicting `cell = {:?}` here", cell.value))
+                .into_combine(format_err!(*span, "conflicting {} here", name)))
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that `no_panic = true` is not combined with parameters that
+    /// generate panicking accessors of their own.
+    fn ensure_no_panic_conflict(&self) -> Result<()> {
+        let no_panic = match &self.no_panic {
+            Some(no_panic) if no_panic.value => no_panic,
+            _ => return Ok(()),
+        };
+        fn enabled(config: &Option<ConfigValue<bool>>) -> Option<Span> {
+            config.as_ref().filter(|config| config.value).map(|config| config.span)
+        }
+        let conflicts = [
+            ("`views = true`", enabled(&self.views)),
+            ("`ptr_view = true`", enabled(&self.ptr_view)),
+            ("`atomic = true`", enabled(&self.atomic)),
+            ("`export_c = true`", enabled(&self.export_c)),
+            ("`reader_writer = true`", enabled(&self.reader_writer)),
+            ("`cell`", self.cell.as_ref().map(|cell| cell.span)),
+        ];
+        for (name, span) in conflicts.iter() {
+            if let Some(span) = span {
+                return Err(format_err!(
+                    Span::call_site(),
+                    "encountered conflicting `no_panic = true` and {} parameters",
+                    name,
+                )
+                .into_combine(format_err!(no_panic.span, "conflicting `no_panic = true` here"))
+                .into_combine(format_err!(*span, "conflicting {} here", name)))
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that `reinterpret` is not combined with `filled = false`.
+    ///
+    /// The conversions between the reinterpretations require infallible byte conversions.
+    fn ensure_no_reinterpret_and_filled_conflict(&self) -> Result<()> {
+        if let (Some(reinterpret), Some(filled @ ConfigValue { value: false, .. })) =
+            (self.reinterpret.as_ref(), self.filled.as_ref())
         {
             return Err(format_err!(
                 Span::call_site(),
-                "encountered conflicting `{:?}` and `filled = {}` parameters",
-                repr.value,
+                "encountered conflicting `reinterpret` and `filled = {}` parameters",
                 filled.value,
             )
-            .into_combine(format_err!(
-                repr.span,
-                "conflicting `{:?}` here",
-                repr.value
-            ))
+            .into_combine(format_err!(reinterpret.span, "conflicting `reinterpret` here"))
             .into_combine(format_err!(
                 filled.span,
                 "conflicting `filled = {}` here",
@@ -159,10 +655,26 @@ impl Config {
     pub fn ensure_no_conflicts(&self) -> Result<()> {
         self.ensure_no_bits_and_repr_conflict()?;
         self.ensure_no_bits_and_bytes_conflict()?;
-        self.ensure_no_repr_and_filled_conflict()?;
+        self.ensure_no_reinterpret_and_filled_conflict()?;
+        self.ensure_no_volatile_cell_conflict()?;
+        self.ensure_no_panic_conflict()?;
+        self.ensure_export_c_for_prefix()?;
         Ok(())
     }
 
+    /// Ensures that `export_c_prefix` is only given together with `export_c = true`.
+    fn ensure_export_c_for_prefix(&self) -> Result<()> {
+        match &self.export_c_prefix {
+            Some(prefix) if !self.export_c_enabled() => {
+                Err(format_err!(
+                    prefix.span,
+                    "encountered `export_c_prefix` parameter without `export_c = true`"
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Returns an error showing both the duplicate as well as the previous parameters.
     fn raise_duplicate_error<T>(
         name: &str,
@@ -189,6 +701,27 @@ impl Config {
         ))
     }
 
+    /// Sets the given parameter slot to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    fn set_once<T>(
+        name: &str,
+        slot: &mut Option<ConfigValue<T>>,
+        value: T,
+        span: Span,
+    ) -> Result<()>
+    where
+        T: core::fmt::Debug + 'static,
+    {
+        match slot {
+            Some(previous) => return Err(Self::raise_duplicate_error(name, span, previous)),
+            None => *slot = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
     /// Sets the `bytes: int` #[bitfield] parameter to the given value.
     ///
     /// # Errors
@@ -234,6 +767,422 @@ impl Config {
         Ok(())
     }
 
+    /// Sets the `bytes_vis: str` #[bitfield] parameter to the given visibility.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn bytes_vis(&mut self, value: syn::Visibility, span: Span) -> Result<()> {
+        match &self.bytes_vis {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `bytes_vis` parameter"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "previous `bytes_vis` parameter here"
+                )))
+            }
+            None => self.bytes_vis = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `bytes_field: str` #[bitfield] parameter to the given field name.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn bytes_field(&mut self, value: syn::Ident, span: Span) -> Result<()> {
+        match &self.bytes_field {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `bytes_field` parameter"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "previous `bytes_field` parameter here"
+                )))
+            }
+            None => self.bytes_field = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `bytes_field_vis: str` #[bitfield] parameter to the given visibility.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn bytes_field_vis(&mut self, value: syn::Visibility, span: Span) -> Result<()> {
+        match &self.bytes_field_vis {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `bytes_field_vis` parameter"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "previous `bytes_field_vis` parameter here"
+                )))
+            }
+            None => self.bytes_field_vis = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `default: int` or `default: str` #[bitfield] parameter to the given
+    /// little-endian reset value bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn default_value(&mut self, value: Vec<u8>, span: Span) -> Result<()> {
+        Self::set_once("default", &mut self.default, value, span)
+    }
+
+    /// Sets the `reset: int` or `reset: str` #[bitfield] parameter to the given
+    /// little-endian reset value bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn reset(&mut self, value: Vec<u8>, span: Span) -> Result<()> {
+        Self::set_once("reset", &mut self.reset, value, span)
+    }
+
+    /// Sets the `accessor_trait: str` #[bitfield] parameter to the given trait name.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn accessor_trait(&mut self, value: syn::Ident, span: Span) -> Result<()> {
+        match &self.accessor_trait {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `accessor_trait` parameter"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "previous `accessor_trait` parameter here"
+                )))
+            }
+            None => self.accessor_trait = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `migrate_from: str` #[bitfield] parameter to the given bitfield types.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn migrate_from(&mut self, value: Vec<syn::Path>, span: Span) -> Result<()> {
+        match &self.migrate_from {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `migrate_from` parameter"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "previous `migrate_from` parameter here"
+                )))
+            }
+            None => self.migrate_from = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `impl_trait: str` #[bitfield] parameter to the given `#[bitfield_trait]` traits.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn impl_trait(&mut self, value: Vec<syn::Path>, span: Span) -> Result<()> {
+        match &self.impl_trait {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `impl_trait` parameter"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "previous `impl_trait` parameter here"
+                )))
+            }
+            None => self.impl_trait = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `ord_by: str` #[bitfield] parameter to the given prioritized fields.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn ord_by(&mut self, value: Vec<syn::Ident>, span: Span) -> Result<()> {
+        match &self.ord_by {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `ord_by` parameter"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "previous `ord_by` parameter here"
+                )))
+            }
+            None => self.ord_by = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `byte_perm: str` #[bitfield] parameter to the given permutation.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn byte_perm(&mut self, value: BytePermKind, span: Span) -> Result<()> {
+        Self::set_once("byte_perm", &mut self.byte_perm, value, span)
+    }
+
+    /// Sets the `as_ref: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn as_ref(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("as_ref", &mut self.as_ref, value, span)
+    }
+
+    /// Sets the `field_errors: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn field_errors(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("field_errors", &mut self.field_errors, value, span)
+    }
+
+    /// Sets the `text: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn text(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("text", &mut self.text, value, span)
+    }
+
+    /// Sets the `gen_tests: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn gen_tests(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("gen_tests", &mut self.gen_tests, value, span)
+    }
+
+    /// Sets the `unaligned: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn unaligned(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("unaligned", &mut self.unaligned, value, span)
+    }
+
+    /// Sets the `bit_ops: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn bit_ops(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("bit_ops", &mut self.bit_ops, value, span)
+    }
+
+    /// Sets the `views: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn views(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("views", &mut self.views, value, span)
+    }
+
+    /// Sets the `ptr_view: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn ptr_view(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("ptr_view", &mut self.ptr_view, value, span)
+    }
+
+    /// Sets the `inline: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn inline(&mut self, value: InlineKind, span: Span) -> Result<()> {
+        Self::set_once("inline", &mut self.inline, value, span)
+    }
+
+    /// Sets the `min_code_size: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn min_code_size(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("min_code_size", &mut self.min_code_size, value, span)
+    }
+
+    /// Sets the `accessors: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn accessors(&mut self, value: AccessorsKind, span: Span) -> Result<()> {
+        Self::set_once("accessors", &mut self.accessors, value, span)
+    }
+
+    /// Sets the `debug: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn debug(&mut self, value: DebugKind, span: Span) -> Result<()> {
+        Self::set_once("debug", &mut self.debug, value, span)
+    }
+
+    /// Sets the `atomic: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn atomic(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("atomic", &mut self.atomic, value, span)
+    }
+
+    /// Sets the `export_c: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn export_c(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("export_c", &mut self.export_c, value, span)
+    }
+
+    /// Sets the `export_c_prefix: str` #[bitfield] parameter to the given symbol prefix.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn export_c_prefix(&mut self, value: syn::Ident, span: Span) -> Result<()> {
+        match &self.export_c_prefix {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `export_c_prefix` parameter"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "previous `export_c_prefix` parameter here"
+                )))
+            }
+            None => self.export_c_prefix = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `reader_writer: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn reader_writer(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("reader_writer", &mut self.reader_writer, value, span)
+    }
+
+    /// Sets the `cell: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn cell(&mut self, value: CellKind, span: Span) -> Result<()> {
+        Self::set_once("cell", &mut self.cell, value, span)
+    }
+
+    /// Sets the `no_panic: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn no_panic(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("no_panic", &mut self.no_panic, value, span)
+    }
+
+    /// Sets the `validated: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn validated(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("validated", &mut self.validated, value, span)
+    }
+
+    /// Sets the `check_reserved: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn check_reserved(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("check_reserved", &mut self.check_reserved, value, span)
+    }
+
+    /// Sets the `reflect: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn reflect(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("reflect", &mut self.reflect, value, span)
+    }
+
+    /// Sets the `layout_docs: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn layout_docs(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once("layout_docs", &mut self.layout_docs, value, span)
+    }
+
+    /// Sets the `reinterpret: str` #[bitfield] parameter to the given bitfield types.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn reinterpret(&mut self, value: Vec<syn::Path>, span: Span) -> Result<()> {
+        match &self.reinterpret {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `reinterpret` parameter"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "previous `reinterpret` parameter here"
+                )))
+            }
+            None => self.reinterpret = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
     /// Registers the `#[repr(uN)]` attribute for the #[bitfield] macro.
     ///
     /// # Errors
@@ -268,6 +1217,25 @@ impl Config {
         Ok(())
     }
 
+    /// Registers the `#[derive(Default)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(Default)]` attribute has already been found.
+    pub fn derive_default(&mut self, span: Span) -> Result<()> {
+        match &self.derive_default {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[derive(Default)]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.derive_default = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
     /// Registers the `#[derive(BitfieldSpecifier)]` attribute for the #[bitfield] macro.
     ///
     /// # Errors
@@ -287,6 +1255,11 @@ impl Config {
         Ok(())
     }
 
+    /// Pushes another marker field that is excluded from the bit layout of the #[bitfield].
+    pub fn push_marker_field(&mut self, field: syn::Field, init: syn::Expr) {
+        self.marker_fields.push(MarkerField { field, init });
+    }
+
     /// Pushes another retained attribute that the #[bitfield] macro is going to re-expand and ignore.
     pub fn push_retained_attribute(&mut self, retained_attr: syn::Attribute) {
         self.retained_attributes.push(retained_attr);
@@ -1,12 +1,22 @@
 use super::{
     config::{
         Config,
+        InlineKind,
         ReprKind,
     },
+    field_config::{
+        AccessKind,
+        FlattenedField,
+        OverflowKind,
+        Variant,
+    },
     field_info::FieldInfo,
     BitfieldStruct,
 };
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{
+    Span,
+    TokenStream as TokenStream2,
+};
 use quote::{
     format_ident,
     quote,
@@ -16,12 +26,14 @@ use syn::{
     self,
     punctuated::Punctuated,
     spanned::Spanned as _,
-    Token,
 };
 
 impl BitfieldStruct {
     /// Expands the given `#[bitfield]` struct into an actual bitfield definition.
     pub fn expand(&self, config: &Config) -> TokenStream2 {
+        if config.volatile_cell() {
+            return self.expand_volatile_cell(config)
+        }
         let span = self.item_struct.span();
         let check_filled = self.generate_check_for_filled(config);
         let struct_definition = self.generate_struct(config);
@@ -29,8 +41,43 @@ impl BitfieldStruct {
         let specifier_impl = self.generate_specifier_impl(config);
 
         let byte_conversion_impls = self.expand_byte_conversion_impls(config);
+        let endian_byte_conversions = self.expand_endian_byte_conversions(config);
+        let byte_perm_conversions = self.expand_byte_perm_conversions(config);
+        let byte_array_from_impls = self.expand_byte_array_from_impls(config);
+        let reinterpret_impls = self.expand_reinterpret_impls(config);
+        let migrate_impls = self.expand_migrate_impls(config);
+        let trait_impls = self.expand_trait_impls(config);
+        let ord_impls = self.expand_ord_impls(config);
         let getters_and_setters = self.expand_getters_and_setters(config);
+        let outlined_accessors = self.expand_outlined_accessors(config);
+        let variant_accessors = self.expand_variant_accessors(config);
+        let flattened_accessors = self.expand_flattened_accessors(config);
+        let bit_accessors = self.expand_bit_accessors(config);
+        let set_flags = self.expand_set_flags(config);
+        let flags_group = self.expand_flags_group(config);
+        let zero_predicates = self.expand_zero_predicates(config);
+        let bit_order_methods = self.expand_bit_order_methods(config);
+        let write_if_changed = self.expand_write_if_changed(config);
+        let groups = self.expand_groups(config);
+        let as_ref_impls = self.expand_as_ref_impls(config);
+        let unaligned = self.expand_unaligned(config);
+        let sample = self.expand_sample(config);
+        let hash32 = self.expand_hash32(config);
+        let bit_ops_impls = self.expand_bit_ops_impls(config);
+        let views = self.expand_views(config);
+        let ptr_view = self.expand_ptr_view(config);
+        let atomic = self.expand_atomic(config);
+        let export_c = self.expand_export_c(config);
+        let reader_writer = self.expand_reader_writer(config);
+        let validated = self.expand_validated(config);
+        let field_errors = self.expand_field_errors(config);
+        let text_impls = self.expand_text_impls(config);
+        let gen_tests = self.expand_gen_tests(config);
+        let reserved_check = self.expand_reserved_check(config);
+        let layout = self.expand_layout(config);
+        let reflect_impl = self.expand_reflect_impl(config);
         let bytes_check = self.expand_optional_bytes_check(config);
+        let overlap_checks = self.expand_overlap_checks(config);
         let repr_impls_and_checks = self.expand_repr_from_impls_and_checks(config);
         let debug_impl = self.generate_debug_impl(config);
 
@@ -39,9 +86,44 @@ impl BitfieldStruct {
             #check_filled
             #constructor_definition
             #byte_conversion_impls
+            #endian_byte_conversions
+            #byte_perm_conversions
+            #byte_array_from_impls
+            #reinterpret_impls
+            #migrate_impls
+            #trait_impls
+            #ord_impls
             #getters_and_setters
+            #outlined_accessors
+            #variant_accessors
+            #flattened_accessors
+            #bit_accessors
+            #set_flags
+            #flags_group
+            #zero_predicates
+            #bit_order_methods
+            #write_if_changed
+            #groups
+            #as_ref_impls
+            #unaligned
+            #sample
+            #hash32
+            #bit_ops_impls
+            #views
+            #ptr_view
+            #atomic
+            #export_c
+            #reader_writer
+            #validated
+            #field_errors
+            #text_impls
+            #gen_tests
+            #reserved_check
+            #layout
+            #reflect_impl
             #specifier_impl
             #bytes_check
+            #overlap_checks
             #repr_impls_and_checks
             #debug_impl
         )
@@ -52,8 +134,11 @@ impl BitfieldStruct {
     ///
     /// Otherwise returns `None`.
     pub fn generate_specifier_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let markers = Self::expand_marker_inits(config);
         config.derive_specifier.as_ref()?;
         let span = self.item_struct.span();
+        let mut storage = config.storage_ident();
+        storage.set_span(span);
         let ident = &self.item_struct.ident;
         let bits = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&bits);
@@ -79,7 +164,7 @@ impl BitfieldStruct {
                 ) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
                     ::core::result::Result::Ok(
                         <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::array_into_bytes(
-                            value.bytes
+                            value.#storage
                         )
                     )
                 }
@@ -90,14 +175,14 @@ impl BitfieldStruct {
                 ) -> ::core::result::Result<Self::InOut, ::modular_bitfield::error::InvalidBitPattern<Self::Bytes>>
                 {
                     let __bf_max_value: Self::Bytes = (0x01 as Self::Bytes)
-                        .checked_shl(Self::BITS as ::core::primitive::u32)
+                        .checked_shl(<Self as ::modular_bitfield::Specifier>::BITS as ::core::primitive::u32)
                         .unwrap_or(<Self::Bytes>::MAX);
                     if bytes > __bf_max_value {
                         return ::core::result::Result::Err(::modular_bitfield::error::InvalidBitPattern::new(bytes))
                     }
                     let __bf_bytes = bytes.to_le_bytes();
                     ::core::result::Result::Ok(Self {
-                        bytes: <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::bytes_into_array(bytes)
+                        #markers #storage: <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::bytes_into_array(bytes)
                     })
                 }
             }
@@ -105,11 +190,22 @@ impl BitfieldStruct {
     }
 
     /// Generates the core::fmt::Debug impl if `#[derive(Debug)]` is included.
+    ///
+    /// With `debug = "raw"` the impl is always generated and additionally
+    /// prints the underlying bytes in hexadecimal.
     pub fn generate_debug_impl(&self, config: &Config) -> Option<TokenStream2> {
-        config.derive_debug.as_ref()?;
+        let storage = config.storage_ident();
+        if config.derive_debug.is_none() && !config.raw_debug() && !config.verbose_debug() {
+            return None
+        }
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let fields = self.field_infos(config).map(|info| {
+        let bytes = match config.volatile_cell() {
+            true => quote_spanned!(span=> self.#storage.get()),
+            false => quote_spanned!(span=> self.#storage),
+        };
+        let verbose_debug = config.verbose_debug();
+        let fields = self.field_infos_with_offsets(config).map(|(info, offset)| {
             let FieldInfo {
                 index: _,
                 field,
@@ -120,28 +216,59 @@ impl BitfieldStruct {
             }
             let field_span = field.span();
             let field_name = info.name();
-            let field_ident = info.ident_frag();
-            let field_getter = field
-                .ident
-                .as_ref()
-                .map(|_| format_ident!("{}_or_err", field_ident))
-                .unwrap_or_else(|| format_ident!("get_{}_or_err", field_ident));
+            let field_getter = info.get_checked_ident();
+            let cfg_attrs = info.cfg_attrs();
+            let value = quote_spanned!(field_span=>
+                self.#field_getter()
+                    .as_ref()
+                    .map(|__bf_field| __bf_field as &dyn (::core::fmt::Debug))
+                    .unwrap_or_else(|__bf_err| __bf_err as &dyn (::core::fmt::Debug))
+            );
+            let value = match verbose_debug {
+                true => {
+                    let ty = &field.ty;
+                    quote_spanned!(field_span=>
+                        &::modular_bitfield::private::VerboseField {
+                            value: #value,
+                            offset: #offset,
+                            bits: <#ty as ::modular_bitfield::Specifier>::BITS,
+                            raw: <<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::into_u128(
+                                ::modular_bitfield::private::read_specifier::<#ty>(&__bf_bytes[..], #offset)
+                            ),
+                        }
+                    )
+                }
+                false => value,
+            };
             Some(quote_spanned!(field_span=>
-                .field(
-                    #field_name,
-                    self.#field_getter()
-                        .as_ref()
-                        .map(|__bf_field| __bf_field as &dyn (::core::fmt::Debug))
-                        .unwrap_or_else(|__bf_err| __bf_err as &dyn (::core::fmt::Debug))
-                )
+                #( #cfg_attrs )*
+                __bf_debug.field(#field_name, #value);
             ))
         });
+        let verbose_bytes = match verbose_debug {
+            true => Some(quote_spanned!(span=> let __bf_bytes = #bytes;)),
+            false => None,
+        };
+        let raw_bytes = match config.raw_debug() {
+            true => {
+                Some(quote_spanned!(span=>
+                    __bf_debug.field(
+                        "bytes",
+                        &::modular_bitfield::private::HexBytes(&#bytes[..]),
+                    );
+                ))
+            }
+            false => None,
+        };
         Some(quote_spanned!(span=>
             impl ::core::fmt::Debug for #ident {
+                #[allow(clippy::identity_op)]
                 fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    __bf_f.debug_struct(::core::stringify!(#ident))
-                        #( #fields )*
-                        .finish()
+                    #verbose_bytes
+                    let mut __bf_debug = __bf_f.debug_struct(::core::stringify!(#ident));
+                    #raw_bytes
+                    #( #fields )*
+                    __bf_debug.finish()
                 }
             }
         ))
@@ -181,27 +308,35 @@ impl BitfieldStruct {
     /// ```
     ///
     /// Which is a compile time evaluatable expression.
-    fn generate_bitfield_size(&self) -> TokenStream2 {
+    ///
+    /// Fields with an `#[overlaps(..)]` attribute do not occupy bits of their own and are not counted.
+    /// Fields with `#[cfg(..)]` attributes are only counted if they are enabled.
+    fn generate_bitfield_size(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
-        let sum = self
-            .item_struct
-            .fields
-            .iter()
-            .map(|field| {
-                let span = field.span();
-                let ty = &field.ty;
-                quote_spanned!(span=>
-                    <#ty as ::modular_bitfield::Specifier>::BITS
-                )
+        let sum = self.generate_bitfield_size_sum(config);
+        quote_spanned!(span=>
+            { #sum }
+        )
+    }
+
+    /// Generates the unbraced sum of the bit widths of all fields.
+    ///
+    /// Used where the sum is passed as a function argument.
+    fn generate_bitfield_size_sum(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        self
+            .field_infos(config)
+            .filter(|info| info.config.overlapped_field().is_none())
+            .map(|info| {
+                let span = info.field.span();
+                let bits = info.bits_expr();
+                quote_spanned!(span=> #bits)
             })
             .fold(quote_spanned!(span=> 0usize), |lhs, rhs| {
                 quote_spanned!(span =>
                     #lhs + #rhs
                 )
-            });
-        quote_spanned!(span=>
-            { #sum }
-        )
+            })
     }
 
     /// Generates the expression denoting the actual configured or implied bit width.
@@ -216,7 +351,7 @@ impl BitfieldStruct {
                     #value
                 )
             })
-            .unwrap_or_else(|| self.generate_bitfield_size())
+            .unwrap_or_else(|| self.generate_bitfield_size(config))
     }
 
     /// Generates a check in case `bits = N` is unset to verify that the actual amount of bits is either
@@ -229,23 +364,14 @@ impl BitfieldStruct {
         required_bits: usize,
     ) -> TokenStream2 {
         let span = self.item_struct.span();
-        let ident = &self.item_struct.ident;
-        let actual_bits = self.generate_bitfield_size();
-        let check_ident = match config.filled_enabled() {
-            true => quote_spanned!(span => CheckFillsUnalignedBits),
-            false => quote_spanned!(span => CheckDoesNotFillUnalignedBits),
-        };
-        let comparator = match config.filled_enabled() {
-            true => quote! { == },
-            false => quote! { > },
+        let actual_bits = self.generate_bitfield_size_sum(config);
+        let check = match config.filled_enabled() {
+            true => quote_spanned!(span=> check_exact_bits),
+            false => quote_spanned!(span=> check_fewer_bits),
         };
         quote_spanned!(span=>
             #[allow(clippy::identity_op)]
-            const _: () = {
-                impl ::modular_bitfield::private::checks::#check_ident for #ident {
-                    type CheckType = [(); (#required_bits #comparator #actual_bits) as usize];
-                }
-            };
+            const _: () = ::modular_bitfield::private::checks::#check(#required_bits, #actual_bits);
         )
     }
 
@@ -255,19 +381,14 @@ impl BitfieldStruct {
     /// - ... not divisible by 8, if `filled = false`
     fn generate_filled_check_for_aligned_bits(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
-        let ident = &self.item_struct.ident;
-        let actual_bits = self.generate_bitfield_size();
-        let check_ident = match config.filled_enabled() {
-            true => quote_spanned!(span => CheckTotalSizeMultipleOf8),
-            false => quote_spanned!(span => CheckTotalSizeIsNotMultipleOf8),
+        let actual_bits = self.generate_bitfield_size_sum(config);
+        let check = match config.filled_enabled() {
+            true => quote_spanned!(span=> check_multiple_of_8_bits),
+            false => quote_spanned!(span=> check_not_multiple_of_8_bits),
         };
         quote_spanned!(span=>
             #[allow(clippy::identity_op)]
-            const _: () = {
-                impl ::modular_bitfield::private::checks::#check_ident for #ident {
-                    type Size = ::modular_bitfield::private::checks::TotalSize<[(); #actual_bits % 8usize]>;
-                }
-            };
+            const _: () = ::modular_bitfield::private::checks::#check(#actual_bits);
         )
     }
 
@@ -300,40 +421,163 @@ impl BitfieldStruct {
     ///
     /// Internally it only contains a byte array equal to the minimum required
     /// amount of bytes to compactly store the information of all its bit fields.
+    ///
+    /// Unless the struct has another `#[repr(..)]` it is `#[repr(C)]` so that it can be
+    /// passed across `extern "C"` boundaries by value.
     fn generate_struct(&self, config: &Config) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let marker_fields = config.marker_fields.iter().map(|marker| &marker.field);
+        let storage_vis = config.storage_visibility();
         let span = self.item_struct.span();
         let attrs = &config.retained_attributes;
         let vis = &self.item_struct.vis;
         let ident = &self.item_struct.ident;
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let layout_docs = self.expand_layout_docs(config);
+        let has_repr = attrs.iter().any(|attr| attr.path.is_ident("repr"));
+        let (repr_c, abi_checks) = match has_repr {
+            true => (None, None),
+            false => {
+                (
+                    Some(quote_spanned!(span=> #[repr(C)])),
+                    Some(quote_spanned!(span=>
+                        #[allow(clippy::identity_op)]
+                        const _: () = {
+                            ::modular_bitfield::private::static_assertions::const_assert_eq!(
+                                ::core::mem::size_of::<#ident>(),
+                                #next_divisible_by_8 / 8usize
+                            );
+                            ::modular_bitfield::private::static_assertions::const_assert_eq!(
+                                ::core::mem::align_of::<#ident>(),
+                                1
+                            );
+                        };
+                    )),
+                )
+            }
+        };
         quote_spanned!(span=>
             #( #attrs )*
+            #layout_docs
+            #repr_c
             #[allow(clippy::identity_op)]
             #vis struct #ident
             {
-                bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize],
+                #( #marker_fields, )*
+                #storage_vis #storage: [::core::primitive::u8; #next_divisible_by_8 / 8usize],
             }
+
+            #abi_checks
         )
     }
 
+    /// Generates the initializers of all marker fields for struct expressions of the bitfield.
+    fn expand_marker_inits(config: &Config) -> TokenStream2 {
+        let inits = config.marker_fields.iter().map(|marker| {
+            let ident = &marker.field.ident;
+            let init = &marker.init;
+            quote_spanned!(marker.field.span()=> #ident: #init,)
+        });
+        quote! {
+            #( #inits )*
+        }
+    }
+
     /// Generates the constructor for the bitfield that initializes all bytes to zero.
+    ///
+    /// Also generates `clear` unless the getter of a field named `clear` occupies the name.
     fn generate_constructor(&self, config: &Config) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let constructor = match &config.default {
+            Some(default) => {
+                let bits = self.generate_bitfield_size_sum(config);
+                let value = &default.value;
+                quote_spanned!(default.span=>
+                    #[allow(clippy::identity_op)]
+                    const __BF_RESET_BYTES: [::core::primitive::u8; #next_divisible_by_8 / 8usize] =
+                        ::modular_bitfield::private::reset_bytes(&[ #( #value ),* ], #bits);
+
+                    /// Returns an instance initialized to the reset value of the `default` parameter.
+                    #[allow(clippy::identity_op)]
+                    pub const fn new() -> Self {
+                        Self {
+                            #markers #storage: Self::__BF_RESET_BYTES,
+                        }
+                    }
+                )
+            }
+            None => {
+                quote_spanned!(span=>
+                    /// Returns an instance with zero initialized data.
+                    #[allow(clippy::identity_op)]
+                    pub const fn new() -> Self {
+                        Self {
+                            #markers #storage: [0u8; #next_divisible_by_8 / 8usize],
+                        }
+                    }
+                )
+            }
+        };
+        let reset = config.reset.as_ref().map(|reset| {
+            let bits = self.generate_bitfield_size_sum(config);
+            let value = &reset.value;
+            quote_spanned!(reset.span=>
+                /// The reset value of the bitfield given by the `reset` parameter.
+                #[allow(dead_code, clippy::identity_op)]
+                pub const RESET: Self = Self {
+                    #markers #storage: ::modular_bitfield::private::reset_bytes(&[ #( #value ),* ], #bits),
+                };
+
+                /// Restores the bitfield to the reset value of the `reset` parameter.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                pub fn reset(&mut self) {
+                    self.#storage = Self::RESET.#storage;
+                }
+            )
+        });
+        let default_impl = config.derive_default.as_ref().map(|derive_default| {
+            quote_spanned!(derive_default.span=>
+                impl ::core::default::Default for #ident {
+                    #[inline]
+                    fn default() -> Self {
+                        Self::new()
+                    }
+                }
+            )
+        });
+        let occupied = self
+            .field_infos(config)
+            .any(|info| info.name() == "clear" && !info.config.skip_getters());
+        let clear = match occupied {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Resets all bits of the bitfield to zero.
+                    #[inline]
+                    #[allow(dead_code, clippy::identity_op)]
+                    pub fn clear(&mut self) {
+                        self.#storage = [0u8; #next_divisible_by_8 / 8usize];
+                    }
+                ))
+            }
+        };
         quote_spanned!(span=>
             impl #ident
             {
-                /// Returns an instance with zero initialized data.
-                #[allow(clippy::identity_op)]
-                pub const fn new() -> Self {
-                    Self {
-                        bytes: [0u8; #next_divisible_by_8 / 8usize],
-                    }
-                }
+                #constructor
+                #reset
+
+                #clear
             }
+
+            #default_impl
         )
     }
 
@@ -355,8 +599,319 @@ impl BitfieldStruct {
         })
     }
 
-    /// Generates `From` impls for a `#[repr(uN)]` annotated #[bitfield] struct.
+    /// Generates the accessors of the sub-layouts of all fields with a `#[variant(..)]` attribute.
+    ///
+    /// For a variant `N => PayloadA` of field `payload` with tag field `kind` these are
+    ///
+    /// - `payload_a` returning the value of `payload` as `PayloadA` if `kind` is `N` and
+    /// - `set_payload_a` setting `kind` to `N` and `payload` to the given `PayloadA`.
+    fn expand_variant_accessors(&self, config: &Config) -> Option<TokenStream2> {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let infos = self.field_infos_with_offsets(config).collect::<Vec<_>>();
+        let accessors = infos
+            .iter()
+            .filter_map(|(info, offset)| {
+                let variants = &info.config.variants.as_ref()?.value;
+                let tag = variants.tag.value();
+                let (tag_info, tag_offset) = infos.iter().find(|(info, _)| info.name() == tag)?;
+                Some(variants.variants.iter().map(move |variant| {
+                    self.expand_variant_accessors_for(
+                        info,
+                        offset,
+                        tag_info,
+                        tag_offset,
+                        variant,
+                        config,
+                    )
+                }))
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        if accessors.is_empty() {
+            return None
+        }
+        let checks = accessors.iter().map(|(check, _)| check);
+        let accessors = accessors.iter().map(|(_, accessors)| accessors);
+        Some(quote_spanned!(span=>
+            #( #checks )*
+
+            impl #ident {
+                #( #accessors )*
+            }
+        ))
+    }
+
+    /// Generates the getter and setter of a single variant of a `#[variant(..)]` field.
+    ///
+    /// Returns the compile-time checks of the variant and its accessors.
+    fn expand_variant_accessors_for(
+        &self,
+        info: &FieldInfo<'_>,
+        offset: &TokenStream2,
+        tag_info: &FieldInfo<'_>,
+        tag_offset: &TokenStream2,
+        variant: &Variant,
+        config: &Config,
+    ) -> (TokenStream2, TokenStream2) {
+        let span = variant.specifier.span();
+        let no_panic = config.no_panic_enabled();
+        let storage = config.storage_ident();
+        let vis = &info.field.vis;
+        let ty = &info.field.ty;
+        let tag_ty = &tag_info.field.ty;
+        let specifier = &variant.specifier;
+        let tag_value = proc_macro2::Literal::u128_unsuffixed(variant.tag_value);
+        let variant_name = specifier
+            .segments
+            .last()
+            .map(|segment| Self::snake_case(&segment.ident.to_string()))
+            .unwrap_or_default();
+        let (name, tag_name) = (info.name(), tag_info.name());
+        let get_ident = format_ident!("{}", variant_name, span = span);
+        let set_ident = format_ident!("set_{}", variant_name, span = span);
+        let specifier_name = quote!(#specifier).to_string().replace(' ', "");
+        let getter_docs = format!(
+            "Returns the value of {} as `{}` if {} is `{}`.\n\n\
+             Returns `None` for other values of {} or if the bits of {} are an invalid bit pattern for `{}`.",
+            name, specifier_name, tag_name, variant.tag_value, tag_name, name, specifier_name,
+        );
+        let setter_docs = format!(
+            "Sets {} to `{}` and {} to the given `{}` value.\n\n\
+             The bits of {} that are not covered by `{}` are set to zero.",
+            tag_name, variant.tag_value, name, specifier_name, name, specifier_name,
+        );
+        let set_assert_msg = format!(
+            "value out of bounds for variant `{}` of field {}",
+            specifier_name, name
+        );
+        let setter = match no_panic {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    #[inline]
+                    #[allow(dead_code, clippy::identity_op)]
+                    #vis fn #set_ident(&mut self, new_val: <#specifier as ::modular_bitfield::Specifier>::InOut) {
+                        let mut __bf_bytes = self.#storage;
+                        ::modular_bitfield::raw::write(
+                            &mut __bf_bytes[..],
+                            #offset,
+                            <#ty as ::modular_bitfield::Specifier>::BITS,
+                            0,
+                        );
+                        if let ::core::result::Result::Err(err) =
+                            ::modular_bitfield::raw::write_specifier::<#specifier>(&mut __bf_bytes[..], #offset, new_val)
+                        {
+                            ::core::panic!("{}: {}", #set_assert_msg, err)
+                        }
+                        ::modular_bitfield::raw::write(
+                            &mut __bf_bytes[..],
+                            #tag_offset,
+                            <#tag_ty as ::modular_bitfield::Specifier>::BITS,
+                            #tag_value,
+                        );
+                        self.#storage = __bf_bytes;
+                    }
+                ))
+            }
+        };
+        let checks = quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            const _: () = {
+                ::modular_bitfield::private::static_assertions::const_assert!(
+                    <#specifier as ::modular_bitfield::Specifier>::BITS <= <#ty as ::modular_bitfield::Specifier>::BITS
+                );
+                ::modular_bitfield::private::static_assertions::const_assert!(
+                    #tag_value <= (::core::primitive::u128::MAX >> (128 - <#tag_ty as ::modular_bitfield::Specifier>::BITS))
+                );
+            };
+        );
+        let accessors = quote_spanned!(span=>
+            #[doc = #getter_docs]
+            #[inline]
+            #[allow(dead_code, clippy::identity_op)]
+            #vis fn #get_ident(&self) -> ::core::option::Option<<#specifier as ::modular_bitfield::Specifier>::InOut> {
+                let __bf_tag = ::modular_bitfield::raw::read(
+                    &self.#storage[..],
+                    #tag_offset,
+                    <#tag_ty as ::modular_bitfield::Specifier>::BITS,
+                );
+                if __bf_tag != #tag_value {
+                    return ::core::option::Option::None
+                }
+                ::modular_bitfield::raw::read_specifier::<#specifier>(&self.#storage[..], #offset).ok()
+            }
+
+            #setter
+        );
+        (checks, accessors)
+    }
+
+    /// Generates the accessors re-exporting the fields of nested bitfields with a `#[flatten(..)]` attribute.
+    ///
+    /// For a field `b` of the nested bitfield stored in field `a` these are `a_b`, `set_a_b` and `with_a_b`.
+    /// They copy out the nested bitfield and copy it back in for setters.
+    fn expand_flattened_accessors(&self, config: &Config) -> Option<TokenStream2> {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let accessors = self
+            .field_infos(config)
+            .filter_map(|info| {
+                let fields = &info.config.flatten.as_ref()?.value;
+                let with_getters = !info.config.skip_getters();
+                let with_setters = with_getters
+                    && !info.config.skip_setters()
+                    && !config.no_panic_enabled();
+                let accessors = fields
+                    .iter()
+                    .map(|flattened| {
+                        self.expand_flattened_accessors_for(
+                            &info,
+                            flattened,
+                            with_getters,
+                            with_setters,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                Some(accessors)
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        if accessors.is_empty() {
+            return None
+        }
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #( #accessors )*
+            }
+        ))
+    }
+
+    /// Generates the accessors of a single flattened field of a nested bitfield.
+    fn expand_flattened_accessors_for(
+        &self,
+        info: &FieldInfo<'_>,
+        flattened: &FlattenedField,
+        with_getters: bool,
+        with_setters: bool,
+    ) -> TokenStream2 {
+        let span = flattened.ident.span();
+        let vis = &info.field.vis;
+        let ty = &flattened.ty;
+        let (name, inner) = (info.name(), &flattened.ident);
+        let outer_get_ident = info.get_ident();
+        let outer_set_ident = format_ident!("set_{}", info.ident_frag());
+        let get_ident = format_ident!("{}_{}", outer_get_ident, inner, span = span);
+        let set_ident = format_ident!("set_{}_{}", info.ident_frag(), inner, span = span);
+        let with_ident = format_ident!("with_{}_{}", info.ident_frag(), inner, span = span);
+        let getter = match with_getters {
+            true => {
+                let getter_docs = format!("Returns the value of {} of the nested {}.", inner, name);
+                Some(quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                        self.#outer_get_ident().#inner()
+                    }
+                ))
+            }
+            false => None,
+        };
+        let setters = match with_setters {
+            true => {
+                let inner_set_ident = format_ident!("set_{}", inner);
+                let setter_docs = format!("Sets the value of {} of the nested {} to the given value.", inner, name);
+                let with_docs = format!(
+                    "Returns a copy of the bitfield with {} of the nested {} set to the given value.",
+                    inner, name
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #vis fn #set_ident(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
+                        let mut __bf_nested = self.#outer_get_ident();
+                        __bf_nested.#inner_set_ident(new_val);
+                        self.#outer_set_ident(__bf_nested)
+                    }
+
+                    #[doc = #with_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #vis fn #with_ident(mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) -> Self {
+                        self.#set_ident(new_val);
+                        self
+                    }
+                ))
+            }
+            false => None,
+        };
+        quote_spanned!(span=>
+            #getter
+            #setters
+        )
+    }
+
+    /// Converts the given `snake_case` identifier into `CamelCase`.
+    fn camel_case(ident: &str) -> String {
+        ident
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Converts the given `CamelCase` identifier into `snake_case`.
+    fn snake_case(ident: &str) -> String {
+        let chars = ident.chars().collect::<Vec<_>>();
+        let mut snake = String::new();
+        for (n, &c) in chars.iter().enumerate() {
+            if c.is_uppercase() && n > 0 {
+                let prev = chars[n - 1];
+                let next_is_lower = chars.get(n + 1).is_some_and(|next| next.is_lowercase());
+                if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                    snake.push('_');
+                }
+            }
+            snake.extend(c.to_lowercase());
+        }
+        snake
+    }
+
+    /// Generates compile-time assertions that all fields with an `#[overlaps(..)]` attribute
+    /// end within the bits of the bitfield.
+    fn expand_overlap_checks(&self, config: &Config) -> TokenStream2 {
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let checks = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| info.config.overlapped_field().is_some())
+            .map(|(info, offset)| {
+                let span = info.field.span();
+                let ty = &info.field.ty;
+                quote_spanned!(span=>
+                    #[allow(clippy::identity_op)]
+                    const _: () = {
+                        ::modular_bitfield::private::static_assertions::const_assert!(
+                            #offset + <#ty as ::modular_bitfield::Specifier>::BITS <= #size
+                        );
+                    };
+                )
+            });
+        quote! { #( #checks )* }
+    }
+
+    /// Generates `From` impls and bit counting helpers for a `#[repr(uN)]` annotated #[bitfield] struct.
     fn expand_repr_from_impls_and_checks(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
         let ident = &self.item_struct.ident;
         config.repr.as_ref().map(|repr| {
             let kind = &repr.value;
@@ -368,14 +923,31 @@ impl BitfieldStruct {
                 ReprKind::U64 => quote! { ::core::primitive::u64 },
                 ReprKind::U128 => quote! { ::core::primitive::u128 },
             };
-            let actual_bits = self.generate_target_or_actual_bitfield_size(config);
             let trait_check_ident = match kind {
-                ReprKind::U8 => quote! { IsU8Compatible },
-                ReprKind::U16 => quote! { IsU16Compatible },
-                ReprKind::U32 => quote! { IsU32Compatible },
-                ReprKind::U64 => quote! { IsU64Compatible },
-                ReprKind::U128 => quote! { IsU128Compatible },
+                ReprKind::U8 => quote_spanned!(span=> IsU8Compatible),
+                ReprKind::U16 => quote_spanned!(span=> IsU16Compatible),
+                ReprKind::U32 => quote_spanned!(span=> IsU32Compatible),
+                ReprKind::U64 => quote_spanned!(span=> IsU64Compatible),
+                ReprKind::U128 => quote_spanned!(span=> IsU128Compatible),
             };
+            match self.known_bitfield_size(config) {
+                Some(bits) if bits < kind.bits() && bits > kind.narrower_bits() => {
+                    return self.expand_oversized_repr(config, &prim, kind.bits(), bits, span)
+                }
+                Some(bits) if bits != kind.bits() => {
+                    // Only emit the failing check so that the mismatch is reported once
+                    // on the `#[repr]` primitive instead of on every conversion.
+                    return quote_spanned!(span=>
+                        impl #ident
+                        where
+                            [(); #bits]: ::modular_bitfield::private::#trait_check_ident,
+                        {
+                        }
+                    )
+                }
+                _ => (),
+            }
+            let actual_bits = self.generate_target_or_actual_bitfield_size(config);
             quote_spanned!(span=>
                 impl ::core::convert::From<#prim> for #ident
                 where
@@ -383,7 +955,7 @@ impl BitfieldStruct {
                 {
                     #[inline]
                     fn from(__bf_prim: #prim) -> Self {
-                        Self { bytes: <#prim>::to_le_bytes(__bf_prim) }
+                        Self { #markers #storage: <#prim>::to_le_bytes(__bf_prim) }
                     }
                 }
 
@@ -393,67 +965,3174 @@ impl BitfieldStruct {
                 {
                     #[inline]
                     fn from(__bf_bitfield: #ident) -> Self {
-                        <Self>::from_le_bytes(__bf_bitfield.bytes)
+                        <Self>::from_le_bytes(__bf_bitfield.#storage)
                     }
                 }
-            )
-        })
-    }
 
-    /// Generates routines to allow conversion from and to bytes for the `#[bitfield]` struct.
-    fn expand_byte_conversion_impls(&self, config: &Config) -> TokenStream2 {
-        let span = self.item_struct.span();
-        let ident = &self.item_struct.ident;
-        let size = self.generate_target_or_actual_bitfield_size(config);
-        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
-        let from_bytes = match config.filled_enabled() {
-            true => {
-                quote_spanned!(span=>
-                    /// Converts the given bytes directly into the bitfield struct.
+                impl #ident
+                where
+                    [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
+                {
+                    /// Returns the number of ones in the binary representation of the bitfield.
                     #[inline]
-                    #[allow(clippy::identity_op)]
-                    pub const fn from_bytes(bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
-                        Self { bytes }
+                    pub fn count_ones(&self) -> ::core::primitive::u32 {
+                        <#prim>::from_le_bytes(self.#storage).count_ones()
                     }
-                )
-            }
-            false => {
-                quote_spanned!(span=>
-                    /// Converts the given bytes directly into the bitfield struct.
+
+                    /// Returns the number of zeros in the binary representation of the bitfield.
+                    #[inline]
+                    pub fn count_zeros(&self) -> ::core::primitive::u32 {
+                        <#prim>::from_le_bytes(self.#storage).count_zeros()
+                    }
+
+                    /// Returns the number of leading zeros in the binary representation of the bitfield.
                     ///
-                    /// # Errors
+                    /// The most significant bit belongs to the last field of the bitfield.
+                    #[inline]
+                    pub fn leading_zeros(&self) -> ::core::primitive::u32 {
+                        <#prim>::from_le_bytes(self.#storage).leading_zeros()
+                    }
+
+                    /// Returns the number of trailing zeros in the binary representation of the bitfield.
                     ///
-                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    /// The least significant bit belongs to the first field of the bitfield.
                     #[inline]
-                    #[allow(clippy::identity_op)]
-                    pub fn from_bytes(
-                        bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
-                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
-                        if bytes[(#next_divisible_by_8 / 8usize) - 1] >= (0x01 << (8 - (#next_divisible_by_8 - #size))) {
-                            return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
-                        }
-                        ::core::result::Result::Ok(Self { bytes })
+                    pub fn trailing_zeros(&self) -> ::core::primitive::u32 {
+                        <#prim>::from_le_bytes(self.#storage).trailing_zeros()
                     }
-                )
-            }
-        };
+                }
+            )
+        })
+    }
+
+    /// Generates the conversions and bit counting methods for a `#[repr(uN)]` primitive
+    /// that has more bits than the bitfield which fits into no narrower primitive.
+    ///
+    /// Conversions into the primitive zero-extend the bitfield while conversions from
+    /// the primitive are fallible and reject values with bits set beyond the bitfield.
+    fn expand_oversized_repr(
+        &self,
+        config: &Config,
+        prim: &TokenStream2,
+        prim_bits: usize,
+        bits: usize,
+        span: Span,
+    ) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        let ident = &self.item_struct.ident;
+        let extra_bits = (prim_bits - bits) as u32;
+        let len = (bits + 7) / 8;
         quote_spanned!(span=>
-            impl #ident {
-                /// Returns the underlying bits.
-                ///
-                /// # Layout
-                ///
-                /// The returned byte array is layed out in the same way as described
-                /// [here](https://docs.rs/modular-bitfield/#generated-structure).
+            impl ::core::convert::From<#ident> for #prim {
+                /// Zero-extends the bits of the bitfield.
                 #[inline]
-                #[allow(clippy::identity_op)]
-                pub const fn into_bytes(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
-                    self.bytes
+                fn from(__bf_bitfield: #ident) -> Self {
+                    __bf_bitfield.__bf_repr()
                 }
+            }
+
+            impl ::core::convert::TryFrom<#prim> for #ident {
+                type Error = ::modular_bitfield::error::OutOfBounds;
+
+                /// Converts the primitive into the bitfield.
+                ///
+                /// # Errors
+                ///
+                /// If the primitive has bits set beyond the bits of the bitfield.
+                #[inline]
+                fn try_from(__bf_prim: #prim) -> ::core::result::Result<Self, Self::Error> {
+                    if __bf_prim >> #bits != 0 {
+                        return ::core::result::Result::Err(
+                            ::modular_bitfield::error::OutOfBounds::with_value(__bf_prim as ::core::primitive::u128, #bits)
+                        )
+                    }
+                    let mut __bf_bytes = [0u8; #len];
+                    __bf_bytes.copy_from_slice(&<#prim>::to_le_bytes(__bf_prim)[..#len]);
+                    ::core::result::Result::Ok(Self { #markers #storage: __bf_bytes })
+                }
+            }
+
+            impl #ident {
+                /// Returns the bits of the bitfield zero-extended to the `#[repr]` primitive.
+                #[inline]
+                fn __bf_repr(&self) -> #prim {
+                    let mut __bf_bytes = [0u8; ::core::mem::size_of::<#prim>()];
+                    __bf_bytes[..#len].copy_from_slice(&self.#storage);
+                    <#prim>::from_le_bytes(__bf_bytes)
+                }
+
+                /// Returns the number of ones in the binary representation of the bitfield.
+                #[inline]
+                pub fn count_ones(&self) -> ::core::primitive::u32 {
+                    self.__bf_repr().count_ones()
+                }
+
+                /// Returns the number of zeros in the binary representation of the bitfield.
+                ///
+                /// Only the bits of the bitfield are counted, not those of the `#[repr]` primitive.
+                #[inline]
+                pub fn count_zeros(&self) -> ::core::primitive::u32 {
+                    self.__bf_repr().count_zeros() - #extra_bits
+                }
+
+                /// Returns the number of leading zeros in the binary representation of the bitfield.
+                ///
+                /// The most significant bit belongs to the last field of the bitfield.
+                /// Only the bits of the bitfield are counted, not those of the `#[repr]` primitive.
+                #[inline]
+                pub fn leading_zeros(&self) -> ::core::primitive::u32 {
+                    self.__bf_repr().leading_zeros() - #extra_bits
+                }
+
+                /// Returns the number of trailing zeros in the binary representation of the bitfield.
+                ///
+                /// The least significant bit belongs to the first field of the bitfield.
+                #[inline]
+                pub fn trailing_zeros(&self) -> ::core::primitive::u32 {
+                    self.__bf_repr().trailing_zeros().min(#bits as ::core::primitive::u32)
+                }
+            }
+        )
+    }
+
+    /// Generates the `BITS` and `BYTES` constants as well as the `LAYOUT` constant
+    /// describing the fields of the `#[bitfield]` struct.
+    fn expand_layout(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let descriptors = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            let span = info.field.span();
+            let ty = &info.field.ty;
+            let name = info.name();
+            let skipped = info.config.skip_all();
+            let kind = match FieldKind::of(ty) {
+                FieldKind::Bool => quote_spanned!(span=> Bool),
+                FieldKind::Unsigned => quote_spanned!(span=> Unsigned),
+                FieldKind::Specifier => quote_spanned!(span=> Specifier),
+            };
+            let bits = info.bits_expr();
+            quote_spanned!(span=>
+                ::modular_bitfield::layout::FieldDescriptor {
+                    name: #name,
+                    offset: #offset,
+                    bits: #bits,
+                    kind: ::modular_bitfield::layout::FieldKind::#kind,
+                    skipped: #skipped,
+                }
+            )
+        });
+        quote_spanned!(span=>
+            impl #ident {
+                /// The number of bits of the bitfield.
+                #[allow(clippy::identity_op)]
+                pub const BITS: ::core::primitive::usize = #size;
+
+                /// The number of bytes storing the bitfield.
+                #[allow(clippy::identity_op)]
+                pub const BYTES: ::core::primitive::usize = #next_divisible_by_8 / 8usize;
+
+                /// The layout of the fields of the bitfield in declaration order.
+                #[allow(clippy::identity_op)]
+                pub const LAYOUT: &'static [::modular_bitfield::layout::FieldDescriptor] = &[
+                    #( #descriptors ),*
+                ];
+
+                /// A fingerprint of the names, order and widths of the fields of the bitfield.
+                pub const LAYOUT_HASH: ::core::primitive::u64 =
+                    ::modular_bitfield::layout::layout_hash(Self::LAYOUT);
+            }
+        )
+    }
+
+    /// Generates the `From` impls copying the fields by name for the `migrate_from` parameter.
+    ///
+    /// Fields without a counterpart in the migrated bitfield are zero initialized.
+    /// A compile-time check rejects fields with matching names that would lose data.
+    fn expand_migrate_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        let migrate_from = config.migrate_from.as_ref()?;
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let impls = migrate_from.value.iter().map(|other| {
+            let span = other.span();
+            quote_spanned!(span=>
+                const _: () = ::core::assert!(
+                    ::modular_bitfield::private::is_lossless_migration(<#other>::LAYOUT, <#ident>::LAYOUT),
+                    "migrate_from requires fields with matching names to have the same kind and bits \
+                     except for unsigned integer fields which may be widened",
+                );
+
+                impl ::core::convert::From<#other> for #ident {
+                    /// Migrates the fields of the given bitfield with matching names.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    fn from(value: #other) -> Self {
+                        let mut migrated = Self {
+                            #markers #storage: [0u8; #next_divisible_by_8 / 8usize],
+                        };
+                        ::modular_bitfield::private::copy_fields(&mut migrated, &value);
+                        migrated
+                    }
+                }
+            )
+        });
+        Some(quote! { #( #impls )* })
+    }
+
+    /// Generates the impls of all `#[bitfield_trait]` traits given to `impl_trait`.
+    ///
+    /// Each trait comes with a hidden macro of the same name that forwards the
+    /// methods of the trait to the inherent methods of the bitfield.
+    fn expand_trait_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let impl_trait = config.impl_trait.as_ref()?;
+        let ident = &self.item_struct.ident;
+        let impls = impl_trait.value.iter().map(|path| {
+            let span = path.span();
+            quote_spanned!(span=>
+                #path!(#ident, #path);
+            )
+        });
+        Some(quote! { #( #impls )* })
+    }
+
+    /// Generates the `PartialOrd` and `Ord` impls for the `ord_by` parameter.
+    ///
+    /// The bits of the given fields are compared in order of priority as unsigned integers.
+    /// Ties are broken by the underlying bytes so that the ordering agrees with `PartialEq`.
+    fn expand_ord_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let ord_by = config.ord_by.as_ref()?;
+        let storage = config.storage_ident();
+        let ident = &self.item_struct.ident;
+        let fields = self.field_infos_with_offsets(config).collect::<Vec<_>>();
+        let comparisons = ord_by.value.iter().filter_map(|name| {
+            let (info, offset) = fields
+                .iter()
+                .find(|(info, _)| info.field.ident.as_ref() == Some(name))?;
+            let span = name.span();
+            let ty = &info.field.ty;
+            Some(quote_spanned!(span=>
+                .then_with(|| ::core::cmp::Ord::cmp(
+                    &::modular_bitfield::private::read_specifier::<#ty>(&self.#storage[..], #offset),
+                    &::modular_bitfield::private::read_specifier::<#ty>(&other.#storage[..], #offset),
+                ))
+            ))
+        });
+        Some(quote_spanned!(ord_by.span=>
+            impl ::core::cmp::PartialOrd for #ident {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            impl ::core::cmp::Ord for #ident {
+                #[inline]
+                #[allow(clippy::identity_op)]
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    ::core::cmp::Ordering::Equal
+                        #( #comparisons )*
+                        .then_with(|| ::core::cmp::Ord::cmp(&self.#storage, &other.#storage))
+                }
+            }
+        ))
+    }
+
+    /// Generates the table of bit positions per field appended to the docs of the struct.
+    ///
+    /// Returns `None` if `layout_docs = false` or if the bit width of any field cannot
+    /// be determined without type information, e.g. for enum fields without `#[bits = N]`.
+    fn expand_layout_docs(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.layout_docs_enabled() {
+            return None
+        }
+        let mut rows = Vec::new();
+        for (info, _, offset) in self.field_infos_with_known_offsets(config) {
+            let (offset, bits) = (offset?, info.known_bits()?);
+            let range = match bits {
+                1 => format!("{}", offset),
+                _ => format!("{}..={}", offset, offset + bits - 1),
+            };
+            let reserved = match info.config.skip_all() {
+                true => " (reserved)",
+                false => "",
+            };
+            rows.push(format!(" | `{}` | `{}`{} |", range, info.name(), reserved));
+        }
+        let span = self.item_struct.span();
+        Some(quote_spanned!(span=>
+            #[doc = ""]
+            #[doc = " # Layout"]
+            #[doc = ""]
+            #[doc = " | Bits | Field |"]
+            #[doc = " |:-----|:------|"]
+            #( #[doc = #rows] )*
+        ))
+    }
+
+    /// Generates the `RawFields` impl providing access to the fields by their names.
+    ///
+    /// Also generates the public `Reflect` impl on top of it if the byte conversions are
+    /// public or `reflect = true` is given.
+    fn expand_reflect_impl(&self, config: &Config) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let validations = self
+            .field_infos(config)
+            .filter(|info| {
+                !info.config.skip_all() && matches!(FieldKind::of(&info.field.ty), FieldKind::Specifier)
+            })
+            .map(|info| {
+                let span = info.field.span();
+                let ty = &info.field.ty;
+                let name = info.name();
+                let cfg_attrs = info.cfg_attrs();
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    #name => <#ty as ::modular_bitfield::Specifier>::from_bytes(
+                        <<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::from_u128(value),
+                    )
+                    .is_ok(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let is_valid = match validations.is_empty() {
+            true => {
+                quote_spanned!(span=>
+                    fn __bf_is_valid(_: &::core::primitive::str, _: ::core::primitive::u128) -> ::core::primitive::bool {
+                        true
+                    }
+                )
+            }
+            false => {
+                quote_spanned!(span=>
+                    fn __bf_is_valid(name: &::core::primitive::str, value: ::core::primitive::u128) -> ::core::primitive::bool {
+                        match name {
+                            #( #validations )*
+                            _ => true,
+                        }
+                    }
+                )
+            }
+        };
+        let reflect = match config.reflect_enabled() {
+            true => {
+                Some(quote_spanned!(span=>
+                    impl ::modular_bitfield::layout::Reflect for #ident {}
+                ))
+            }
+            false => None,
+        };
+        quote_spanned!(span=>
+            impl ::modular_bitfield::private::RawFields for #ident {
+                const LAYOUT: &'static [::modular_bitfield::layout::FieldDescriptor] = <#ident>::LAYOUT;
+
+                #[inline]
+                fn __bf_bytes(&self) -> &[::core::primitive::u8] {
+                    &self.#storage[..]
+                }
+
+                #[inline]
+                fn __bf_bytes_mut(&mut self) -> &mut [::core::primitive::u8] {
+                    &mut self.#storage[..]
+                }
+
+                #[inline]
+                #is_valid
+            }
+
+            #reflect
+        )
+    }
+
+    /// Generates the `__bf_debug_assert_reserved` method for `check_reserved = true`.
+    ///
+    /// The method debug asserts that the bits of all entirely `#[skip]`ped fields are zero.
+    fn expand_reserved_check(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        if !config.check_reserved_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let checks = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| info.config.skip_all())
+            .map(|(info, offset)| {
+                let span = info.field.span();
+                let ty = &info.field.ty;
+                let assert_msg = format!(
+                    "reserved bits of field {}.{} are not zero",
+                    ident,
+                    info.name()
+                );
+                let cfg_attrs = info.cfg_attrs();
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    ::core::debug_assert!(
+                        <<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::into_u128(
+                            ::modular_bitfield::private::read_specifier::<#ty>(&self.#storage[..], #offset)
+                        ) == 0,
+                        #assert_msg
+                    );
+                )
+            });
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #[doc(hidden)]
+                #[inline]
+                #[allow(clippy::identity_op)]
+                fn __bf_debug_assert_reserved(&self) {
+                    #( #checks )*
+                }
+            }
+        ))
+    }
+
+    /// Generates routines to allow conversion from and to bytes for the `#[bitfield]` struct.
+    ///
+    /// `as_bytes` and `as_bytes_mut` are not generated if the getter of a field with the same name
+    /// already occupies it.
+    fn expand_byte_conversion_impls(&self, config: &Config) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let vis = config.bytes_visibility();
+        let padding_check = self.expand_padding_check(config);
+        let occupied = |name: &str| {
+            self.field_infos(config)
+                .any(|info| info.name() == name && !info.config.skip_getters())
+        };
+        let try_from_bytes = self.expand_try_from_bytes(config);
+        let from_bytes = match (config.filled_enabled(), config.check_reserved_enabled()) {
+            (true, true) => {
+                quote_spanned!(span=>
+                    /// Converts the given bytes directly into the bitfield struct.
+                    ///
+                    /// # Panics
+                    ///
+                    /// In debug builds if the given bytes contain non-zero reserved bits.
+                    #[inline]
+                    #[allow(clippy::identity_op, dead_code)]
+                    #vis fn from_bytes(bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
+                        let __bf_bitfield = Self { #markers #storage: bytes };
+                        __bf_bitfield.__bf_debug_assert_reserved();
+                        __bf_bitfield
+                    }
+                )
+            }
+            (true, false) => {
+                quote_spanned!(span=>
+                    /// Converts the given bytes directly into the bitfield struct.
+                    #[inline]
+                    #[allow(clippy::identity_op, dead_code)]
+                    #vis const fn from_bytes(bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
+                        Self { #markers #storage: bytes }
+                    }
+                )
+            }
+            (false, check_reserved) => {
+                // The debug assertion of the reserved bits is not `const` compatible.
+                let (check_reserved, constness) = match check_reserved {
+                    true => {
+                        (
+                            Some(quote_spanned!(span=> __bf_bitfield.__bf_debug_assert_reserved();)),
+                            None,
+                        )
+                    }
+                    false => (None, Some(quote_spanned!(span=> const))),
+                };
+                quote_spanned!(span=>
+                    /// Converts the given bytes directly into the bitfield struct.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    #[inline]
+                    #[allow(clippy::identity_op, dead_code)]
+                    #vis #constness fn from_bytes(
+                        bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                        #padding_check
+                        let __bf_bitfield = Self { #markers #storage: bytes };
+                        #check_reserved
+                        ::core::result::Result::Ok(__bf_bitfield)
+                    }
+                )
+            }
+        };
+        let from_bytes_unchecked = match config.filled_enabled() {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Converts the given bytes directly into the bitfield struct without
+                    /// checking the bits at positions that are undefined for `Self`.
+                    ///
+                    /// Useful in const initializers and hot paths with bytes that are known to be valid.
+                    /// Bits set at undefined positions are retained by `into_bytes`.
+                    #[inline]
+                    #[allow(clippy::identity_op, dead_code)]
+                    #vis const fn from_bytes_unchecked(
+                        bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                    ) -> Self {
+                        Self { #markers #storage: bytes }
+                    }
+                ))
+            }
+        };
+        let from_bytes_slice_result = match config.filled_enabled() {
+            true => {
+                quote_spanned!(span=>
+                    ::core::result::Result::Ok(Self::from_bytes(__bf_array))
+                )
+            }
+            false => {
+                quote_spanned!(span=>
+                    Self::from_bytes(__bf_array)
+                        .map_err(|_| ::modular_bitfield::error::FromSliceError::InvalidPadding)
+                )
+            }
+        };
+        let as_bytes_mut = match config.filled_enabled() && !occupied("as_bytes_mut") {
+            true => {
+                Some(quote_spanned!(span=>
+                    /// Returns an exclusive reference to the underlying bits.
+                    ///
+                    /// # Layout
+                    ///
+                    /// The returned byte array is layed out in the same way as described
+                    /// [here](https://docs.rs/modular-bitfield/#generated-structure).
+                    #[inline]
+                    #[allow(clippy::identity_op, dead_code)]
+                    #vis fn as_bytes_mut(&mut self) -> &mut [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                        &mut self.#storage
+                    }
+                ))
+            }
+            false => None,
+        };
+        let as_bytes = match occupied("as_bytes") {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Returns a shared reference to the underlying bits.
+                    ///
+                    /// # Layout
+                    ///
+                    /// The returned byte array is layed out in the same way as described
+                    /// [here](https://docs.rs/modular-bitfield/#generated-structure).
+                    #[inline]
+                    #[allow(clippy::identity_op, dead_code)]
+                    #vis const fn as_bytes(&self) -> &[::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                        &self.#storage
+                    }
+                ))
+            }
+        };
+        quote_spanned!(span=>
+            impl #ident {
+                /// Returns the underlying bits.
+                ///
+                /// # Layout
+                ///
+                /// The returned byte array is layed out in the same way as described
+                /// [here](https://docs.rs/modular-bitfield/#generated-structure).
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis const fn into_bytes(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                    self.#storage
+                }
+
+                #as_bytes
+                #as_bytes_mut
 
                 #from_bytes
+
+                #from_bytes_unchecked
+
+                #try_from_bytes
+
+                /// Converts the prefix of the given byte slice into the bitfield struct.
+                ///
+                /// Bytes following the prefix required by the bitfield are ignored.
+                ///
+                /// # Errors
+                ///
+                /// - If the given byte slice contains fewer bytes than required.
+                /// - If the given bytes contain bits at positions that are undefined for `Self`.
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis fn from_bytes_slice(
+                    bytes: &[::core::primitive::u8],
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::FromSliceError> {
+                    let __bf_len: ::core::primitive::usize = #next_divisible_by_8 / 8usize;
+                    if bytes.len() < __bf_len {
+                        return ::core::result::Result::Err(
+                            ::modular_bitfield::error::FromSliceError::TooShort {
+                                expected: __bf_len,
+                                found: bytes.len(),
+                            }
+                        )
+                    }
+                    let mut __bf_array = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    __bf_array.copy_from_slice(&bytes[..__bf_len]);
+                    #from_bytes_slice_result
+                }
+            }
+        )
+    }
+
+    /// Generates the statement returning `OutOfBounds` if the given `bytes` have
+    /// bits set at positions that are undefined for a bitfield with `filled = false`.
+    fn expand_padding_check(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        self.expand_padding_check_with_error(
+            config,
+            quote_spanned!(span=> ::modular_bitfield::error::OutOfBounds::new()),
+        )
+    }
+
+    /// Generates the statement returning the given error if the given `bytes` have
+    /// bits set at positions that are undefined for a bitfield with `filled = false`.
+    fn expand_padding_check_with_error(&self, config: &Config, error: TokenStream2) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        quote_spanned!(span=>
+            if (bytes[(#next_divisible_by_8 / 8usize) - 1] as ::core::primitive::u16) >> (8 - (#next_divisible_by_8 - #size)) != 0 {
+                return ::core::result::Result::Err(#error)
+            }
+        )
+    }
+
+    /// Generates the strict `try_from_bytes` constructor that validates the
+    /// padding bits as well as the bit patterns of all fields up front.
+    ///
+    /// Returns `None` if the getter of a field named `try_from_bytes` already occupies the name.
+    fn expand_try_from_bytes(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        let occupied = self
+            .field_infos(config)
+            .any(|info| info.name() == "try_from_bytes" && !info.config.skip_getters());
+        if occupied {
+            return None
+        }
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let vis = config.bytes_visibility();
+        let padding_check = match config.filled_enabled() {
+            true => None,
+            false => {
+                Some(self.expand_padding_check_with_error(
+                    config,
+                    quote_spanned!(span=> ::modular_bitfield::error::DecodeError::InvalidPadding),
+                ))
+            }
+        };
+        let field_checks = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            let span = info.field.span();
+            let ty = &info.field.ty;
+            let name = info.name();
+            let validate_value = info.config.validator().map(|validator| {
+                quote_spanned!(validator.span()=>
+                    if #validator(&__bf_value).is_err() {
+                        return ::core::result::Result::Err(::modular_bitfield::error::DecodeError::InvalidValue {
+                            field: #name,
+                        })
+                    }
+                )
+            });
+            let cfg_attrs = info.cfg_attrs();
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                match <#ty as ::modular_bitfield::Specifier>::from_bytes(
+                    ::modular_bitfield::private::read_specifier::<#ty>(&bytes[..], #offset)
+                ) {
+                    ::core::result::Result::Ok(__bf_value) => {
+                        #validate_value
+                    }
+                    ::core::result::Result::Err(__bf_err) => {
+                        return ::core::result::Result::Err(::modular_bitfield::error::DecodeError::InvalidField {
+                            field: #name,
+                            invalid_bits: <<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::into_u128(
+                                __bf_err.invalid_bytes
+                            ),
+                        })
+                    }
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            /// Converts the given bytes into the bitfield struct after validating
+            /// the bit patterns of all its fields.
+            ///
+            /// # Errors
+            ///
+            /// - If the given bytes contain bits at positions that are undefined for `Self`.
+            /// - If the given bytes contain an invalid bit pattern for any of the fields.
+            #[inline]
+            #[allow(clippy::identity_op, clippy::single_match, dead_code)]
+            #vis fn try_from_bytes(
+                bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+            ) -> ::core::result::Result<Self, ::modular_bitfield::error::DecodeError> {
+                #padding_check
+                #( #field_checks )*
+                ::core::result::Result::Ok(Self { #markers #storage: bytes })
+            }
+        ))
+    }
+
+    /// Generates `to_{le,be,ne}_bytes` and `from_{le,be,ne}_bytes` conversions.
+    ///
+    /// The internal layout of the bitfield bytes is little-endian, therefore the
+    /// big-endian conversions reverse the order of the bytes.
+    fn expand_endian_byte_conversions(&self, config: &Config) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let vis = config.bytes_visibility();
+        let from_bytes_output = match config.filled_enabled() {
+            true => quote_spanned!(span=> Self),
+            false => {
+                quote_spanned!(span=>
+                    ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds>
+                )
+            }
+        };
+        let errors_doc = match config.filled_enabled() {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                ))
+            }
+        };
+        quote_spanned!(span=>
+            impl #ident {
+                /// Returns the underlying bits in little-endian byte order.
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis const fn to_le_bytes(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                    self.#storage
+                }
+
+                /// Returns the underlying bits in big-endian byte order.
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis fn to_be_bytes(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                    let mut __bf_bytes = self.#storage;
+                    __bf_bytes.reverse();
+                    __bf_bytes
+                }
+
+                /// Returns the underlying bits in the native byte order of the target platform.
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis fn to_ne_bytes(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                    if ::core::cfg!(target_endian = "big") {
+                        self.to_be_bytes()
+                    } else {
+                        self.to_le_bytes()
+                    }
+                }
+
+                /// Converts the given bytes in little-endian byte order into the bitfield struct.
+                #errors_doc
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis fn from_le_bytes(
+                    bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                ) -> #from_bytes_output {
+                    Self::from_bytes(bytes)
+                }
+
+                /// Converts the given bytes in big-endian byte order into the bitfield struct.
+                #errors_doc
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis fn from_be_bytes(
+                    mut bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                ) -> #from_bytes_output {
+                    bytes.reverse();
+                    Self::from_bytes(bytes)
+                }
+
+                /// Converts the given bytes in the native byte order of the target platform into the bitfield struct.
+                #errors_doc
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis fn from_ne_bytes(
+                    bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                ) -> #from_bytes_output {
+                    if ::core::cfg!(target_endian = "big") {
+                        Self::from_be_bytes(bytes)
+                    } else {
+                        Self::from_le_bytes(bytes)
+                    }
+                }
+            }
+        )
+    }
+
+    /// Generates the `to_perm_bytes` and `from_perm_bytes` conversions for the `byte_perm` parameter.
+    ///
+    /// The permutation reverses the order of the words of the underlying bytes which is checked
+    /// at compile time to consist of whole words.
+    fn expand_byte_perm_conversions(&self, config: &Config) -> Option<TokenStream2> {
+        let byte_perm = config.byte_perm.as_ref()?;
+        let storage = config.storage_ident();
+        let span = byte_perm.span;
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let vis = config.bytes_visibility();
+        let word_bytes = byte_perm.value.word_bytes();
+        let from_bytes_output = match config.filled_enabled() {
+            true => quote_spanned!(span=> Self),
+            false => {
+                quote_spanned!(span=>
+                    ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds>
+                )
+            }
+        };
+        let errors_doc = match config.filled_enabled() {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                ))
+            }
+        };
+        let word_docs = format!(
+            " The order of the little-endian {}-bit words is reversed.",
+            word_bytes * 8
+        );
+        Some(quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            const _: () = {
+                ::modular_bitfield::private::static_assertions::const_assert_eq!(
+                    (#next_divisible_by_8 / 8usize) % #word_bytes,
+                    0
+                );
+            };
+
+            impl #ident {
+                /// Returns the underlying bits in the byte order given by the `byte_perm` parameter.
+                ///
+                #[doc = #word_docs]
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis const fn to_perm_bytes(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                    ::modular_bitfield::private::reverse_words(self.#storage, #word_bytes)
+                }
+
+                /// Converts the given bytes in the byte order given by the `byte_perm` parameter
+                /// into the bitfield struct.
+                ///
+                #[doc = #word_docs]
+                #errors_doc
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                #vis fn from_perm_bytes(
+                    bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                ) -> #from_bytes_output {
+                    Self::from_bytes(::modular_bitfield::private::reverse_words(bytes, #word_bytes))
+                }
+            }
+        ))
+    }
+
+    /// Generates `AsRef<[u8]>` and `AsMut<[u8]>` impls if `as_ref = true` is set.
+    ///
+    /// The `AsMut<[u8]>` impl is only generated for bitfields with `filled = true`.
+    fn expand_as_ref_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        if !config.as_ref_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let as_mut_impl = match config.filled_enabled() {
+            true => {
+                Some(quote_spanned!(span=>
+                    impl ::core::convert::AsMut<[::core::primitive::u8]> for #ident {
+                        #[inline]
+                        fn as_mut(&mut self) -> &mut [::core::primitive::u8] {
+                            &mut self.#storage[..]
+                        }
+                    }
+                ))
+            }
+            false => None,
+        };
+        Some(quote_spanned!(span=>
+            impl ::core::convert::AsRef<[::core::primitive::u8]> for #ident {
+                #[inline]
+                fn as_ref(&self) -> &[::core::primitive::u8] {
+                    &self.#storage[..]
+                }
+            }
+
+            #as_mut_impl
+        ))
+    }
+
+    /// Generates the bitwise operator impls operating on the underlying bytes if `bit_ops = true` is set.
+    ///
+    /// For bitfields with `filled = false` the `Not` impl keeps the undefined bits zeroed.
+    fn expand_bit_ops_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        if !config.bit_ops_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let binary_ops = [
+            ("BitAnd", "bitand", "BitAndAssign", "bitand_assign", quote!(&=)),
+            ("BitOr", "bitor", "BitOrAssign", "bitor_assign", quote!(|=)),
+            ("BitXor", "bitxor", "BitXorAssign", "bitxor_assign", quote!(^=)),
+        ];
+        let binary_impls = binary_ops.iter().map(|(op, method, op_assign, method_assign, assign)| {
+            let op = format_ident!("{}", op);
+            let method = format_ident!("{}", method);
+            let op_assign = format_ident!("{}", op_assign);
+            let method_assign = format_ident!("{}", method_assign);
+            quote_spanned!(span=>
+                impl ::core::ops::#op for #ident {
+                    type Output = Self;
+
+                    #[inline]
+                    fn #method(mut self, rhs: Self) -> Self::Output {
+                        ::core::ops::#op_assign::#method_assign(&mut self, rhs);
+                        self
+                    }
+                }
+
+                impl ::core::ops::#op_assign for #ident {
+                    #[inline]
+                    fn #method_assign(&mut self, rhs: Self) {
+                        for (lhs, rhs) in self.#storage.iter_mut().zip(rhs.#storage.iter()) {
+                            *lhs #assign *rhs;
+                        }
+                    }
+                }
+            )
+        });
+        let mask_undefined_bits = match config.filled_enabled() {
+            true => None,
+            false => {
+                let size = self.generate_bitfield_size(config);
+                Some(quote_spanned!(span=>
+                    let __bf_last = self.#storage.len() - 1;
+                    self.#storage[__bf_last] &= (0x01 << ((#size) % 8)) - 1;
+                ))
+            }
+        };
+        Some(quote_spanned!(span=>
+            #( #binary_impls )*
+
+            impl ::core::ops::Not for #ident {
+                type Output = Self;
+
+                #[inline]
+                #[allow(clippy::identity_op)]
+                fn not(mut self) -> Self::Output {
+                    for byte in self.#storage.iter_mut() {
+                        *byte = !*byte;
+                    }
+                    #mask_undefined_bits
+                    self
+                }
+            }
+        ))
+    }
+
+    /// Generates the borrowed `View` and `ViewMut` types if `views = true` is set.
+    ///
+    /// For a `#[bitfield]` struct `Foo` these are `FooView<'a>` and `FooViewMut<'a>`
+    /// that provide the same getters (and setters) as `Foo` but operate in place
+    /// on a borrowed byte array.
+    fn expand_views(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.views_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let view_ident = format_ident!("{}View", ident);
+        let view_mut_ident = format_ident!("{}ViewMut", ident);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let storage = syn::Member::Unnamed(syn::Index::from(0));
+        let getters = self.expand_field_accessors(config, &storage, false);
+        let getters_and_setters = self.expand_field_accessors(config, &storage, true);
+        let view_docs = format!(
+            "Immutable view over borrowed bytes with the layout of [`{}`].",
+            ident
+        );
+        let view_mut_docs = format!(
+            "Mutable view over borrowed bytes with the layout of [`{}`].",
+            ident
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #view_docs]
+            #[derive(::core::marker::Copy, ::core::clone::Clone)]
+            #[allow(clippy::identity_op)]
+            #vis struct #view_ident<'a>(&'a [::core::primitive::u8; #next_divisible_by_8 / 8usize]);
+
+            #[allow(dead_code, clippy::identity_op)]
+            impl<'a> #view_ident<'a> {
+                /// Creates a view over the given bytes.
+                #[inline]
+                pub const fn new(bytes: &'a [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
+                    Self(bytes)
+                }
+
+                /// Returns the viewed bytes.
+                #[inline]
+                pub const fn as_bytes(&self) -> &'a [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                    self.0
+                }
+
+                #getters
+            }
+
+            #[doc = #view_mut_docs]
+            #[allow(clippy::identity_op)]
+            #vis struct #view_mut_ident<'a>(&'a mut [::core::primitive::u8; #next_divisible_by_8 / 8usize]);
+
+            #[allow(dead_code, clippy::identity_op)]
+            impl<'a> #view_mut_ident<'a> {
+                /// Creates a mutable view over the given bytes.
+                #[inline]
+                pub fn new(bytes: &'a mut [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
+                    Self(bytes)
+                }
+
+                /// Returns an immutable view over the same bytes.
+                #[inline]
+                pub fn as_view(&self) -> #view_ident<'_> {
+                    #view_ident(&*self.0)
+                }
+
+                #getters_and_setters
+            }
+        ))
+    }
+
+    /// Generates the pointer backed `FooPtr` view type if `ptr_view = true` is set.
+    ///
+    /// All accessors of the generated type use volatile reads and writes through the
+    /// raw pointer and only touch the bytes covered by the accessed field.
+    fn expand_ptr_view(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        if !config.ptr_view_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let ptr_ident = format_ident!("{}Ptr", ident);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let accessors = self
+            .field_infos_with_offsets(config)
+            .map(|(info, offset)| {
+                self.expand_load_store_accessors_for_field(&info, &offset, "through the pointer")
+            });
+        let write_accessors = self
+            .field_infos_with_offsets(config)
+            .map(|(info, offset)| self.expand_ptr_write_accessor_for_field(&info, &offset));
+        let ptr_docs = format!(
+            "Accesses a [`{}`] in place through a raw pointer using volatile reads and writes.",
+            ident
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #ptr_docs]
+            #vis struct #ptr_ident(*mut ::core::primitive::u8);
+
+            #[allow(dead_code, clippy::identity_op)]
+            impl #ptr_ident {
+                /// Creates a view that accesses the bitfield through the given pointer.
+                ///
+                /// # Safety
+                ///
+                /// The pointer must be valid for volatile reads and writes of all bytes
+                /// of the bitfield for as long as the returned view is used.
+                #[inline]
+                pub const unsafe fn from_ptr(ptr: *mut ::core::primitive::u8) -> Self {
+                    Self(ptr)
+                }
+
+                /// Returns the underlying pointer.
+                #[inline]
+                pub const fn as_ptr(&self) -> *mut ::core::primitive::u8 {
+                    self.0
+                }
+
+                /// Returns a bitfield with all bits set to zero.
+                #[inline]
+                fn __bf_zeroed() -> #ident {
+                    #ident { #markers #storage: [0u8; #next_divisible_by_8 / 8usize] }
+                }
+
+                /// Reads the bytes covering the given bit range into an otherwise zeroed bitfield.
+                #[inline]
+                fn __bf_load(&self, offset: ::core::primitive::usize, bits: ::core::primitive::usize) -> #ident {
+                    let mut __bf_bitfield = Self::__bf_zeroed();
+                    for __bf_index in (offset / 8)..((offset + bits + 7) / 8) {
+                        // SAFETY: The pointer is valid for reads as guaranteed by `from_ptr`.
+                        __bf_bitfield.#storage[__bf_index] = unsafe {
+                            ::core::ptr::read_volatile(self.0.add(__bf_index))
+                        };
+                    }
+                    __bf_bitfield
+                }
+
+                /// Writes the bytes covering the given bit range from the given bitfield.
+                #[inline]
+                fn __bf_store(&self, bitfield: #ident, offset: ::core::primitive::usize, bits: ::core::primitive::usize) {
+                    for __bf_index in (offset / 8)..((offset + bits + 7) / 8) {
+                        // SAFETY: The pointer is valid for writes as guaranteed by `from_ptr`.
+                        unsafe {
+                            ::core::ptr::write_volatile(self.0.add(__bf_index), bitfield.#storage[__bf_index])
+                        };
+                    }
+                }
+
+                /// Reads the entire bitfield through the pointer.
+                #[inline]
+                pub fn read(&self) -> #ident {
+                    self.__bf_load(0, #size)
+                }
+
+                /// Writes the entire bitfield through the pointer.
+                #[inline]
+                pub fn write(&self, value: #ident) {
+                    self.__bf_store(value, 0, #size)
+                }
+
+                /// Reads the entire bitfield, modifies it with `f` and writes it back.
+                #[inline]
+                pub fn modify<F>(&self, f: F)
+                where
+                    F: ::core::ops::FnOnce(#ident) -> #ident,
+                {
+                    self.write(f(self.read()))
+                }
+
+                #( #accessors )*
+                #( #write_accessors )*
+            }
+
+            impl ::modular_bitfield::VolatileAccess for #ptr_ident {
+                type Bitfield = #ident;
+
+                #[inline]
+                fn read(&self) -> Self::Bitfield {
+                    #ptr_ident::read(self)
+                }
+
+                #[inline]
+                fn write(&self, value: Self::Bitfield) {
+                    #ptr_ident::write(self, value)
+                }
+            }
+        ))
+    }
+
+    /// Generates the write-only `write_f` method of the `FooPtr` view for a single field.
+    ///
+    /// Unlike the setters it does not read the covered bytes first but writes zeros to all
+    /// other bits within them so that write-1-to-clear bits of other fields are not affected.
+    fn expand_ptr_write_accessor_for_field(
+        &self,
+        info: &FieldInfo<'_>,
+        offset: &TokenStream2,
+    ) -> Option<TokenStream2> {
+        let FieldInfo { field, config, .. } = info;
+        if config.skip_setters() {
+            return None
+        }
+        let span = field.span();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let retained_attrs = &config.retained_attrs;
+        let ident = info.ident_frag();
+        let name = info.name();
+        let set_ident = format_ident!("set_{}", ident);
+        let write_ident = format_ident!("write_{}", ident);
+        let write_docs = format!(
+            "Writes the value of {} through the pointer without reading it first.\n\n\
+             All other bits within the written bytes are written as zeros. Use this instead of \
+             the read-modify-write setters for registers with write-1-to-clear bits.\n\n\
+             #Panics\n\n\
+             If the given value is out of bounds for {}.",
+            name, name,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #write_docs]
+            #[inline]
+            #( #retained_attrs )*
+            #vis fn #write_ident(&self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
+                let mut __bf_bitfield = Self::__bf_zeroed();
+                __bf_bitfield.#set_ident(new_val);
+                self.__bf_store(__bf_bitfield, #offset, <#ty as ::modular_bitfield::Specifier>::BITS)
+            }
+        ))
+    }
+
+    /// Generates the getters and setters of the `FooPtr` view or of a bitfield
+    /// with `cell = "volatile"` for a single field.
+    ///
+    /// They load the bytes covering the field with `__bf_load`, forward to the accessors
+    /// of the loaded bitfield and store the modified bytes back with `__bf_store` for setters.
+    fn expand_load_store_accessors_for_field(
+        &self,
+        info: &FieldInfo<'_>,
+        offset: &TokenStream2,
+        via: &str,
+    ) -> TokenStream2 {
+        let FieldInfo { field, config, .. } = info;
+        let span = field.span();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let retained_attrs = &config.retained_attrs;
+        let checked_error = config.checked_setter_error();
+        let name = info.name();
+        let getters = match config.skip_getters() {
+            true => None,
+            false => {
+                let get_ident = info.get_ident();
+                let get_checked_ident = info.get_checked_ident();
+                let getter_docs = format!("Reads the value of {} {}.", name, via);
+                Some(quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                        self.__bf_load(#offset, <#ty as ::modular_bitfield::Specifier>::BITS).#get_ident()
+                    }
+
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #get_checked_ident(
+                        &self,
+                    ) -> ::core::result::Result<
+                        <#ty as ::modular_bitfield::Specifier>::InOut,
+                        ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
+                    > {
+                        self.__bf_load(#offset, <#ty as ::modular_bitfield::Specifier>::BITS).#get_checked_ident()
+                    }
+                ))
+            }
+        };
+        let setters = match config.skip_setters() {
+            true => None,
+            false => {
+                let ident = info.ident_frag();
+                let set_ident = format_ident!("set_{}", ident);
+                let set_checked_ident = format_ident!("set_{}_checked", ident);
+                let setter_docs = format!("Writes the value of {} {}.", name, via);
+                Some(quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #set_ident(&self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
+                        let mut __bf_bitfield = self.__bf_load(#offset, <#ty as ::modular_bitfield::Specifier>::BITS);
+                        __bf_bitfield.#set_ident(new_val);
+                        self.__bf_store(__bf_bitfield, #offset, <#ty as ::modular_bitfield::Specifier>::BITS)
+                    }
+
+                    #[doc = #setter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #set_checked_ident(
+                        &self,
+                        new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+                    ) -> ::core::result::Result<(), #checked_error> {
+                        let mut __bf_bitfield = self.__bf_load(#offset, <#ty as ::modular_bitfield::Specifier>::BITS);
+                        __bf_bitfield.#set_checked_ident(new_val)?;
+                        self.__bf_store(__bf_bitfield, #offset, <#ty as ::modular_bitfield::Specifier>::BITS);
+                        ::core::result::Result::Ok(())
+                    }
+                ))
+            }
+        };
+        let write_one_accessors = match config.write_one_accessors() {
+            true => {
+                let method_ident = match config.access_kind() {
+                    AccessKind::WriteOneToClear => format_ident!("clear_{}", info.ident_frag()),
+                    _ => format_ident!("set_{}", info.ident_frag()),
+                };
+                let docs = format!(
+                    "Writes all ones to {} {} and zeros to all \
+                     other write one fields within the written bytes.",
+                    name, via,
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #method_ident(&self) {
+                        let mut __bf_bitfield = self.__bf_load(#offset, <#ty as ::modular_bitfield::Specifier>::BITS);
+                        __bf_bitfield.#method_ident();
+                        self.__bf_store(__bf_bitfield, #offset, <#ty as ::modular_bitfield::Specifier>::BITS)
+                    }
+                ))
+            }
+            false => None,
+        };
+        quote_spanned!(span=>
+            #getters
+            #setters
+            #write_one_accessors
+        )
+    }
+
+    /// Generates the atomic integer backed `AtomicFoo` type if `atomic = true` is set.
+    ///
+    /// Only bitfields with a size of 1, 2, 4 or 8 bytes are supported since the
+    /// bytes are stored in an `AtomicU8`, `AtomicU16`, `AtomicU32` or `AtomicU64`.
+    fn expand_atomic(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        if !config.atomic_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let atomic_ident = format_ident!("Atomic{}", ident);
+        let next_divisible_by_8 =
+            Self::next_divisible_by_8(&self.generate_target_or_actual_bitfield_size(config));
+        let bytes_ty = quote_spanned!(span=>
+            [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+        );
+        let accessors = self
+            .field_infos_with_offsets(config)
+            .map(|(info, offset)| self.expand_atomic_accessors_for_field(&info, &offset, config));
+        let atomic_docs = format!(
+            "A [`{}`] that can be safely shared between threads and interrupt handlers.",
+            ident
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #atomic_docs]
+            #vis struct #atomic_ident(<#bytes_ty as ::modular_bitfield::private::AtomicBytes>::Atomic);
+
+            #[allow(dead_code)]
+            impl #atomic_ident {
+                /// Creates a new atomic bitfield with all bits set to zero.
+                #[inline]
+                pub const fn new() -> Self {
+                    Self(<#bytes_ty as ::modular_bitfield::private::AtomicBytes>::ZERO)
+                }
+
+                /// Consumes the atomic bitfield and returns the contained bitfield.
+                #[inline]
+                pub fn into_inner(self) -> #ident {
+                    #ident {
+                        #markers #storage: <#bytes_ty as ::modular_bitfield::private::AtomicBytes>::into_inner(self.0),
+                    }
+                }
+
+                /// Loads the bitfield.
+                #[inline]
+                pub fn load(&self, order: ::core::sync::atomic::Ordering) -> #ident {
+                    #ident {
+                        #markers #storage: <#bytes_ty as ::modular_bitfield::private::AtomicBytes>::load(&self.0, order),
+                    }
+                }
+
+                /// Stores the given bitfield.
+                #[inline]
+                pub fn store(&self, value: #ident, order: ::core::sync::atomic::Ordering) {
+                    <#bytes_ty as ::modular_bitfield::private::AtomicBytes>::store(&self.0, value.#storage, order)
+                }
+
+                /// Atomically updates the bitfield with the given closure.
+                ///
+                /// Returns `Ok` with the previous bitfield if `f` returned `Some`
+                /// and otherwise `Err` with the current bitfield.
+                #[inline]
+                pub fn fetch_update<F>(
+                    &self,
+                    set_order: ::core::sync::atomic::Ordering,
+                    fetch_order: ::core::sync::atomic::Ordering,
+                    mut f: F,
+                ) -> ::core::result::Result<#ident, #ident>
+                where
+                    F: ::core::ops::FnMut(#ident) -> ::core::option::Option<#ident>,
+                {
+                    <#bytes_ty as ::modular_bitfield::private::AtomicBytes>::fetch_update(
+                        &self.0,
+                        set_order,
+                        fetch_order,
+                        |bytes| f(#ident { #markers #storage: bytes }).map(|value| value.#storage),
+                    )
+                    .map(|bytes| #ident { #markers #storage: bytes })
+                    .map_err(|bytes| #ident { #markers #storage: bytes })
+                }
+
+                /// Stores `new` if the current bitfield has the same bits as `current`.
+                ///
+                /// Returns `Ok` with the previous bitfield on success
+                /// and otherwise `Err` with the current bitfield.
+                #[inline]
+                pub fn compare_exchange(
+                    &self,
+                    current: #ident,
+                    new: #ident,
+                    success: ::core::sync::atomic::Ordering,
+                    failure: ::core::sync::atomic::Ordering,
+                ) -> ::core::result::Result<#ident, #ident> {
+                    <#bytes_ty as ::modular_bitfield::private::AtomicBytes>::compare_exchange(
+                        &self.0,
+                        current.#storage,
+                        new.#storage,
+                        success,
+                        failure,
+                    )
+                    .map(|bytes| #ident { #markers #storage: bytes })
+                    .map_err(|bytes| #ident { #markers #storage: bytes })
+                }
+
+                #( #accessors )*
+            }
+
+            impl ::core::default::Default for #atomic_ident {
+                #[inline]
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl ::core::convert::From<#ident> for #atomic_ident {
+                #[inline]
+                fn from(__bf_bitfield: #ident) -> Self {
+                    Self(<#bytes_ty as ::modular_bitfield::private::AtomicBytes>::new(__bf_bitfield.#storage))
+                }
+            }
+        ))
+    }
+
+    /// Generates the getters and setters of the `AtomicFoo` type for a single field.
+    ///
+    /// Setters atomically update only the bits of the field within a compare and swap loop.
+    fn expand_atomic_accessors_for_field(
+        &self,
+        info: &FieldInfo<'_>,
+        offset: &TokenStream2,
+        config: &Config,
+    ) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        let next_divisible_by_8 =
+            Self::next_divisible_by_8(&self.generate_target_or_actual_bitfield_size(config));
+        let FieldInfo { field, config, .. } = info;
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let retained_attrs = &config.retained_attrs;
+        let checked_error = config.checked_setter_error();
+        let ident = info.ident_frag();
+        let name = info.name();
+        let get_ident = info.get_ident();
+        let get_checked_ident = info.get_checked_ident();
+        let set_ident = format_ident!("set_{}", ident);
+        let set_checked_ident = format_ident!("set_{}_checked", ident);
+        let with_ident = format_ident!("with_{}", ident);
+        let with_checked_ident = format_ident!("with_{}_checked", ident);
+        let fetch_update_ident = format_ident!("fetch_update_{}", ident);
+        let compare_exchange_ident = format_ident!("compare_exchange_{}", ident);
+        let getters = match config.skip_getters() {
+            true => None,
+            false => {
+                let getter_docs = format!("Atomically loads the value of {}.", name);
+                Some(quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #get_ident(
+                        &self,
+                        order: ::core::sync::atomic::Ordering,
+                    ) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                        self.load(order).#get_ident()
+                    }
+
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #get_checked_ident(
+                        &self,
+                        order: ::core::sync::atomic::Ordering,
+                    ) -> ::core::result::Result<
+                        <#ty as ::modular_bitfield::Specifier>::InOut,
+                        ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
+                    > {
+                        self.load(order).#get_checked_ident()
+                    }
+                ))
+            }
+        };
+        let setters = match config.skip_setters() {
+            true => None,
+            false => {
+                let handle_overflow = Self::expand_overflow_handling(info);
+                let set_assert_msg = match config.validator() {
+                    Some(_) => format!("invalid value for field {}.{}", struct_ident, name),
+                    None => format!("value out of bounds for field {}.{}", struct_ident, name),
+                };
+                let setter_docs = format!(
+                    "Atomically sets the value of {} to the given value.\n\n\
+                     #Panics\n\n\
+                     If the given value is out of bounds for {}.",
+                    name, name,
+                );
+                let checked_setter_docs = format!(
+                    "Atomically sets the value of {} to the given value.\n\n\
+                     #Errors\n\n\
+                     If the given value is out of bounds for {}.",
+                    name, name,
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #set_ident(
+                        &self,
+                        new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+                        order: ::core::sync::atomic::Ordering,
+                    ) {
+                        #handle_overflow
+                        if let ::core::result::Result::Err(err) = self.#set_checked_ident(new_val, order) {
+                            ::core::panic!("{}: {}", #set_assert_msg, err)
+                        }
+                    }
+
+                    #[doc = #checked_setter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #set_checked_ident(
+                        &self,
+                        new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+                        order: ::core::sync::atomic::Ordering,
+                    ) -> ::core::result::Result<(), #checked_error> {
+                        let __bf_zeroed = #struct_ident { #markers #storage: [0u8; #next_divisible_by_8 / 8usize] };
+                        let __bf_field_bytes = __bf_zeroed.#with_checked_ident(new_val)?.#storage;
+                        let _ = self.fetch_update(
+                            order,
+                            ::modular_bitfield::private::fetch_order(order),
+                            |mut __bf_bitfield| {
+                                ::modular_bitfield::private::write_specifier::<#ty>(&mut __bf_bitfield.#storage[..], #offset, 0);
+                                for (__bf_byte, __bf_field_byte) in __bf_bitfield.#storage.iter_mut().zip(&__bf_field_bytes) {
+                                    *__bf_byte |= *__bf_field_byte;
+                                }
+                                ::core::option::Option::Some(__bf_bitfield)
+                            },
+                        );
+                        ::core::result::Result::Ok(())
+                    }
+                ))
+            }
+        };
+        let fetch_update = match config.skip_getters() || config.skip_setters() {
+            true => None,
+            false => {
+                let fetch_update_docs = format!(
+                    "Atomically updates the value of {} with the given closure.\n\n\
+                     Returns `Ok` with the previous value if `f` returned `Some` \
+                     and otherwise `Err` with the current value.\n\n\
+                     #Panics\n\n\
+                     If `f` returns a value that is out of bounds for {}.",
+                    name, name,
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #fetch_update_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #fetch_update_ident<F>(
+                        &self,
+                        set_order: ::core::sync::atomic::Ordering,
+                        fetch_order: ::core::sync::atomic::Ordering,
+                        mut f: F,
+                    ) -> ::core::result::Result<
+                        <#ty as ::modular_bitfield::Specifier>::InOut,
+                        <#ty as ::modular_bitfield::Specifier>::InOut,
+                    >
+                    where
+                        F: ::core::ops::FnMut(
+                            <#ty as ::modular_bitfield::Specifier>::InOut,
+                        ) -> ::core::option::Option<<#ty as ::modular_bitfield::Specifier>::InOut>,
+                    {
+                        self.fetch_update(set_order, fetch_order, |__bf_bitfield| {
+                            let __bf_bytes = __bf_bitfield.#storage;
+                            f(__bf_bitfield.#get_ident())
+                                .map(|new_val| #struct_ident { #markers #storage: __bf_bytes }.#with_ident(new_val))
+                        })
+                        .map(|__bf_bitfield| __bf_bitfield.#get_ident())
+                        .map_err(|__bf_bitfield| __bf_bitfield.#get_ident())
+                    }
+                ))
+            }
+        };
+        let compare_exchange = match config.skip_getters() || config.skip_setters() {
+            true => None,
+            false => {
+                let cas_assert_msg =
+                    format!("value out of bounds for field {}.{}", struct_ident, name);
+                let compare_exchange_docs = format!(
+                    "Atomically sets the value of {} to `new` if it currently equals `current`.\n\n\
+                     Returns `Ok` with the previous value on success and otherwise `Err` \
+                     with the current value. Concurrent changes to other fields do not \
+                     make the exchange fail but only retry it.\n\n\
+                     #Panics\n\n\
+                     If `current` or `new` is out of bounds for {}.",
+                    name, name,
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #compare_exchange_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #compare_exchange_ident(
+                        &self,
+                        current: <#ty as ::modular_bitfield::Specifier>::InOut,
+                        new: <#ty as ::modular_bitfield::Specifier>::InOut,
+                        success: ::core::sync::atomic::Ordering,
+                        failure: ::core::sync::atomic::Ordering,
+                    ) -> ::core::result::Result<
+                        <#ty as ::modular_bitfield::Specifier>::InOut,
+                        <#ty as ::modular_bitfield::Specifier>::InOut,
+                    > {
+                        let __bf_field_bytes = |value: <#ty as ::modular_bitfield::Specifier>::InOut| {
+                            let __bf_zeroed = #struct_ident { #markers #storage: [0u8; #next_divisible_by_8 / 8usize] };
+                            __bf_zeroed.#with_checked_ident(value).expect(#cas_assert_msg).#storage
+                        };
+                        let __bf_current_bytes = __bf_field_bytes(current);
+                        let __bf_new_bytes = __bf_field_bytes(new);
+                        let mut __bf_loaded = self.load(failure);
+                        loop {
+                            let mut __bf_expected = #struct_ident { #markers #storage: __bf_loaded.#storage };
+                            ::modular_bitfield::private::write_specifier::<#ty>(&mut __bf_expected.#storage[..], #offset, 0);
+                            let mut __bf_desired = #struct_ident { #markers #storage: __bf_expected.#storage };
+                            for (__bf_byte, __bf_field_byte) in __bf_expected.#storage.iter_mut().zip(&__bf_current_bytes) {
+                                *__bf_byte |= *__bf_field_byte;
+                            }
+                            if __bf_expected.#storage != __bf_loaded.#storage {
+                                return ::core::result::Result::Err(__bf_loaded.#get_ident())
+                            }
+                            for (__bf_byte, __bf_field_byte) in __bf_desired.#storage.iter_mut().zip(&__bf_new_bytes) {
+                                *__bf_byte |= *__bf_field_byte;
+                            }
+                            match self.compare_exchange(__bf_loaded, __bf_desired, success, failure) {
+                                ::core::result::Result::Ok(__bf_previous) => {
+                                    return ::core::result::Result::Ok(__bf_previous.#get_ident())
+                                }
+                                ::core::result::Result::Err(__bf_actual) => __bf_loaded = __bf_actual,
+                            }
+                        }
+                    }
+                ))
+            }
+        };
+        quote_spanned!(span=>
+            #getters
+            #setters
+            #fetch_update
+            #compare_exchange
+        )
+    }
+
+    /// Generates the `#[no_mangle] extern "C"` accessor functions if `export_c = true` is set.
+    ///
+    /// For a bitfield `Foo` with `pub` field `f` these are `foo_get_f(ptr)` and `foo_set_f(ptr, val)`
+    /// where the `foo` prefix can be overridden with `export_c_prefix`. Private fields are not exported.
+    fn expand_export_c(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.export_c_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let prefix = match &config.export_c_prefix {
+            Some(prefix) => prefix.value.to_string(),
+            None => Self::snake_case(&self.item_struct.ident.to_string()),
+        };
+        let exports = self
+            .field_infos(config)
+            .filter(|info| matches!(info.field.vis, syn::Visibility::Public(_)))
+            .map(|info| self.expand_export_c_for_field(&prefix, &info));
+        Some(quote_spanned!(span=>
+            #( #exports )*
+        ))
+    }
+
+    /// Generates the exported C getter and setter functions for a single field.
+    ///
+    /// The setter returns `false` instead of panicking if the value is out of bounds
+    /// since unwinding across the C boundary is not allowed.
+    fn expand_export_c_for_field(&self, prefix: &str, info: &FieldInfo<'_>) -> TokenStream2 {
+        let FieldInfo { field, config, .. } = info;
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let ty = &field.ty;
+        let ident = info.ident_frag();
+        let name = info.name();
+        let get_ident = info.get_ident();
+        let set_checked_ident = format_ident!("set_{}_checked", ident);
+        let getter = match config.skip_getters() {
+            true => None,
+            false => {
+                let export_ident = format_ident!("{}_get_{}", prefix, ident);
+                let cfg_attrs = info.cfg_attrs();
+                let getter_docs = format!(
+                    "Returns the value of {} of the given [`{}`].",
+                    name, struct_ident,
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #( #cfg_attrs )*
+                    #[no_mangle]
+                    pub extern "C" fn #export_ident(
+                        ptr: &#struct_ident,
+                    ) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                        ptr.#get_ident()
+                    }
+                ))
+            }
+        };
+        let setter = match config.skip_setters() {
+            true => None,
+            false => {
+                let export_ident = format_ident!("{}_set_{}", prefix, ident);
+                let cfg_attrs = info.cfg_attrs();
+                let setter_docs = format!(
+                    "Sets the value of {} of the given [`{}`].\n\n\
+                     Returns `false` and leaves the bitfield unchanged \
+                     if the value is out of bounds for {}.",
+                    name, struct_ident, name,
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    #( #cfg_attrs )*
+                    #[no_mangle]
+                    pub extern "C" fn #export_ident(
+                        ptr: &mut #struct_ident,
+                        val: <#ty as ::modular_bitfield::Specifier>::InOut,
+                    ) -> ::core::primitive::bool {
+                        ptr.#set_checked_ident(val).is_ok()
+                    }
+                ))
+            }
+        };
+        quote_spanned!(span=>
+            #getter
+            #setter
+        )
+    }
+
+    /// Generates the `FooR` and `FooW` proxy types if `reader_writer = true` is set.
+    ///
+    /// Reads of the bitfield go through `FooR` which only provides getters while
+    /// writes go through `FooW` which only provides chainable setters.
+    fn expand_reader_writer(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        if !config.reader_writer_enabled() {
+            return None
+        }
+        let next_divisible_by_8 =
+            Self::next_divisible_by_8(&self.generate_target_or_actual_bitfield_size(config));
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let reader_ident = format_ident!("{}R", ident);
+        let writer_ident = format_ident!("{}W", ident);
+        let (reader_accessors, writer_accessors): (Vec<_>, Vec<_>) = self
+            .field_infos(config)
+            .map(|info| self.expand_reader_writer_accessors_for_field(&info))
+            .unzip();
+        let reader_docs = format!("Read proxy providing the getters of [`{}`].", ident);
+        let writer_docs = format!("Write proxy providing the chainable setters of [`{}`].", ident);
+        Some(quote_spanned!(span=>
+            #[doc = #reader_docs]
+            #vis struct #reader_ident(#ident);
+
+            #[allow(dead_code)]
+            impl #reader_ident {
+                /// Returns the read bitfield.
+                #[inline]
+                pub fn bits(&self) -> &#ident {
+                    &self.0
+                }
+
+                #( #reader_accessors )*
+            }
+
+            #[doc = #writer_docs]
+            #vis struct #writer_ident(#ident);
+
+            #[allow(dead_code)]
+            impl #writer_ident {
+                /// Returns the bitfield written so far.
+                #[inline]
+                pub fn bits(&self) -> &#ident {
+                    &self.0
+                }
+
+                /// Resets all bits of the written bitfield to zero.
+                #[inline]
+                pub fn reset(&mut self) -> &mut Self {
+                    self.0.#storage = [0u8; #next_divisible_by_8 / 8usize];
+                    self
+                }
+
+                #( #writer_accessors )*
+            }
+
+            #[allow(dead_code)]
+            impl #ident {
+                /// Returns a read proxy for the current value of the bitfield.
+                #[inline]
+                pub fn read(&self) -> #reader_ident {
+                    #reader_ident(#ident { #markers #storage: self.#storage })
+                }
+
+                /// Writes the bitfield starting from all bits set to zero.
+                #[inline]
+                pub fn write<F>(&mut self, f: F)
+                where
+                    F: ::core::ops::FnOnce(&mut #writer_ident) -> &mut #writer_ident,
+                {
+                    let mut __bf_writer = #writer_ident(#ident {
+                        #markers #storage: [0u8; #next_divisible_by_8 / 8usize],
+                    });
+                    f(&mut __bf_writer);
+                    self.#storage = __bf_writer.0.#storage;
+                }
+
+                /// Modifies the bitfield given a read proxy for its current value
+                /// and a write proxy starting from its current value.
+                #[inline]
+                pub fn modify<F>(&mut self, f: F)
+                where
+                    F: for<'a> ::core::ops::FnOnce(&#reader_ident, &'a mut #writer_ident) -> &'a mut #writer_ident,
+                {
+                    let __bf_reader = self.read();
+                    let mut __bf_writer = #writer_ident(#ident { #markers #storage: self.#storage });
+                    f(&__bf_reader, &mut __bf_writer);
+                    self.#storage = __bf_writer.0.#storage;
+                }
+            }
+        ))
+    }
+
+    /// Generates the `ValidFoo` wrapper for a `#[bitfield]` struct `Foo` with `validated = true`.
+    ///
+    /// The wrapper can only be constructed via `Foo::validated` which validates the bit patterns
+    /// of all fields up front and stores their decoded values so that the getters of the wrapper
+    /// neither fail nor panic.
+    fn expand_validated(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        if !config.validated_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let valid_ident = format_ident!("Valid{}", ident);
+        let mut fields = Vec::new();
+        let mut inits = Vec::new();
+        let mut getters = Vec::new();
+        for (info, offset) in self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| !info.config.skip_getters())
+        {
+            let FieldInfo { field, config, .. } = &info;
+            let span = field.span();
+            let ty = &field.ty;
+            let vis = &field.vis;
+            let retained_attrs = &config.retained_attrs;
+            let name = info.name();
+            let get_ident = info.get_ident();
+            let value_ident = format_ident!("__bf_field_{}", info.ident_frag());
+            let cfg_attrs = info.cfg_attrs().collect::<Vec<_>>();
+            fields.push(quote_spanned!(span=>
+                #( #cfg_attrs )*
+                #value_ident: <#ty as ::modular_bitfield::Specifier>::InOut,
+            ));
+            inits.push(quote_spanned!(span=>
+                #( #cfg_attrs )*
+                #value_ident: match <#ty as ::modular_bitfield::Specifier>::from_bytes(
+                    ::modular_bitfield::private::read_specifier::<#ty>(&__bf_bitfield.#storage[..], #offset)
+                ) {
+                    ::core::result::Result::Ok(__bf_value) => __bf_value,
+                    ::core::result::Result::Err(__bf_err) => {
+                        return ::core::result::Result::Err(::modular_bitfield::error::DecodeError::InvalidField {
+                            field: #name,
+                            invalid_bits: <<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::into_u128(
+                                __bf_err.invalid_bytes
+                            ),
+                        })
+                    }
+                },
+            ));
+            let getter_docs = format!("Returns the validated value of {}.", name);
+            getters.push(quote_spanned!(span=>
+                #[doc = #getter_docs]
+                #[inline]
+                #( #retained_attrs )*
+                #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                    ::core::clone::Clone::clone(&self.#value_ident)
+                }
+            ));
+        }
+        let valid_docs = format!(
+            "A [`{}`] whose fields are known to contain valid bit patterns.\n\n\
+             Constructed via [`{}::validated`].",
+            ident, ident,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #valid_docs]
+            #vis struct #valid_ident {
+                __bf_bitfield: #ident,
+                #( #fields )*
+            }
+
+            #[allow(dead_code)]
+            impl #valid_ident {
+                /// Returns a shared reference to the validated bitfield.
+                #[inline]
+                pub fn as_inner(&self) -> &#ident {
+                    &self.__bf_bitfield
+                }
+
+                /// Returns the validated bitfield.
+                #[inline]
+                pub fn into_inner(self) -> #ident {
+                    self.__bf_bitfield
+                }
+
+                #( #getters )*
+            }
+
+            impl ::core::convert::From<#valid_ident> for #ident {
+                #[inline]
+                fn from(valid: #valid_ident) -> Self {
+                    valid.__bf_bitfield
+                }
+            }
+
+            #[allow(dead_code)]
+            impl #ident {
+                /// Validates the padding bits and the bit patterns of all fields of the bitfield.
+                ///
+                /// # Errors
+                ///
+                /// If the bitfield contains invalid padding bits or an invalid bit pattern for any of its fields.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub fn validated(self) -> ::core::result::Result<#valid_ident, ::modular_bitfield::error::DecodeError> {
+                    let __bf_bitfield = <#ident>::try_from_bytes(self.#storage)?;
+                    ::core::result::Result::Ok(#valid_ident {
+                        #( #inits )*
+                        __bf_bitfield,
+                    })
+                }
+            }
+        ))
+    }
+
+    /// Generates the getters of the `FooR` and the setters of the `FooW` proxy for a single field.
+    fn expand_reader_writer_accessors_for_field(
+        &self,
+        info: &FieldInfo<'_>,
+    ) -> (TokenStream2, TokenStream2) {
+        let FieldInfo { field, config, .. } = info;
+        let span = field.span();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let retained_attrs = &config.retained_attrs;
+        let checked_error = config.checked_setter_error();
+        let ident = info.ident_frag();
+        let name = info.name();
+        let getters = match config.skip_getters() {
+            true => None,
+            false => {
+                let get_ident = info.get_ident();
+                let get_checked_ident = info.get_checked_ident();
+                let getter_docs = format!("Returns the read value of {}.", name);
+                Some(quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                        self.0.#get_ident()
+                    }
+
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #get_checked_ident(
+                        &self,
+                    ) -> ::core::result::Result<
+                        <#ty as ::modular_bitfield::Specifier>::InOut,
+                        ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
+                    > {
+                        self.0.#get_checked_ident()
+                    }
+                ))
+            }
+        };
+        let setters = match config.skip_setters() {
+            true => None,
+            false => {
+                let set_ident = format_ident!("set_{}", ident);
+                let set_checked_ident = format_ident!("set_{}_checked", ident);
+                let setter_docs = format!(
+                    "Sets the written value of {} to the given value.\n\n\
+                     #Panics\n\n\
+                     If the given value is out of bounds for {}.",
+                    name, name,
+                );
+                let checked_setter_docs = format!(
+                    "Sets the written value of {} to the given value.\n\n\
+                     #Errors\n\n\
+                     If the given value is out of bounds for {}.",
+                    name, name,
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #set_ident(
+                        &mut self,
+                        new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+                    ) -> &mut Self {
+                        self.0.#set_ident(new_val);
+                        self
+                    }
+
+                    #[doc = #checked_setter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #set_checked_ident(
+                        &mut self,
+                        new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+                    ) -> ::core::result::Result<&mut Self, #checked_error> {
+                        self.0.#set_checked_ident(new_val)?;
+                        ::core::result::Result::Ok(self)
+                    }
+                ))
+            }
+        };
+        let write_one_accessors = match config.write_one_accessors() {
+            true => {
+                let method_ident = match config.access_kind() {
+                    AccessKind::WriteOneToClear => format_ident!("clear_{}", ident),
+                    _ => format_ident!("set_{}", ident),
+                };
+                let docs = format!(
+                    "Writes all ones to {} and zeros to all other write one fields.",
+                    name
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #method_ident(&mut self) -> &mut Self {
+                        self.0.#method_ident();
+                        self
+                    }
+                ))
+            }
+            false => None,
+        };
+        let writers = quote_spanned!(span=>
+            #setters
+            #write_one_accessors
+        );
+        (quote_spanned!(span=> #getters), writers)
+    }
+
+    /// Generates the `bit` and `set_bit` methods to access single bits by their index.
+    ///
+    /// Returns `None` for `no_panic` bitfields since both methods panic on out of bounds indices.
+    /// A method is not generated if the accessors of a field named `bit` already occupy its name.
+    /// Both methods have the visibility given by `bytes_vis` since they access the raw bits.
+    fn expand_bit_accessors(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        if config.no_panic_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = config.bytes_visibility();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let assert_msg = format!("bit index out of bounds for {}", ident);
+        let check_reserved = match config.check_reserved_enabled() {
+            true => Some(quote_spanned!(span=> self.__bf_debug_assert_reserved();)),
+            false => None,
+        };
+        let has_getter = |name: &str| {
+            self.field_infos(config)
+                .any(|info| info.name() == name && !info.config.skip_getters())
+        };
+        let has_setter = |name: &str| {
+            self.field_infos(config)
+                .any(|info| info.name() == name && !info.config.skip_setters())
+        };
+        let bit = match has_getter("bit") {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Returns the value of the bit at the given index.
+                    ///
+                    /// # Panics
+                    ///
+                    /// If `index` is out of bounds for the bit width of the bitfield.
+                    #[inline]
+                    #[allow(dead_code, clippy::identity_op)]
+                    #vis fn bit(&self, index: ::core::primitive::usize) -> ::core::primitive::bool {
+                        ::core::assert!(index < #size, #assert_msg);
+                        (self.#storage[index / 8] >> (index % 8)) & 0x01 != 0
+                    }
+                ))
+            }
+        };
+        let set_bit = match has_setter("bit") || has_getter("set_bit") {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Sets the bit at the given index to the given value.
+                    ///
+                    /// # Panics
+                    ///
+                    /// If `index` is out of bounds for the bit width of the bitfield.
+                    #[inline]
+                    #[allow(dead_code, clippy::identity_op)]
+                    #vis fn set_bit(&mut self, index: ::core::primitive::usize, value: ::core::primitive::bool) {
+                        ::core::assert!(index < #size, #assert_msg);
+                        let __bf_mask = 0x01_u8 << (index % 8);
+                        if value {
+                            self.#storage[index / 8] |= __bf_mask;
+                        } else {
+                            self.#storage[index / 8] &= !__bf_mask;
+                        }
+                        #check_reserved
+                    }
+                ))
+            }
+        };
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #bit
+                #set_bit
+            }
+        ))
+    }
+
+    /// Generates `set_flags` which iterates over the names of all `bool` fields that are set.
+    ///
+    /// Fields without getters are not considered. Not generated if the setter of a field
+    /// named `flags` already occupies the name.
+    fn expand_set_flags(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        if self
+            .field_infos(config)
+            .any(|info| info.name() == "flags" && !info.config.skip_setters())
+        {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let flags = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| {
+                !info.config.skip_getters()
+                    && matches!(FieldKind::of(&info.field.ty), FieldKind::Bool)
+            })
+            .map(|(info, offset)| {
+                let span = info.field.span();
+                let name = info.name();
+                let bits = info.bits_expr();
+                quote_spanned!(span=> (#name, #offset, #bits))
+            });
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Returns an iterator over the names of all `bool` fields that are set.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                pub fn set_flags(&self) -> impl ::core::iter::Iterator<Item = &'static ::core::primitive::str> {
+                    const FLAGS: &[(&::core::primitive::str, ::core::primitive::usize, ::core::primitive::usize)] = &[
+                        #( #flags ),*
+                    ];
+                    let __bf_bytes = self.#storage;
+                    let __bf_set = ::core::iter::Iterator::filter(FLAGS.iter(), move |(_, offset, bits)| {
+                        *bits != 0 && (__bf_bytes[*offset / 8] >> (*offset % 8)) & 0x01 != 0
+                    });
+                    ::core::iter::Iterator::map(__bf_set, |(name, _, _)| *name)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `GROUP_MASK` constants and `clear_group_g` methods for all groups `g`
+    /// of `#[group = ".."]` fields.
+    fn expand_groups(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        let mut groups = Vec::<(syn::Ident, Vec<(FieldInfo, TokenStream2)>)>::new();
+        for (info, offset) in self.field_infos_with_offsets(config) {
+            let group = match info.config.group_name() {
+                Some(group) => group.clone(),
+                None => continue,
+            };
+            match groups.iter_mut().find(|(name, _)| *name == group) {
+                Some((_, fields)) => fields.push((info, offset)),
+                None => groups.push((group, vec![(info, offset)])),
+            }
+        }
+        if groups.is_empty() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let items = groups.iter().map(|(group, fields)| {
+            let span = group.span();
+            let read_group = self.expand_read_group(config, group, fields);
+            let fields = fields.iter().map(|(info, offset)| {
+                let span = info.field.span();
+                let bits = info.bits_expr();
+                quote_spanned!(span=> (#offset, #bits))
+            });
+            let mask = format_ident!("{}_MASK", group.to_string().to_uppercase(), span = span);
+            let clear = format_ident!("clear_group_{}", group, span = span);
+            let mask_docs = format!("The mask of all bits of the fields in group `{}`.", group);
+            let clear_docs = format!("Resets all fields in group `{}` to 0.", group);
+            quote_spanned!(span=>
+                #[doc = #mask_docs]
+                #[allow(clippy::identity_op)]
+                pub const #mask: [::core::primitive::u8; #next_divisible_by_8 / 8usize] =
+                    ::modular_bitfield::private::mask_bytes(&[ #( #fields ),* ]);
+
+                #[doc = #clear_docs]
+                #[inline]
+                #[allow(dead_code)]
+                pub fn #clear(&mut self) {
+                    for (byte, mask) in self.#storage.iter_mut().zip(Self::#mask.iter()) {
+                        *byte &= !*mask;
+                    }
+                }
+
+                #read_group
+            )
+        });
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #( #items )*
+            }
+        ))
+    }
+
+    /// Generates the `read_group_g` method for the group `g` with the given fields and offsets.
+    ///
+    /// The method reads the bits covering all fields of the group at once and returns
+    /// the values of the fields as a tuple in declaration order.
+    /// Returns `None` for `no_panic` bitfields since the method panics on invalid bit patterns
+    /// and if any field of the group has no getter, `#[cfg(..)]` attributes or an
+    /// `#[overlaps(..)]` attribute.
+    fn expand_read_group(
+        &self,
+        config: &Config,
+        group: &syn::Ident,
+        fields: &[(FieldInfo, TokenStream2)],
+    ) -> Option<TokenStream2> {
+        if config.no_panic_enabled() {
+            return None
+        }
+        let unsupported = fields.iter().any(|(info, _)| {
+            info.config.skip_getters()
+                || info.cfg_attrs().next().is_some()
+                || info.config.overlapped_field().is_some()
+        });
+        if unsupported {
+            return None
+        }
+        let storage = config.storage_ident();
+        let struct_ident = &self.item_struct.ident;
+        let span = group.span();
+        let read_group = format_ident!("read_group_{}", group, span = span);
+        let (_, first_offset) = fields.first()?;
+        let (last, last_offset) = fields.last()?;
+        let last_ty = &last.field.ty;
+        let start = quote_spanned!(span=> (#first_offset));
+        let end = quote_spanned!(span=> (#last_offset + <#last_ty as ::modular_bitfield::Specifier>::BITS));
+        let types = fields.iter().map(|(info, _)| {
+            let span = info.field.span();
+            let ty = &info.field.ty;
+            quote_spanned!(span=> <#ty as ::modular_bitfield::Specifier>::InOut)
+        });
+        let values = fields.iter().map(|(info, offset)| {
+            let span = info.field.span();
+            let ty = &info.field.ty;
+            let get_assert_msg = format!(
+                "value contains invalid bit pattern for field {}.{}",
+                struct_ident,
+                info.name()
+            );
+            quote_spanned!(span=>
+                <#ty as ::modular_bitfield::Specifier>::from_bytes(
+                    <<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::from_u128(
+                        (__bf_covering >> (#offset - #start))
+                            & (::core::primitive::u128::MAX >> (128 - <#ty as ::modular_bitfield::Specifier>::BITS))
+                    )
+                )
+                .expect(#get_assert_msg)
+            )
+        });
+        let docs = format!(
+            "Returns the values of all fields in group `{}` as a tuple in declaration order.\n\n\
+             Reads the bits covering the fields of the group only once.\n\n\
+             #Panics\n\n\
+             If any of the values contains an invalid bit pattern for its field.",
+            group,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #docs]
+            #[inline]
+            #[allow(dead_code, clippy::identity_op)]
+            pub fn #read_group(&self) -> ( #( #types, )* ) {
+                const _: () = ::core::assert!(
+                    #end - #start <= 128,
+                    "the fields of a group must be within 128 bits to be read at once"
+                );
+                let __bf_covering = ::modular_bitfield::raw::read(&self.#storage[..], #start, #end - #start);
+                ( #( #values, )* )
+            }
+        ))
+    }
+
+    /// Generates the `is_zero` and `any` predicates over all bits that are not reserved by `#[skip]`.
+    ///
+    /// A predicate is not generated if the getter of a field already occupies its name.
+    fn expand_zero_predicates(&self, config: &Config) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let occupied = |name: &str| {
+            self.field_infos(config)
+                .any(|info| info.name() == name && !info.config.skip_getters())
+        };
+        let reads = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| !info.config.skip_all())
+            .map(|(info, offset)| {
+                let span = info.field.span();
+                let bits = info.bits_expr();
+                quote_spanned!(span=>
+                    ::modular_bitfield::raw::read(&self.#storage[..], #offset, #bits)
+                )
+            })
+            .collect::<Vec<_>>();
+        let (any_set, all_clear) = match reads.is_empty() {
+            true => (quote_spanned!(span=> false), quote_spanned!(span=> true)),
+            false => {
+                (
+                    quote_spanned!(span=> #( #reads != 0 )||*),
+                    quote_spanned!(span=> #( #reads == 0 )&&*),
+                )
+            }
+        };
+        let any = match occupied("any") {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Returns `true` if any bit of a field that is not reserved by `#[skip]` is set.
+                    #[inline]
+                    #[allow(dead_code, clippy::identity_op)]
+                    pub fn any(&self) -> ::core::primitive::bool {
+                        #any_set
+                    }
+                ))
+            }
+        };
+        let is_zero = match occupied("is_zero") {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Returns `true` if all bits of fields that are not reserved by `#[skip]` are clear.
+                    #[inline]
+                    #[allow(dead_code, clippy::identity_op)]
+                    pub fn is_zero(&self) -> ::core::primitive::bool {
+                        #all_clear
+                    }
+                ))
+            }
+        };
+        quote_spanned!(span=>
+            impl #ident {
+                #any
+                #is_zero
+            }
+        )
+    }
+
+    /// Generates `swap_bytes` and `reverse_bits` operating on all bytes of the bitfield.
+    ///
+    /// Only generated for bitfields with `filled = true` since otherwise the undefined bits
+    /// would be moved into the fields. A method is not generated if the getter of a field
+    /// already occupies its name.
+    fn expand_bit_order_methods(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.filled_enabled() {
+            return None
+        }
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let occupied = |name: &str| {
+            self.field_infos(config)
+                .any(|info| info.name() == name && !info.config.skip_getters())
+        };
+        let swap_bytes = match occupied("swap_bytes") {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Returns the bitfield with the order of its bytes reversed.
+                    ///
+                    /// The first byte becomes the last byte and vice versa while the
+                    /// order of the bits within every byte is preserved.
+                    #[inline]
+                    #[allow(dead_code)]
+                    pub fn swap_bytes(self) -> Self {
+                        let mut __bf_bytes = self.#storage;
+                        __bf_bytes.reverse();
+                        Self { #markers #storage: __bf_bytes }
+                    }
+                ))
+            }
+        };
+        let reverse_bits = match occupied("reverse_bits") {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Returns the bitfield with the order of all of its bits reversed.
+                    ///
+                    /// The least significant bit of the first byte becomes the most
+                    /// significant bit of the last byte and vice versa.
+                    #[inline]
+                    #[allow(dead_code)]
+                    pub fn reverse_bits(self) -> Self {
+                        let mut __bf_bytes = self.#storage;
+                        __bf_bytes.reverse();
+                        for __bf_byte in __bf_bytes.iter_mut() {
+                            *__bf_byte = __bf_byte.reverse_bits();
+                        }
+                        Self { #markers #storage: __bf_bytes }
+                    }
+                ))
+            }
+        };
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #swap_bytes
+                #reverse_bits
+            }
+        ))
+    }
+
+    /// Generates the invocation of `__bf_impl_sample!` which implements `sample` if the
+    /// `rand` crate feature is enabled.
+    ///
+    /// Entirely `#[skip]`ped fields and fields with an `#[overlaps(..)]` attribute are not sampled.
+    /// Skipped if a getter named `sample` exists.
+    fn expand_sample(&self, config: &Config) -> Option<TokenStream2> {
+        let occupied = self
+            .field_infos(config)
+            .any(|info| info.name() == "sample" && !info.config.skip_getters());
+        if occupied {
+            return None
+        }
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let fields = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| !info.config.skip_all() && info.config.overlapped_field().is_none())
+            .map(|(info, offset)| {
+                let span = info.field.span();
+                let ty = &info.field.ty;
+                let cfg_attrs = info.cfg_attrs();
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    (#ty, #offset)
+                )
+            });
+        Some(quote_spanned!(span=>
+            ::modular_bitfield::__bf_impl_sample!(
+                #ident { #markers } #storage: [u8; #next_divisible_by_8 / 8usize];
+                #( #fields )*
+            );
+        ))
+    }
+
+    /// Generates the invocation of `__bf_impl_hash32!` which implements `hash32::Hash` if the
+    /// `hash32` crate feature is enabled.
+    ///
+    /// The hash is computed from the underlying bytes in line with the derived `PartialEq`.
+    fn expand_hash32(&self, config: &Config) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        quote_spanned!(span=>
+            ::modular_bitfield::__bf_impl_hash32!(#ident, #storage);
+        )
+    }
+
+    /// Generates the alignment check and the optional `zerocopy::Unaligned` impl
+    /// for the `unaligned = true` #[bitfield] parameter.
+    fn expand_unaligned(&self, config: &Config) -> Option<TokenStream2> {
+        let unaligned = config.unaligned.as_ref().filter(|config| config.value)?;
+        let ident = &self.item_struct.ident;
+        Some(quote_spanned!(unaligned.span=>
+            const _: () = {
+                ::modular_bitfield::private::static_assertions::const_assert_eq!(
+                    ::core::mem::align_of::<#ident>(),
+                    1
+                );
+            };
+
+            ::modular_bitfield::__bf_impl_unaligned!(#ident);
+        ))
+    }
+
+    /// Generates the `Display` and `FromStr` impls for `text = true`.
+    ///
+    /// Both use the `name=value,flag=true` syntax based on the `RawFields` impl of the bitfield.
+    fn expand_text_impls(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.text_enabled() {
+            return None
+        }
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        Some(quote_spanned!(span=>
+            impl ::core::fmt::Display for #ident {
+                fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::modular_bitfield::private::fmt_text(self, __bf_f)
+                }
+            }
+
+            impl ::core::str::FromStr for #ident {
+                type Err = ::modular_bitfield::error::ParseTextError;
+
+                /// Parses the bitfield from `name=value` pairs separated by `,`.
+                ///
+                /// Fields that are not mentioned are zero initialized.
+                #[allow(clippy::identity_op)]
+                fn from_str(text: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                    let mut __bf_parsed = Self {
+                        #markers #storage: [0u8; #next_divisible_by_8 / 8usize],
+                    };
+                    ::modular_bitfield::private::parse_text(&mut __bf_parsed, text)?;
+                    ::core::result::Result::Ok(__bf_parsed)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `#[cfg(test)]` module with round trip tests for `gen_tests = true`.
+    ///
+    /// Every field with getters and setters is tested through the raw bits of its
+    /// specifier: bit patterns rejected by `Specifier::from_bytes` or by a `validate`
+    /// function are skipped, so enums, nested bitfields and validated fields are covered
+    /// without requiring `PartialEq` or `Debug` on their `InOut` types.
+    /// The tests start from zeroed bytes instead of `new()` so that a `default` does not
+    /// leak into them and leave the bits of `#[skip]`ped fields clear for `check_reserved`.
+    fn expand_gen_tests(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.gen_tests_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let module = format_ident!("__bf_tests_{}", Self::snake_case(&ident.to_string()));
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let testable_fields = self
+            .field_infos(config)
+            .filter(|info| {
+                let config = &info.config;
+                info.cfg_attrs().next().is_none()
+                    && !config.skip_getters()
+                    && !config.skip_setters()
+                    && config.overlapped_field().is_none()
+            })
+            .collect::<Vec<_>>();
+        let from_bytes = match config.filled_enabled() {
+            true => quote_spanned!(span=> <#ident>::from_bytes(bytes)),
+            false => quote_spanned!(span=> <#ident>::from_bytes(bytes).unwrap()),
+        };
+        let round_trips = testable_fields.iter().map(|info| {
+            let span = info.field.span();
+            let ty = &info.field.ty;
+            let name = info.name();
+            let get_ident = info.get_ident();
+            let set_checked_ident = format_ident!("set_{}_checked", info.ident_frag());
+            let message = format!("round trip of field {}.{} failed", ident, name);
+            let neighbours_message = format!("setting field {}.{} changed other bits", ident, name);
+            quote_spanned!(span=>
+                let max = ::core::primitive::u128::MAX >> (128 - <#ty as ::modular_bitfield::Specifier>::BITS);
+                for raw in [0u128, max / 2, max] {
+                    let value = match <#ty as ::modular_bitfield::Specifier>::from_bytes(
+                        <<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::from_u128(raw),
+                    ) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(_) => continue,
+                    };
+                    let bytes = [0x00u8; #next_divisible_by_8 / 8usize];
+                    let mut bitfield = #from_bytes;
+                    if bitfield.#set_checked_ident(value).is_err() {
+                        continue
+                    }
+                    let read = <#ty as ::modular_bitfield::Specifier>::into_bytes(bitfield.#get_ident())
+                        .map(<<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::into_u128);
+                    assert_eq!(read, ::core::result::Result::Ok(raw), "{} for {}", #message, raw);
+                    let ones = <#ident>::into_bytes(bitfield)
+                        .iter()
+                        .map(|byte| byte.count_ones())
+                        .sum::<u32>();
+                    assert_eq!(ones, raw.count_ones(), "{} for {}", #neighbours_message, raw);
+                }
+            )
+        });
+        let clear_reserved = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| info.config.skip_all())
+            .map(|(info, offset)| {
+                let span = info.field.span();
+                let bits = info.bits_expr();
+                let cfg_attrs = info.cfg_attrs();
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    ::modular_bitfield::raw::write(&mut bytes[..], #offset, #bits, 0);
+                )
+            })
+            .collect::<Vec<_>>();
+        let mutability = match clear_reserved.is_empty() {
+            true => None,
+            false => Some(quote_spanned!(span=> mut)),
+        };
+        let all_ones = match config.filled_enabled() {
+            true => {
+                Some(quote_spanned!(span=>
+                    let #mutability bytes = [0xFFu8; #next_divisible_by_8 / 8usize];
+                    #( #clear_reserved )*
+                    assert_eq!(<#ident>::into_bytes(#from_bytes), bytes);
+                ))
+            }
+            false => None,
+        };
+        Some(quote_spanned!(span=>
+            #[cfg(test)]
+            #[allow(clippy::identity_op)]
+            mod #module {
+                use super::*;
+
+                #[test]
+                fn field_round_trips() {
+                    #( #round_trips )*
+                }
+
+                #[test]
+                fn bytes_round_trips() {
+                    let bytes = [0x00u8; #next_divisible_by_8 / 8usize];
+                    assert_eq!(<#ident>::into_bytes(#from_bytes), bytes);
+                    #all_ones
+                }
+            }
+        ))
+    }
+
+    /// Generates the `FooError` enum and the `try_get_all` method for `field_errors = true`.
+    ///
+    /// The enum has one variant per field of a specifier type that might contain invalid
+    /// bit patterns, i.e. all fields that are neither `bool` nor unsigned integers.
+    fn expand_field_errors(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.field_errors_enabled() {
+            return None
+        }
+        let storage = config.storage_ident();
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let error_ident = format_ident!("{}Error", ident);
+        let fallible_fields = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| {
+                !info.config.skip_getters() && matches!(FieldKind::of(&info.field.ty), FieldKind::Specifier)
+            })
+            .map(|(info, offset)| {
+                let variant = match &info.field.ident {
+                    Some(field_ident) => Self::camel_case(&field_ident.to_string()),
+                    None => format!("Field{}", info.index),
+                };
+                let variant = format_ident!("{}", variant, span = info.field.span());
+                (info, offset, variant)
+            })
+            .collect::<Vec<_>>();
+        let variants = fallible_fields.iter().map(|(info, _, variant)| {
+            let span = info.field.span();
+            let docs = format!(" The field `{}` contains the given invalid bit pattern.", info.name());
+            let cfg_attrs = info.cfg_attrs();
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                #[doc = #docs]
+                #variant(::core::primitive::u128),
+            )
+        });
+        let names = fallible_fields.iter().map(|(info, _, variant)| {
+            let span = info.field.span();
+            let name = info.name();
+            let cfg_attrs = info.cfg_attrs();
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                #error_ident::#variant(__bf_invalid_bits) => (#name, __bf_invalid_bits),
+            )
+        });
+        let checks = fallible_fields.iter().map(|(info, offset, variant)| {
+            let span = info.field.span();
+            let ty = &info.field.ty;
+            let cfg_attrs = info.cfg_attrs();
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                if let ::core::result::Result::Err(__bf_err) = <#ty as ::modular_bitfield::Specifier>::from_bytes(
+                    ::modular_bitfield::private::read_specifier::<#ty>(&self.#storage[..], #offset)
+                ) {
+                    return ::core::result::Result::Err(#error_ident::#variant(
+                        <<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::into_u128(
+                            __bf_err.invalid_bytes
+                        ),
+                    ))
+                }
+            )
+        });
+        let error_docs = format!(
+            "The fields of [`{}`] that contain an invalid bit pattern.",
+            ident
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #error_docs]
+            #[derive(::core::fmt::Debug, ::core::marker::Copy, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+            #vis enum #error_ident {
+                #( #variants )*
+            }
+
+            impl #error_ident {
+                /// Returns the name and the invalid bit pattern of the field.
+                #[allow(dead_code)]
+                fn __bf_field(&self) -> (&'static ::core::primitive::str, ::core::primitive::u128) {
+                    match *self {
+                        #( #names )*
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for #error_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    let (field, invalid_bits) = self.__bf_field();
+                    ::core::write!(f, "encountered an invalid bit pattern for field {}: {:#X}", field, invalid_bits)
+                }
+            }
+
+            impl ::core::convert::From<#error_ident> for ::modular_bitfield::error::DecodeError {
+                #[inline]
+                fn from(error: #error_ident) -> Self {
+                    let (field, invalid_bits) = error.__bf_field();
+                    Self::InvalidField { field, invalid_bits }
+                }
+            }
+
+            impl #ident {
+                /// Checks that all fields contain valid bit patterns.
+                ///
+                /// # Errors
+                ///
+                /// If any field contains an invalid bit pattern.
+                /// The returned error identifies the first such field.
+                #[inline]
+                #[allow(clippy::identity_op, dead_code)]
+                pub fn try_get_all(&self) -> ::core::result::Result<(), #error_ident> {
+                    #( #checks )*
+                    ::core::result::Result::Ok(())
+                }
+            }
+        ))
+    }
+
+    /// Generates the `write_if_changed` method that only hands the bytes of the bitfield
+    /// to a sink if they differ from a previously written image.
+    ///
+    /// Generates nothing if a field getter named `write_if_changed` already exists.
+    fn expand_write_if_changed(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let occupied = self
+            .field_infos(config)
+            .any(|info| info.name() == "write_if_changed" && !info.config.skip_getters());
+        if occupied {
+            return None
+        }
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Writes the bytes of `self` to `sink` unless they equal the bytes of `current`.
+                ///
+                /// Returns `true` if the bytes were written, e.g. to keep writes to
+                /// EEPROM or flash backed configuration to a minimum.
+                ///
+                /// # Errors
+                ///
+                /// If `sink` returns an error.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn write_if_changed<E, F>(
+                    &self,
+                    current: &Self,
+                    mut sink: F,
+                ) -> ::core::result::Result<::core::primitive::bool, E>
+                where
+                    F: ::core::ops::FnMut(&[::core::primitive::u8]) -> ::core::result::Result<(), E>,
+                {
+                    if self.#storage == current.#storage {
+                        return ::core::result::Result::Ok(false)
+                    }
+                    sink(&self.#storage[..])?;
+                    ::core::result::Result::Ok(true)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `FooFlags` type for the `#[flags]` fields of a `#[bitfield]` struct `Foo`.
+    ///
+    /// The analysis guarantees that the `#[flags]` fields are a contiguous run of at most
+    /// 128 `bool` fields so that the flags map directly onto the underlying bits.
+    fn expand_flags_group(&self, config: &Config) -> Option<TokenStream2> {
+        let storage = config.storage_ident();
+        let flags = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| info.config.is_flag())
+            .collect::<Vec<_>>();
+        let (_, offset) = flags.first()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let flags_ident = format_ident!("{}Flags", ident);
+        let count = flags.len();
+        let prim = match count {
+            0..=8 => quote! { ::core::primitive::u8 },
+            9..=16 => quote! { ::core::primitive::u16 },
+            17..=32 => quote! { ::core::primitive::u32 },
+            33..=64 => quote! { ::core::primitive::u64 },
+            _ => quote! { ::core::primitive::u128 },
+        };
+        let all_bits = match count {
+            128 => u128::MAX,
+            _ => (1_u128 << count) - 1,
+        };
+        let all_bits = syn::LitInt::new(&all_bits.to_string(), span);
+        let consts = flags.iter().enumerate().map(|(index, (info, _))| {
+            let span = info.field.span();
+            let name = info.name();
+            let const_ident = format_ident!("{}", name.to_uppercase(), span = span);
+            let docs = format!("The flag of field `{}`.", name);
+            quote_spanned!(span=>
+                #[doc = #docs]
+                pub const #const_ident: Self = Self { bits: (0x01 as #prim) << #index };
+            )
+        });
+        let struct_docs = format!("The flags of the `#[flags]` fields of [`{}`].", ident);
+        Some(quote_spanned!(span=>
+            #[doc = #struct_docs]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::marker::Copy,
+                ::core::clone::Clone,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+                ::core::default::Default,
+            )]
+            #vis struct #flags_ident {
+                bits: #prim,
+            }
+
+            #[allow(dead_code)]
+            impl #flags_ident {
+                #( #consts )*
+
+                /// Returns a set without any flags.
+                #[inline]
+                pub const fn empty() -> Self {
+                    Self { bits: 0 }
+                }
+
+                /// Returns a set with all flags.
+                #[inline]
+                pub const fn all() -> Self {
+                    Self { bits: #all_bits }
+                }
+
+                /// Returns the raw bits of the set with the first flag in the least significant bit.
+                #[inline]
+                pub const fn bits(&self) -> #prim {
+                    self.bits
+                }
+
+                /// Creates a set from the given raw bits dropping all bits that do not correspond to a flag.
+                #[inline]
+                pub const fn from_bits_truncate(bits: #prim) -> Self {
+                    Self { bits: bits & #all_bits }
+                }
+
+                /// Returns `true` if the set contains no flags.
+                #[inline]
+                pub const fn is_empty(&self) -> ::core::primitive::bool {
+                    self.bits == 0
+                }
+
+                /// Returns `true` if the set contains all flags of `other`.
+                #[inline]
+                pub const fn contains(&self, other: Self) -> ::core::primitive::bool {
+                    self.bits & other.bits == other.bits
+                }
+
+                /// Inserts all flags of `other` into the set.
+                #[inline]
+                pub fn insert(&mut self, other: Self) {
+                    self.bits |= other.bits;
+                }
+
+                /// Removes all flags of `other` from the set.
+                #[inline]
+                pub fn remove(&mut self, other: Self) {
+                    self.bits &= !other.bits;
+                }
+            }
+
+            impl ::core::ops::BitOr for #flags_ident {
+                type Output = Self;
+
+                #[inline]
+                fn bitor(self, rhs: Self) -> Self::Output {
+                    Self { bits: self.bits | rhs.bits }
+                }
+            }
+
+            impl ::core::ops::BitAnd for #flags_ident {
+                type Output = Self;
+
+                #[inline]
+                fn bitand(self, rhs: Self) -> Self::Output {
+                    Self { bits: self.bits & rhs.bits }
+                }
             }
-        )
+
+            impl ::core::ops::Not for #flags_ident {
+                type Output = Self;
+
+                #[inline]
+                fn not(self) -> Self::Output {
+                    Self::from_bits_truncate(!self.bits)
+                }
+            }
+
+            impl #ident {
+                /// Returns the flags of the `#[flags]` fields.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                pub fn flag_group(&self) -> #flags_ident {
+                    #flags_ident {
+                        bits: ::modular_bitfield::raw::read(&self.#storage[..], #offset, #count) as #prim,
+                    }
+                }
+
+                /// Sets the `#[flags]` fields to the given flags.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                pub fn set_flag_group(&mut self, flags: #flags_ident) {
+                    ::modular_bitfield::raw::write(
+                        &mut self.#storage[..],
+                        #offset,
+                        #count,
+                        flags.bits as ::core::primitive::u128,
+                    );
+                }
+
+                /// Returns a copy of the bitfield with the `#[flags]` fields set to the given flags.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn with_flag_group(mut self, flags: #flags_ident) -> Self {
+                    self.set_flag_group(flags);
+                    self
+                }
+            }
+
+            impl ::core::convert::From<#flags_ident> for #ident {
+                #[inline]
+                fn from(flags: #flags_ident) -> Self {
+                    Self::new().with_flag_group(flags)
+                }
+            }
+
+            impl ::core::convert::From<#ident> for #flags_ident {
+                #[inline]
+                fn from(bitfield: #ident) -> Self {
+                    bitfield.flag_group()
+                }
+            }
+        ))
+    }
+
+    /// Generates `From` impls between the `#[bitfield]` struct and its byte array.
+    ///
+    /// For bitfields with `filled = false` the conversion from the byte array is
+    /// fallible and therefore a `TryFrom` impl is generated instead.
+    ///
+    /// Returns `None` if the byte conversion methods are not publicly visible.
+    fn expand_byte_array_from_impls(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.bytes_public() {
+            return None
+        }
+        let storage = config.storage_ident();
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let from_array_impl = match config.filled_enabled() {
+            true => {
+                quote_spanned!(span=>
+                    #[allow(clippy::identity_op)]
+                    impl ::core::convert::From<[::core::primitive::u8; #next_divisible_by_8 / 8usize]> for #ident {
+                        #[inline]
+                        fn from(__bf_bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
+                            Self::from_bytes(__bf_bytes)
+                        }
+                    }
+                )
+            }
+            false => {
+                quote_spanned!(span=>
+                    #[allow(clippy::identity_op)]
+                    impl ::core::convert::TryFrom<[::core::primitive::u8; #next_divisible_by_8 / 8usize]> for #ident {
+                        type Error = ::modular_bitfield::error::OutOfBounds;
+
+                        #[inline]
+                        fn try_from(
+                            __bf_bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                        ) -> ::core::result::Result<Self, Self::Error> {
+                            Self::from_bytes(__bf_bytes)
+                        }
+                    }
+                )
+            }
+        };
+        let from_bytes = match config.filled_enabled() {
+            true => quote_spanned!(span=> ::core::result::Result::Ok(Self::from_bytes(__bf_bytes))),
+            false => quote_spanned!(span=> Self::from_bytes(__bf_bytes)),
+        };
+        Some(quote_spanned!(span=>
+            #from_array_impl
+
+            #[allow(clippy::identity_op)]
+            impl ::modular_bitfield::Bitfield for #ident {
+                type Bytes = [::core::primitive::u8; #next_divisible_by_8 / 8usize];
+
+                #[inline]
+                fn zeroed_bytes() -> Self::Bytes {
+                    [0x00_u8; #next_divisible_by_8 / 8usize]
+                }
+
+                #[inline]
+                fn as_bytes(&self) -> &Self::Bytes {
+                    &self.#storage
+                }
+
+                #[inline]
+                fn into_bytes(self) -> Self::Bytes {
+                    Self::into_bytes(self)
+                }
+
+                #[inline]
+                fn from_bytes(
+                    __bf_bytes: Self::Bytes,
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                    #from_bytes
+                }
+            }
+
+            #[allow(clippy::identity_op)]
+            impl ::core::convert::From<#ident> for [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                #[inline]
+                fn from(__bf_bitfield: #ident) -> Self {
+                    __bf_bitfield.into_bytes()
+                }
+            }
+        ))
+    }
+
+    /// Generates the conversions into the bitfield from all bitfields given to `reinterpret`.
+    ///
+    /// The conversions reinterpret the bytes of the other bitfield as the bytes of this one.
+    /// Only this direction is generated so that two bitfields may list each other and since
+    /// only the infallible `from_bytes` of this bitfield is known to exist.
+    fn expand_reinterpret_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let reinterpret = config.reinterpret.as_ref()?;
+        let ident = &self.item_struct.ident;
+        let impls = reinterpret.value.iter().map(|other| {
+            let span = other.span();
+            quote_spanned!(span=>
+                const _: () = {
+                    ::modular_bitfield::private::static_assertions::assert_eq_size!(#ident, #other);
+                };
+
+                impl ::core::convert::From<#other> for #ident {
+                    /// Reinterprets the bytes of the given bitfield.
+                    #[inline]
+                    fn from(value: #other) -> Self {
+                        Self::from_bytes(value.into_bytes())
+                    }
+                }
+            )
+        });
+        Some(quote! { #( #impls )* })
     }
 
     /// Generates code to check for the bit size arguments of bitfields.
@@ -489,6 +4168,10 @@ impl BitfieldStruct {
         &self,
         offset: &Punctuated<syn::Expr, syn::Token![+]>,
         info: &FieldInfo<'_>,
+        storage: &syn::Member,
+        access: FieldAccess,
+        inline: InlineKind,
+        no_panic: bool,
     ) -> Option<TokenStream2> {
         let FieldInfo {
             index: _,
@@ -499,21 +4182,13 @@ impl BitfieldStruct {
             return None
         }
         let struct_ident = &self.item_struct.ident;
+        let struct_name = struct_ident.to_string();
         let span = field.span();
-        let ident = info.ident_frag();
         let name = info.name();
 
         let retained_attrs = &config.retained_attrs;
-        let get_ident = field
-            .ident
-            .as_ref()
-            .cloned()
-            .unwrap_or_else(|| format_ident!("get_{}", ident));
-        let get_checked_ident = field
-            .ident
-            .as_ref()
-            .map(|_| format_ident!("{}_or_err", ident))
-            .unwrap_or_else(|| format_ident!("get_{}_or_err", ident));
+        let get_ident = info.get_ident();
+        let get_checked_ident = info.get_checked_ident();
         let ty = &field.ty;
         let vis = &field.vis;
         let get_assert_msg = format!(
@@ -521,6 +4196,8 @@ impl BitfieldStruct {
             struct_ident, name
         );
 
+        let inline = inline.expand(span);
+        let read_field = self.expand_read_field(offset, info, storage, access);
         let getter_docs = format!("Returns the value of {}.", name);
         let checked_getter_docs = format!(
             "Returns the value of {}.\n\n\
@@ -528,16 +4205,22 @@ impl BitfieldStruct {
              If the returned value contains an invalid bit pattern for {}.",
             name, name,
         );
+        let panicking_getter = match no_panic {
+            true => None,
+            false => Some(quote_spanned!(span=>
+                #[doc = #getter_docs]
+                #inline
+                #( #retained_attrs )*
+                #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                    self.#get_checked_ident().expect(#get_assert_msg)
+                }
+            )),
+        };
         let getters = quote_spanned!(span=>
-            #[doc = #getter_docs]
-            #[inline]
-            #( #retained_attrs )*
-            #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
-                self.#get_checked_ident().expect(#get_assert_msg)
-            }
+            #panicking_getter
 
             #[doc = #checked_getter_docs]
-            #[inline]
+            #inline
             #[allow(dead_code)]
             #( #retained_attrs )*
             #vis fn #get_checked_ident(
@@ -547,18 +4230,62 @@ impl BitfieldStruct {
                 ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
             > {
                 let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
-                    ::modular_bitfield::private::read_specifier::<#ty>(&self.bytes[..], #offset)
+                    #read_field
                 };
-                <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+                <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read).map_err(|err| {
+                    err.with_context(::modular_bitfield::error::FieldContext {
+                        struct_name: #struct_name,
+                        field_name: #name,
+                        offset: #offset,
+                        bits: <#ty as ::modular_bitfield::Specifier>::BITS,
+                    })
+                })
             }
         );
         Some(getters)
     }
 
+    /// Generates the statements that apply the `#[on_overflow = ".."]` policy
+    /// of the field to `new_val` before it is passed to the checked setter.
+    ///
+    /// Returns `None` for the default policy that leaves `new_val` as is.
+    fn expand_overflow_handling(info: &FieldInfo<'_>) -> Option<TokenStream2> {
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let max_value = quote_spanned!(span=>
+            let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::InOut = {
+                !0 >> (8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::InOut>()
+                    - <#ty as ::modular_bitfield::Specifier>::BITS)
+            };
+        );
+        match info.config.overflow_kind() {
+            OverflowKind::Error => None,
+            OverflowKind::Saturate => {
+                Some(quote_spanned!(span=>
+                    #max_value
+                    let new_val = match new_val > __bf_max_value {
+                        true => __bf_max_value,
+                        false => new_val,
+                    };
+                ))
+            }
+            OverflowKind::Truncate => {
+                Some(quote_spanned!(span=>
+                    #max_value
+                    let new_val = new_val & __bf_max_value;
+                ))
+            }
+        }
+    }
+
     fn expand_setters_for_field(
         &self,
         offset: &Punctuated<syn::Expr, syn::Token![+]>,
         info: &FieldInfo<'_>,
+        storage: &syn::Member,
+        access: FieldAccess,
+        inline: InlineKind,
+        bitfield_config: &Config,
     ) -> Option<TokenStream2> {
         let FieldInfo {
             index: _,
@@ -581,14 +4308,65 @@ impl BitfieldStruct {
         let set_checked_ident = format_ident!("set_{}_checked", ident);
         let with_ident = format_ident!("with_{}", ident);
         let with_checked_ident = format_ident!("with_{}_checked", ident);
+        let clear_ident = format_ident!("clear_{}", ident);
+        let set_from_ident = format_ident!("set_{}_from", ident);
+        let with_from_ident = format_ident!("with_{}_from", ident);
 
-        let set_assert_msg =
-            format!("value out of bounds for field {}.{}", struct_ident, name);
+        let inline = inline.expand(span);
+        let write_field = self.expand_write_field(
+            offset,
+            info,
+            storage,
+            access,
+            quote_spanned!(span=> __bf_raw_val),
+        );
+        let (clear_value, clear_docs) = match bitfield_config.default {
+            Some(_) => {
+                (
+                    quote_spanned!(span=>
+                        ::modular_bitfield::private::read_specifier::<#ty>(
+                            &#struct_ident::__BF_RESET_BYTES[..],
+                            #offset,
+                        )
+                    ),
+                    format!("Resets all bits of {} to its bits of the `default` parameter.", name),
+                )
+            }
+            None => (quote_spanned!(span=> 0), format!("Resets all bits of {} to zero.", name)),
+        };
+        let clear_field = self.expand_write_field(
+            offset,
+            info,
+            storage,
+            access,
+            quote_spanned!(span=> __bf_clear_val),
+        );
+        let checked_error = config.checked_setter_error();
+        let set_assert_msg = match config.validator() {
+            Some(_) => format!("invalid value for field {}.{}", struct_ident, name),
+            None => format!("value out of bounds for field {}.{}", struct_ident, name),
+        };
+        let overflow_kind = config.overflow_kind();
+        let overflow_docs = match overflow_kind {
+            OverflowKind::Error => format!("If the given value is out of bounds for {}.", name),
+            OverflowKind::Saturate => {
+                format!(
+                    "Out of bounds values are clamped to the maximum value of {}.",
+                    name
+                )
+            }
+            OverflowKind::Truncate => {
+                format!(
+                    "Out of bounds values are truncated to the bits of {}.",
+                    name
+                )
+            }
+        };
         let setter_docs = format!(
             "Sets the value of {} to the given value.\n\n\
              #Panics\n\n\
-             If the given value is out of bounds for {}.",
-            name, name,
+             {}",
+            name, overflow_docs,
         );
         let checked_setter_docs = format!(
             "Sets the value of {} to the given value.\n\n\
@@ -600,8 +4378,8 @@ impl BitfieldStruct {
             "Returns a copy of the bitfield with the value of {} \
              set to the given value.\n\n\
              #Panics\n\n\
-             If the given value is out of bounds for {}.",
-            name, name,
+             {}",
+            name, overflow_docs,
         );
         let checked_with_docs = format!(
             "Returns a copy of the bitfield with the value of {} \
@@ -610,108 +4388,920 @@ impl BitfieldStruct {
              If the given value is out of bounds for {}.",
             name, name,
         );
+        let set_from_docs = format!(
+            "Sets the value of {} to the given value after converting it.\n\n\
+             #Panics\n\n\
+             If the converted value is out of bounds for {}.",
+            name, name,
+        );
+        let with_from_docs = format!(
+            "Returns a copy of the bitfield with the value of {} \
+             set to the given value after converting it.\n\n\
+             #Panics\n\n\
+             If the converted value is out of bounds for {}.",
+            name, name,
+        );
+        let validate_new_val = config.validator().map(|validator| {
+            quote_spanned!(validator.span()=>
+                if let ::core::result::Result::Err(err) = #validator(&new_val) {
+                    return ::core::result::Result::Err(::modular_bitfield::error::ValidateError::Invalid(err))
+                }
+            )
+        });
+        let out_of_bounds_err = match config.validator() {
+            Some(_) => {
+                quote_spanned!(span=> ::modular_bitfield::error::ValidateError::OutOfBounds(__bf_err))
+            }
+            None => quote_spanned!(span=> __bf_err),
+        };
+        let handle_overflow = Self::expand_overflow_handling(info);
+        let check_reserved = match bitfield_config.check_reserved_enabled() {
+            true => Some(quote_spanned!(span=> self.__bf_debug_assert_reserved();)),
+            false => None,
+        };
+        let panicking_setters = match bitfield_config.no_panic_enabled() {
+            true => None,
+            false => Some(quote_spanned!(span=>
+                #[doc = #with_docs]
+                #inline
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #with_ident(
+                    mut self,
+                    new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+                ) -> Self {
+                    self.#set_ident(new_val);
+                    self
+                }
+
+                #[doc = #setter_docs]
+                #inline
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #set_ident(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
+                    #handle_overflow
+                    if let ::core::result::Result::Err(err) = self.#set_checked_ident(new_val) {
+                        ::core::panic!("{}: {}", #set_assert_msg, err)
+                    }
+                }
+
+                #[doc = #with_from_docs]
+                #inline
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #with_from_ident(
+                    mut self,
+                    new_val: impl ::core::convert::Into<<#ty as ::modular_bitfield::Specifier>::InOut>,
+                ) -> Self {
+                    self.#set_from_ident(new_val);
+                    self
+                }
+
+                #[doc = #set_from_docs]
+                #inline
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #set_from_ident(
+                    &mut self,
+                    new_val: impl ::core::convert::Into<<#ty as ::modular_bitfield::Specifier>::InOut>,
+                ) {
+                    self.#set_ident(::core::convert::Into::into(new_val))
+                }
+            )),
+        };
         let setters = quote_spanned!(span=>
-            #[doc = #with_docs]
-            #[inline]
+            #panicking_setters
+
+            #[doc = #checked_with_docs]
+            #inline
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #with_checked_ident(
+                mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+            ) -> ::core::result::Result<Self, #checked_error> {
+                self.#set_checked_ident(new_val)?;
+                ::core::result::Result::Ok(self)
+            }
+
+            #[doc = #checked_setter_docs]
+            #inline
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #set_checked_ident(
+                &mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) -> ::core::result::Result<(), #checked_error> {
+                #validate_new_val
+                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes =
+                    (!0 as <#ty as ::modular_bitfield::Specifier>::Bytes)
+                        .checked_shr((__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS) as ::core::primitive::u32)
+                        .unwrap_or(0);
+                let __bf_spec_bits: ::core::primitive::usize = <#ty as ::modular_bitfield::Specifier>::BITS;
+                let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                    <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
+                }?;
+                // We compare base bits with spec bits to drop this condition
+                // if there cannot be invalid inputs.
+                if !(__bf_base_bits == __bf_spec_bits || __bf_raw_val <= __bf_max_value) {
+                    let __bf_err = ::modular_bitfield::error::OutOfBounds::with_value(
+                        <<#ty as ::modular_bitfield::Specifier>::Bytes as ::modular_bitfield::private::WordAccess>::into_u128(__bf_raw_val),
+                        __bf_spec_bits,
+                    );
+                    return ::core::result::Result::Err(#out_of_bounds_err)
+                }
+                #write_field
+                #check_reserved
+                ::core::result::Result::Ok(())
+            }
+
+            #[doc = #clear_docs]
+            #inline
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #clear_ident(&mut self) {
+                let __bf_clear_val: <#ty as ::modular_bitfield::Specifier>::Bytes = #clear_value;
+                #clear_field
+            }
+        );
+        Some(setters)
+    }
+
+    fn expand_getters_and_setters_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        static_offset: Option<usize>,
+        info: FieldInfo<'_>,
+        storage: &syn::Member,
+        with_setters: bool,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        let FieldInfo {
+            index: _, field, ..
+        } = &info;
+        let span = field.span();
+        let inline = config.inline_for(&info.config);
+        let access = if config.outline_accessors() {
+            FieldAccess::Outline
+        } else if config.min_code_size_enabled() {
+            FieldAccess::Shared
+        } else {
+            ByteAlignedField::new(static_offset, &info)
+                .map(FieldAccess::ByteAligned)
+                .unwrap_or(FieldAccess::Generic)
+        };
+        let no_panic = config.no_panic_enabled();
+        let getters =
+            self.expand_getters_for_field(offset, &info, storage, access, inline, no_panic);
+        let setters = match with_setters {
+            true => {
+                self.expand_setters_for_field(offset, &info, storage, access, inline, config)
+            }
+            false => None,
+        };
+        let write_one_accessors = match with_setters {
+            true => self.expand_write_one_accessors_for_field(offset, &info, storage, inline, config),
+            false => None,
+        };
+        let replace_accessors = match with_setters {
+            true => Self::expand_replace_accessors_for_field(&info, inline, config),
+            false => None,
+        };
+        let take_accessors = match with_setters {
+            true => self.expand_take_accessors_for_field(offset, &info, storage, access, inline, config),
+            false => None,
+        };
+        let getters_and_setters = quote_spanned!(span=>
+            #getters
+            #setters
+            #write_one_accessors
+            #replace_accessors
+            #take_accessors
+        );
+        Some(getters_and_setters)
+    }
+
+    /// Generates the `replace_f` method of a field with both getters and setters.
+    ///
+    /// Not generated with `no_panic = true` since it builds upon the panicking accessors.
+    fn expand_replace_accessors_for_field(
+        info: &FieldInfo<'_>,
+        inline: InlineKind,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        if info.config.skip_getters() || info.config.skip_setters() || config.no_panic_enabled() {
+            return None
+        }
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let vis = &info.field.vis;
+        let retained_attrs = &info.config.retained_attrs;
+        let ident = info.ident_frag();
+        let name = info.name();
+        let get_ident = info.get_ident();
+        let set_ident = format_ident!("set_{}", ident);
+        let replace_ident = format_ident!("replace_{}", ident);
+        let get_path = Self::expand_accessor_path(config, span, &get_ident);
+        let set_path = Self::expand_accessor_path(config, span, &set_ident);
+        let inline = inline.expand(span);
+        let replace_docs = format!(
+            "Sets the value of {} to the given value and returns its previous value.\n\n\
+             #Panics\n\n\
+             If the previous value contains an invalid bit pattern for {} \
+             or if the given value is out of bounds for {}.",
+            name, name, name,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #replace_docs]
+            #inline
             #[allow(dead_code)]
             #( #retained_attrs )*
-            #vis fn #with_ident(
-                mut self,
-                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
-            ) -> Self {
-                self.#set_ident(new_val);
-                self
+            #vis fn #replace_ident(
+                &mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+            ) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                let __bf_previous = #get_path(self);
+                #set_path(self, new_val);
+                __bf_previous
+            }
+        ))
+    }
+
+    /// Returns the path through which generated accessors call the given accessor.
+    ///
+    /// With the `accessor_trait` parameter the accessors are trait methods that would
+    /// otherwise be shadowed by inherent methods of the same name.
+    fn expand_accessor_path(config: &Config, span: Span, method: &syn::Ident) -> TokenStream2 {
+        match &config.accessor_trait {
+            Some(accessor_trait) => {
+                let accessor_trait = &accessor_trait.value;
+                quote_spanned!(span=> <Self as #accessor_trait>::#method)
+            }
+            None => quote_spanned!(span=> Self::#method),
+        }
+    }
+
+    /// Generates the `take_f` method of a field with both getters and setters.
+    ///
+    /// The field is reset to its bits of the `default` parameter if given and to zero otherwise.
+    /// Not generated with `no_panic = true` since it builds upon the panicking getter.
+    fn expand_take_accessors_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        storage: &syn::Member,
+        access: FieldAccess,
+        inline: InlineKind,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        if info.config.skip_getters() || info.config.skip_setters() || config.no_panic_enabled() {
+            return None
+        }
+        let struct_ident = &self.item_struct.ident;
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let vis = &info.field.vis;
+        let retained_attrs = &info.config.retained_attrs;
+        let ident = info.ident_frag();
+        let name = info.name();
+        let get_ident = info.get_ident();
+        let take_ident = format_ident!("take_{}", ident);
+        let get_path = Self::expand_accessor_path(config, span, &get_ident);
+        let inline = inline.expand(span);
+        let (reset_value, reset_docs) = match config.default {
+            Some(_) => {
+                (
+                    quote_spanned!(span=>
+                        ::modular_bitfield::private::read_specifier::<#ty>(
+                            &#struct_ident::__BF_RESET_BYTES[..],
+                            #offset,
+                        )
+                    ),
+                    "its bits of the `default` parameter",
+                )
+            }
+            None => (quote_spanned!(span=> 0), "zero"),
+        };
+        let reset_field = self.expand_write_field(
+            offset,
+            info,
+            storage,
+            access,
+            quote_spanned!(span=> __bf_reset_val),
+        );
+        let take_docs = format!(
+            "Returns the value of {} and resets all bits of {} to {}.\n\n\
+             #Panics\n\n\
+             If the returned value contains an invalid bit pattern for {}.",
+            name, name, reset_docs, name,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #take_docs]
+            #inline
+            #[allow(dead_code, clippy::identity_op)]
+            #( #retained_attrs )*
+            #vis fn #take_ident(&mut self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                let __bf_previous = #get_path(self);
+                let __bf_reset_val: <#ty as ::modular_bitfield::Specifier>::Bytes = #reset_value;
+                #reset_field
+                __bf_previous
             }
+        ))
+    }
 
-            #[doc = #checked_with_docs]
-            #[inline]
+    /// Generates the `clear_f` or `set_f` method of an `#[access(w1c)]` or `#[access(w1s)]` field.
+    ///
+    /// It writes all ones to the field and zeros to all other write one fields
+    /// so that writing back the bytes only triggers the action of this field.
+    fn expand_write_one_accessors_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        storage: &syn::Member,
+        inline: InlineKind,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        if !info.config.write_one_accessors() {
+            return None
+        }
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let vis = &info.field.vis;
+        let retained_attrs = &info.config.retained_attrs;
+        let ident = info.ident_frag();
+        let name = info.name();
+        let (method_ident, action) = match info.config.access_kind() {
+            AccessKind::WriteOneToClear => (format_ident!("clear_{}", ident), "clear"),
+            _ => (format_ident!("set_{}", ident), "set"),
+        };
+        let docs = format!(
+            "Writes all ones to {} in order to {} its bits.\n\n\
+             All other write one fields are set to zero so that they are not affected.",
+            name, action,
+        );
+        let inline = inline.expand(span);
+        let zero_siblings = self
+            .field_infos_with_offsets(config)
+            .filter(|(sibling, _)| {
+                sibling.index != info.index && sibling.config.access_kind().is_write_one()
+            })
+            .map(|(sibling, offset)| {
+                let ty = &sibling.field.ty;
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::write_specifier::<#ty>(&mut self.#storage[..], #offset, 0);
+                )
+            });
+        Some(quote_spanned!(span=>
+            #[doc = #docs]
+            #inline
             #[allow(dead_code)]
             #( #retained_attrs )*
-            #vis fn #with_checked_ident(
-                mut self,
-                new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
-            ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
-                self.#set_checked_ident(new_val)?;
-                ::core::result::Result::Ok(self)
+            #vis fn #method_ident(&mut self) {
+                #( #zero_siblings )*
+                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                let __bf_ones: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                    !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                };
+                ::modular_bitfield::private::write_specifier::<#ty>(&mut self.#storage[..], #offset, __bf_ones);
             }
+        ))
+    }
 
-            #[doc = #setter_docs]
-            #[inline]
-            #[allow(dead_code)]
-            #( #retained_attrs )*
-            #vis fn #set_ident(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
-                self.#set_checked_ident(new_val).expect(#set_assert_msg)
+    /// Generates the expression reading the raw bits of the field from `self.storage`.
+    fn expand_read_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        storage: &syn::Member,
+        access: FieldAccess,
+    ) -> TokenStream2 {
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        match access {
+            FieldAccess::Generic => {
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::read_specifier::<#ty>(&self.#storage[..], #offset)
+                )
+            }
+            FieldAccess::Shared => {
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::read_specifier_shared::<#ty>(&self.#storage[..], #offset)
+                )
+            }
+            FieldAccess::Outline => {
+                let struct_ident = &self.item_struct.ident;
+                let read_ident = format_ident!("__bf_read_{}", info.ident_frag());
+                quote_spanned!(span=>
+                    #struct_ident::#read_ident(&self.#storage[..])
+                )
+            }
+            FieldAccess::ByteAligned(aligned) => {
+                let checks = aligned.expand_checks(offset, ty);
+                let bytes = aligned.byte_indices();
+                quote_spanned!(span=>
+                    #checks
+                    <<#ty as ::modular_bitfield::Specifier>::Bytes>::from_le_bytes([
+                        #( self.#storage[#bytes] ),*
+                    ])
+                )
             }
+        }
+    }
 
-            #[doc = #checked_setter_docs]
-            #[inline]
-            #( #retained_attrs )*
-            #vis fn #set_checked_ident(
-                &mut self,
-                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
-            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
-                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
-                let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
-                    !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
-                };
-                let __bf_spec_bits: ::core::primitive::usize = <#ty as ::modular_bitfield::Specifier>::BITS;
-                let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
-                    <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
-                }?;
-                // We compare base bits with spec bits to drop this condition
-                // if there cannot be invalid inputs.
-                if !(__bf_base_bits == __bf_spec_bits || __bf_raw_val <= __bf_max_value) {
-                    return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+    /// Generates the statement writing the raw bits of `value` into the field in `self.storage`.
+    fn expand_write_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        storage: &syn::Member,
+        access: FieldAccess,
+        value: TokenStream2,
+    ) -> TokenStream2 {
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        match access {
+            FieldAccess::Generic => {
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::write_specifier::<#ty>(&mut self.#storage[..], #offset, #value);
+                )
+            }
+            FieldAccess::Shared => {
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::write_specifier_shared::<#ty>(&mut self.#storage[..], #offset, #value);
+                )
+            }
+            FieldAccess::Outline => {
+                let struct_ident = &self.item_struct.ident;
+                let write_ident = format_ident!("__bf_write_{}", info.ident_frag());
+                quote_spanned!(span=>
+                    #struct_ident::#write_ident(&mut self.#storage[..], #value);
+                )
+            }
+            FieldAccess::ByteAligned(aligned) => {
+                let checks = aligned.expand_checks(offset, ty);
+                let bytes = aligned.byte_indices();
+                let positions = 0..aligned.bits / 8;
+                quote_spanned!(span=>
+                    #checks
+                    let __bf_le_bytes = <<#ty as ::modular_bitfield::Specifier>::Bytes>::to_le_bytes(#value);
+                    #( self.#storage[#bytes] = __bf_le_bytes[#positions]; )*
+                )
+            }
+        }
+    }
+
+    /// Generates the hidden `#[inline(never)]` functions performing the bit access
+    /// of every field if `accessors = "outline"` is set.
+    ///
+    /// All accessors of a field, including those of the generated view types,
+    /// share these functions instead of containing the bit access code themselves.
+    fn expand_outlined_accessors(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.outline_accessors() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (read_fn, write_fn) = match config.min_code_size_enabled() {
+            true => (quote!(read_specifier_shared), quote!(write_specifier_shared)),
+            false => (quote!(read_specifier), quote!(write_specifier)),
+        };
+        let accessors = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            let span = info.field.span();
+            let ty = &info.field.ty;
+            let read_ident = format_ident!("__bf_read_{}", info.ident_frag());
+            let write_ident = format_ident!("__bf_write_{}", info.ident_frag());
+            let cfg_attrs = info.cfg_attrs().collect::<Vec<_>>();
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                #[doc(hidden)]
+                #[inline(never)]
+                #[allow(dead_code)]
+                fn #read_ident(
+                    __bf_bytes: &[::core::primitive::u8],
+                ) -> <#ty as ::modular_bitfield::Specifier>::Bytes {
+                    ::modular_bitfield::private::#read_fn::<#ty>(__bf_bytes, #offset)
                 }
-                ::modular_bitfield::private::write_specifier::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val);
-                ::core::result::Result::Ok(())
+
+                #( #cfg_attrs )*
+                #[doc(hidden)]
+                #[inline(never)]
+                #[allow(dead_code)]
+                fn #write_ident(
+                    __bf_bytes: &mut [::core::primitive::u8],
+                    __bf_value: <#ty as ::modular_bitfield::Specifier>::Bytes,
+                ) {
+                    ::modular_bitfield::private::#write_fn::<#ty>(__bf_bytes, #offset, __bf_value)
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #( #accessors )*
             }
-        );
-        Some(setters)
+        ))
     }
 
-    fn expand_getters_and_setters_for_field(
+    /// Generates the getters and optionally the setters of all fields operating on
+    /// the bytes stored in the given `storage` member of `self`.
+    pub fn expand_field_accessors(
         &self,
-        offset: &mut Punctuated<syn::Expr, syn::Token![+]>,
-        info: FieldInfo<'_>,
-    ) -> Option<TokenStream2> {
-        let FieldInfo {
-            index: _, field, ..
-        } = &info;
-        let span = field.span();
-        let ty = &field.ty;
-        let getters = self.expand_getters_for_field(offset, &info);
-        let setters = self.expand_setters_for_field(offset, &info);
-        let getters_and_setters = quote_spanned!(span=>
-            #getters
-            #setters
+        config: &Config,
+        storage: &syn::Member,
+        with_setters: bool,
+    ) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let setters_and_getters = self.field_infos_with_known_offsets(config).map(
+            |(field_info, offset, static_offset)| {
+                self.expand_getters_and_setters_for_field(
+                    &offset,
+                    static_offset,
+                    field_info,
+                    storage,
+                    with_setters,
+                    config,
+                )
+            },
         );
-        offset.push(syn::parse_quote! { <#ty as ::modular_bitfield::Specifier>::BITS });
-        Some(getters_and_setters)
+        quote_spanned!(span=>
+            #( #setters_and_getters )*
+        )
     }
 
     fn expand_getters_and_setters(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let mut offset = {
-            let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
-            offset.push(syn::parse_quote! { 0usize });
-            offset
+        let bits_checks = self
+            .field_infos(config)
+            .map(|field_info| self.expand_bits_checks_for_field(field_info));
+        let setters_and_getters =
+            self.expand_field_accessors(config, &syn::Member::Named(config.storage_ident()), true);
+        let accessors = match &config.accessor_trait {
+            Some(accessor_trait) => self.expand_accessor_trait(&accessor_trait.value, setters_and_getters),
+            None => {
+                quote_spanned!(span=>
+                    impl #ident {
+                        #setters_and_getters
+                    }
+                )
+            }
+        };
+        quote_spanned!(span=>
+            const _: () = {
+                #( #bits_checks )*
+            };
+
+            #accessors
+        )
+    }
+
+    /// Generates the trait declaring the given accessors together with its implementation
+    /// for the bitfield if the `accessor_trait` parameter has been set.
+    ///
+    /// The trait methods are declared with the signatures and docs of the inherent accessors
+    /// that would have been generated otherwise.
+    fn expand_accessor_trait(
+        &self,
+        accessor_trait: &syn::Ident,
+        setters_and_getters: TokenStream2,
+    ) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let accessors = match syn::parse2::<syn::ItemImpl>(quote!(impl #ident { #setters_and_getters })) {
+            Ok(accessors) => accessors,
+            Err(err) => return err.to_compile_error(),
         };
+        let mut declarations = Vec::new();
+        let mut methods = Vec::new();
+        for item in accessors.items {
+            let mut method = match item {
+                syn::ImplItem::Method(method) => method,
+                _ => continue,
+            };
+            method.vis = syn::Visibility::Inherited;
+            let mut sig = method.sig.clone();
+            // Patterns such as `mut self` are not allowed in methods without bodies.
+            for input in sig.inputs.iter_mut() {
+                match input {
+                    syn::FnArg::Receiver(receiver) => {
+                        if receiver.reference.is_none() {
+                            receiver.mutability = None;
+                        }
+                    }
+                    syn::FnArg::Typed(typed) => {
+                        if let syn::Pat::Ident(pat) = &mut *typed.pat {
+                            pat.mutability = None;
+                        }
+                    }
+                }
+            }
+            let attrs = method
+                .attrs
+                .iter()
+                .filter(|attr| !attr.path.is_ident("inline"));
+            declarations.push(quote_spanned!(span=>
+                #( #attrs )*
+                #sig;
+            ));
+            methods.push(method);
+        }
+        let trait_docs = format!("Accessors for the fields of [`{}`].", ident);
+        quote_spanned!(span=>
+            #[doc = #trait_docs]
+            #[allow(dead_code)]
+            #vis trait #accessor_trait: ::core::marker::Sized {
+                #( #declarations )*
+            }
+
+            impl #accessor_trait for #ident {
+                #( #methods )*
+            }
+        )
+    }
+
+    /// Expands a `#[bitfield]` struct with `cell = "volatile"` that stores its bytes in a `VolatileCell`.
+    ///
+    /// The field accessors are generated for a hidden bitfield type owning the bytes.
+    /// The accessors of the bitfield itself take `&self`, load the bytes into the hidden
+    /// type with a volatile read, forward to its accessors and store the modified bytes
+    /// back with a volatile write for setters.
+    fn expand_volatile_cell(&self, config: &Config) -> TokenStream2 {
+        let storage = config.storage_ident();
+        let markers = Self::expand_marker_inits(config);
+        let marker_fields = config.marker_fields.iter().map(|marker| &marker.field);
+        let storage_vis = config.storage_visibility();
+        let span = self.item_struct.span();
+        let attrs = &config.retained_attributes;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let bytes_vis = config.bytes_visibility();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let check_filled = self.generate_check_for_filled(config);
+        let bytes_check = self.expand_optional_bytes_check(config);
+        let overlap_checks = self.expand_overlap_checks(config);
+        let debug_impl = self.generate_debug_impl(config);
+        let outlined_accessors = self.expand_outlined_accessors(config);
+        let layout = self.expand_layout(config);
         let bits_checks = self
             .field_infos(config)
             .map(|field_info| self.expand_bits_checks_for_field(field_info));
-        let setters_and_getters = self.field_infos(config).map(|field_info| {
-            self.expand_getters_and_setters_for_field(&mut offset, field_info)
+        let loaded_accessors =
+            self.expand_field_accessors(config, &syn::Member::Unnamed(syn::Index::from(0)), true);
+        let occupied = self
+            .field_infos(config)
+            .any(|info| info.name() == "clear" && !info.config.skip_getters());
+        let clear = match occupied {
+            true => None,
+            false => {
+                Some(quote_spanned!(span=>
+                    /// Resets all bits of the bitfield to zero with a volatile write.
+                    #[inline]
+                    pub fn clear(&self) {
+                        self.#storage.set([0u8; #next_divisible_by_8 / 8usize])
+                    }
+                ))
+            }
+        };
+        let cell_accessors = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            self.expand_load_store_accessors_for_field(&info, &offset, "through the volatile cell")
         });
+        let (from_bytes, set_bytes) = match config.filled_enabled() {
+            true => {
+                (
+                    quote_spanned!(span=>
+                        /// Converts the given bytes directly into the bitfield struct.
+                        #[inline]
+                        #[allow(clippy::identity_op, dead_code)]
+                        #bytes_vis const fn from_bytes(bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
+                            Self { #markers #storage: ::modular_bitfield::private::VolatileCell::new(bytes) }
+                        }
+                    ),
+                    quote_spanned!(span=>
+                        /// Writes the given bytes with a volatile write.
+                        #[inline]
+                        #[allow(clippy::identity_op, dead_code)]
+                        #bytes_vis fn set_bytes(&self, bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]) {
+                            self.#storage.set(bytes)
+                        }
+                    ),
+                )
+            }
+            false => {
+                let padding_check = self.expand_padding_check(config);
+                (
+                    quote_spanned!(span=>
+                        /// Converts the given bytes directly into the bitfield struct.
+                        ///
+                        /// # Errors
+                        ///
+                        /// If the given bytes contain bits at positions that are undefined for `Self`.
+                        #[inline]
+                        #[allow(clippy::identity_op, dead_code)]
+                        #bytes_vis fn from_bytes(
+                            bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                        ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                            #padding_check
+                            ::core::result::Result::Ok(Self { #markers #storage: ::modular_bitfield::private::VolatileCell::new(bytes) })
+                        }
+                    ),
+                    quote_spanned!(span=>
+                        /// Writes the given bytes with a volatile write.
+                        ///
+                        /// # Errors
+                        ///
+                        /// If the given bytes contain bits at positions that are undefined for `Self`.
+                        #[inline]
+                        #[allow(clippy::identity_op, dead_code)]
+                        #bytes_vis fn set_bytes(
+                            &self,
+                            bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                        ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                            #padding_check
+                            self.#storage.set(bytes);
+                            ::core::result::Result::Ok(())
+                        }
+                    ),
+                )
+            }
+        };
+        let layout_docs = self.expand_layout_docs(config);
         quote_spanned!(span=>
+            #( #attrs )*
+            #layout_docs
+            #[repr(transparent)]
+            #[allow(clippy::identity_op)]
+            #vis struct #ident
+            {
+                #( #marker_fields, )*
+                #storage_vis #storage: ::modular_bitfield::private::VolatileCell<[::core::primitive::u8; #next_divisible_by_8 / 8usize]>,
+            }
+
+            #check_filled
+
+            #[allow(clippy::identity_op)]
             const _: () = {
                 #( #bits_checks )*
+
+                /// The bytes of the bitfield loaded from the volatile cell.
+                struct __BitfieldBytes([::core::primitive::u8; #next_divisible_by_8 / 8usize]);
+
+                #[allow(dead_code)]
+                impl __BitfieldBytes {
+                    #loaded_accessors
+                }
+
+                #[allow(dead_code)]
+                impl #ident {
+                    /// Returns an instance with zero initialized data.
+                    pub const fn new() -> Self {
+                        Self {
+                            #markers #storage: ::modular_bitfield::private::VolatileCell::new([0u8; #next_divisible_by_8 / 8usize]),
+                        }
+                    }
+
+                    #clear
+
+                    /// Returns the underlying bits.
+                    #[inline]
+                    #bytes_vis fn into_bytes(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                        self.#storage.get()
+                    }
+
+                    /// Reads the underlying bits with a volatile read.
+                    #[inline]
+                    #bytes_vis fn get_bytes(&self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                        self.#storage.get()
+                    }
+
+                    #set_bytes
+
+                    #from_bytes
+
+                    /// Loads all bytes of the bitfield with a volatile read.
+                    #[inline]
+                    fn __bf_load(&self, _offset: ::core::primitive::usize, _bits: ::core::primitive::usize) -> __BitfieldBytes {
+                        __BitfieldBytes(self.#storage.get())
+                    }
+
+                    /// Stores all bytes of the bitfield with a volatile write.
+                    #[inline]
+                    fn __bf_store(&self, bytes: __BitfieldBytes, _offset: ::core::primitive::usize, _bits: ::core::primitive::usize) {
+                        self.#storage.set(bytes.0)
+                    }
+
+                    #( #cell_accessors )*
+                }
             };
 
-            impl #ident {
-                #( #setters_and_getters )*
+            #outlined_accessors
+            #layout
+            #bytes_check
+            #overlap_checks
+            #debug_impl
+        )
+    }
+}
+
+/// The kind of the values of a field as reported by the `LAYOUT` constant.
+enum FieldKind {
+    /// A `bool` field.
+    Bool,
+    /// A `B1` to `B128`, `U1` to `U127` or (possibly narrowed) `u8` to `u128` field.
+    Unsigned,
+    /// A field of any other specifier type.
+    Specifier,
+}
+
+impl FieldKind {
+    /// Determines the kind of a field from its type.
+    fn of(ty: &syn::Type) -> Self {
+        let ident = match ty {
+            syn::Type::Path(path) if path.qself.is_none() => {
+                path.path.segments.last().map(|segment| segment.ident.to_string())
             }
+            _ => None,
+        };
+        match ident.as_deref() {
+            Some("bool") => Self::Bool,
+            Some("u8") | Some("u16") | Some("u32") | Some("u64") | Some("u128") => Self::Unsigned,
+            Some("NarrowedPrimitive") | Some("UInt") => Self::Unsigned,
+            Some(ident)
+                if ident.len() > 1
+                    && (ident.starts_with('B') || ident.starts_with('U'))
+                    && ident[1..].parse::<usize>().is_ok_and(|bits| (1..=128).contains(&bits)) =>
+            {
+                Self::Unsigned
+            }
+            _ => Self::Specifier,
+        }
+    }
+}
+
+/// Determines how the generated accessors of a field access its bits.
+#[derive(Copy, Clone)]
+enum FieldAccess {
+    /// Through the generic bit manipulation routines.
+    Generic,
+    /// Through the shared non-generic bit manipulation routines for `min_code_size = true`.
+    Shared,
+    /// Through the hidden `#[inline(never)]` functions for `accessors = "outline"`.
+    Outline,
+    /// Directly through the bytes of a byte aligned field.
+    ByteAligned(ByteAlignedField),
+}
+
+/// A field that starts at a byte boundary and spans the whole width of a primitive integer.
+///
+/// Accesses to such fields operate directly on their bytes instead of
+/// going through the generic bit manipulation routines.
+#[derive(Copy, Clone)]
+struct ByteAlignedField {
+    /// The bit offset of the field within the bitfield.
+    offset: usize,
+    /// The amount of bits of the field.
+    bits: usize,
+}
+
+impl ByteAlignedField {
+    /// Returns the byte aligned field for the field at the given bit offset if applicable.
+    ///
+    /// Returns `None` if the offset or the width of the field is unknown at expansion time
+    /// or if the field does not match a primitive integer at a byte boundary.
+    fn new(static_offset: Option<usize>, info: &FieldInfo<'_>) -> Option<Self> {
+        let offset = static_offset?;
+        let bits = info.specifier_bits()?;
+        if offset % 8 != 0 || !matches!(bits, 8 | 16 | 32 | 64 | 128) {
+            return None
+        }
+        Some(Self { offset, bits })
+    }
+
+    /// Returns the indices of the bytes covered by the field.
+    fn byte_indices(&self) -> core::ops::Range<usize> {
+        (self.offset / 8)..((self.offset + self.bits) / 8)
+    }
+
+    /// Generates compile-time checks asserting that the offset and width of the field
+    /// determined at expansion time match the actual ones.
+    fn expand_checks(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        ty: &syn::Type,
+    ) -> TokenStream2 {
+        let span = ty.span();
+        let static_offset = self.offset;
+        let bits = self.bits;
+        quote_spanned!(span=>
+            let _: ::modular_bitfield::private::checks::BitsCheck::<[(); #static_offset]> =
+                ::modular_bitfield::private::checks::BitsCheck::<[(); #static_offset]>{
+                    arr: [(); #offset]
+                };
+            let _: ::modular_bitfield::private::checks::BitsCheck::<[(); #bits]> =
+                ::modular_bitfield::private::checks::BitsCheck::<[(); #bits]>{
+                    arr: [(); <#ty as ::modular_bitfield::Specifier>::BITS]
+                };
         )
     }
 }
@@ -1,6 +1,13 @@
-use super::config::ConfigValue;
+use super::config::{
+    ConfigValue,
+    InlineKind,
+};
 use crate::errors::CombineError;
-use proc_macro2::Span;
+use proc_macro2::{
+    Span,
+    TokenStream as TokenStream2,
+};
+use quote::quote;
 
 #[derive(Default, Clone)]
 pub struct FieldConfig {
@@ -10,6 +17,110 @@ pub struct FieldConfig {
     pub bits: Option<ConfigValue<usize>>,
     /// An encountered `#[skip]` attribute on a field.
     pub skip: Option<ConfigValue<SkipWhich>>,
+    /// An encountered `#[inline(..)]` attribute on a field.
+    pub inline: Option<ConfigValue<InlineKind>>,
+    /// An encountered `#[access(..)]` attribute on a field.
+    pub access: Option<ConfigValue<AccessKind>>,
+    /// An encountered `#[validate(..)]` attribute on a field.
+    pub validate: Option<ConfigValue<Validator>>,
+    /// An encountered `#[on_overflow = ".."]` attribute on a field.
+    pub on_overflow: Option<ConfigValue<OverflowKind>>,
+    /// An encountered `#[overlaps(..)]` attribute on a field.
+    pub overlaps: Option<ConfigValue<syn::Ident>>,
+    /// An encountered `#[variant(..)]` attribute on a field.
+    pub variants: Option<ConfigValue<VariantsConfig>>,
+    /// An encountered `#[flatten(..)]` attribute on a field.
+    pub flatten: Option<ConfigValue<Vec<FlattenedField>>>,
+    /// An encountered `#[flags]` attribute on a field.
+    pub flags: Option<ConfigValue<()>>,
+    /// An encountered `#[group = ".."]` attribute on a field.
+    pub group: Option<ConfigValue<syn::Ident>>,
+}
+
+/// A field of a nested bitfield whose accessors are re-exported by `#[flatten(..)]`.
+#[derive(Clone)]
+pub struct FlattenedField {
+    /// The name of the field of the nested bitfield.
+    pub ident: syn::Ident,
+    /// The bit specifier of the field of the nested bitfield.
+    pub ty: syn::Type,
+}
+
+impl syn::parse::Parse for FlattenedField {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = input.parse()?;
+        input.parse::<syn::Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Self { ident, ty })
+    }
+}
+
+/// The sub-layouts of a field selected by the value of a tag field.
+#[derive(Clone)]
+pub struct VariantsConfig {
+    /// The name of the tag field.
+    pub tag: syn::LitStr,
+    /// The variants in declaration order.
+    pub variants: Vec<Variant>,
+}
+
+/// A single sub-layout of a `#[variant(..)]` field.
+#[derive(Clone)]
+pub struct Variant {
+    /// The value of the tag field selecting the variant.
+    pub tag_value: u128,
+    /// The bit specifier of the variant.
+    pub specifier: syn::Path,
+}
+
+/// The validation function of a field given by a `#[validate(..)]` attribute.
+#[derive(Clone)]
+pub struct Validator {
+    /// The path to the validation function.
+    pub function: syn::Path,
+    /// The error type returned by the validation function, defaulting to `()`.
+    pub error: Option<syn::Type>,
+}
+
+/// What the plain setters of a field do with out of bounds values.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum OverflowKind {
+    /// Panic on out of bounds values. This is the default.
+    Error,
+    /// Clamp out of bounds values to the maximum value of the field.
+    Saturate,
+    /// Drop the bits of out of bounds values that do not fit into the field.
+    Truncate,
+}
+
+/// The hardware access semantics of a field.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AccessKind {
+    /// The field can be read and written. This is the default.
+    ReadWrite,
+    /// The field can only be read.
+    ///
+    /// Skips code generation of setters.
+    ReadOnly,
+    /// The field can only be written.
+    ///
+    /// Skips code generation of getters.
+    WriteOnly,
+    /// The field can be read and writing ones to it clears its bits.
+    ///
+    /// Replaces the setters with a `clear_f` method writing all ones to the field.
+    WriteOneToClear,
+    /// The field can be read and writing ones to it sets its bits.
+    ///
+    /// Replaces the setters with a `set_f` method writing all ones to the field.
+    WriteOneToSet,
+}
+
+impl AccessKind {
+    /// Returns `true` if writing ones to the field triggers an action.
+    pub fn is_write_one(self) -> bool {
+        matches!(self, Self::WriteOneToClear | Self::WriteOneToSet)
+    }
 }
 
 /// Controls which parts of the code generation to skip.
@@ -77,6 +188,245 @@ impl FieldConfig {
         Ok(())
     }
 
+    /// Sets the `#[inline(always)]` or `#[inline(never)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered an `#[inline(..)]` attribute.
+    pub fn inline(&mut self, kind: InlineKind, span: Span) -> Result<(), syn::Error> {
+        match self.inline {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[inline(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[inline(..)]` here")))
+            }
+            None => self.inline = Some(ConfigValue { value: kind, span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[access(..)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered an `#[access(..)]` attribute.
+    pub fn access(&mut self, kind: AccessKind, span: Span) -> Result<(), syn::Error> {
+        match self.access {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[access(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[access(..)]` here")))
+            }
+            None => self.access = Some(ConfigValue { value: kind, span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[validate(path, error = "E")]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[validate(..)]` attribute.
+    pub fn validate(&mut self, validator: Validator, span: Span) -> Result<(), syn::Error> {
+        match self.validate {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[validate(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[validate(..)]` here")))
+            }
+            None => {
+                self.validate = Some(ConfigValue {
+                    value: validator,
+                    span,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the path to the validation function of the field if any.
+    pub fn validator(&self) -> Option<&syn::Path> {
+        self.validate.as_ref().map(|config| &config.value.function)
+    }
+
+    /// Returns the error type of the checked setters of the field.
+    ///
+    /// This is `ValidateError<E>` for fields with a `#[validate(..)]` attribute
+    /// whose validation function returns `E` and `OutOfBounds` otherwise.
+    pub fn checked_setter_error(&self) -> TokenStream2 {
+        match &self.validate {
+            Some(config) => {
+                let error = match &config.value.error {
+                    Some(error) => quote! { #error },
+                    None => quote! { () },
+                };
+                quote! { ::modular_bitfield::error::ValidateError<#error> }
+            }
+            None => quote! { ::modular_bitfield::error::OutOfBounds },
+        }
+    }
+
+    /// Sets the `#[on_overflow = ".."]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered an `#[on_overflow = ".."]` attribute.
+    pub fn on_overflow(&mut self, kind: OverflowKind, span: Span) -> Result<(), syn::Error> {
+        match self.on_overflow {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[on_overflow = \"..\"]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[on_overflow = \"..\"]` here")))
+            }
+            None => self.on_overflow = Some(ConfigValue { value: kind, span }),
+        }
+        Ok(())
+    }
+
+    /// Returns the overflow policy of the plain setters, defaulting to `OverflowKind::Error`.
+    pub fn overflow_kind(&self) -> OverflowKind {
+        self.on_overflow
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(OverflowKind::Error)
+    }
+
+    /// Sets the `#[overlaps(f)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered an `#[overlaps(..)]` attribute.
+    pub fn overlaps(&mut self, field: syn::Ident, span: Span) -> Result<(), syn::Error> {
+        match self.overlaps {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[overlaps(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[overlaps(..)]` here")))
+            }
+            None => self.overlaps = Some(ConfigValue { value: field, span }),
+        }
+        Ok(())
+    }
+
+    /// Returns the name of the field whose bits this field overlaps if any.
+    pub fn overlapped_field(&self) -> Option<&syn::Ident> {
+        self.overlaps.as_ref().map(|config| &config.value)
+    }
+
+    /// Sets the `#[variant(..)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[variant(..)]` attribute.
+    pub fn variants(&mut self, variants: VariantsConfig, span: Span) -> Result<(), syn::Error> {
+        match self.variants {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[variant(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[variant(..)]` here")))
+            }
+            None => {
+                self.variants = Some(ConfigValue {
+                    value: variants,
+                    span,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[flatten(..)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[flatten(..)]` attribute.
+    pub fn flatten(&mut self, fields: Vec<FlattenedField>, span: Span) -> Result<(), syn::Error> {
+        match self.flatten {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[flatten(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[flatten(..)]` here")))
+            }
+            None => {
+                self.flatten = Some(ConfigValue {
+                    value: fields,
+                    span,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Registers a `#[flags]` attribute found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[flags]` attribute.
+    pub fn flags(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.flags {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[flags]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[flags]` here")))
+            }
+            None => self.flags = Some(ConfigValue { value: (), span }),
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the field is part of the flags group of the bitfield.
+    pub fn is_flag(&self) -> bool {
+        self.flags.is_some()
+    }
+
+    /// Sets the `#[group = ".."]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[group = ".."]` attribute.
+    pub fn group(&mut self, name: syn::Ident, span: Span) -> Result<(), syn::Error> {
+        match self.group {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[group = \"..\"]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[group = \"..\"]` here")))
+            }
+            None => self.group = Some(ConfigValue { value: name, span }),
+        }
+        Ok(())
+    }
+
+    /// Returns the name of the group of the field if any.
+    pub fn group_name(&self) -> Option<&syn::Ident> {
+        self.group.as_ref().map(|config| &config.value)
+    }
+
+    /// Returns the access semantics of the field, defaulting to `AccessKind::ReadWrite`.
+    pub fn access_kind(&self) -> AccessKind {
+        self.access
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(AccessKind::ReadWrite)
+    }
+
     /// Sets the `#[skip(which)]` if found for a `#[bitfield]` annotated field.
     ///
     /// # Syntax
@@ -138,21 +488,50 @@ impl FieldConfig {
         Ok(())
     }
 
+    /// Returns `true` if the field is entirely skipped via `#[skip]` and thus reserved.
+    pub fn skip_all(&self) -> bool {
+        self.skip
+            .as_ref()
+            .map(|config| config.value == SkipWhich::All)
+            .unwrap_or(false)
+    }
+
     /// Returns `true` if the config demands that code generation for setters should be skipped.
+    ///
+    /// This is the case for skipped setters and fields that are not `#[access(read_write)]`
+    /// or `#[access(write_only)]`.
     pub fn skip_setters(&self) -> bool {
-        self.skip
+        let skipped = self
+            .skip
             .as_ref()
             .map(|config| config.value)
             .map(SkipWhich::skip_setters)
-            .unwrap_or(false)
+            .unwrap_or(false);
+        skipped || !matches!(self.access_kind(), AccessKind::ReadWrite | AccessKind::WriteOnly)
     }
 
     /// Returns `true` if the config demands that code generation for getters should be skipped.
+    ///
+    /// This is the case for skipped getters and `#[access(write_only)]` fields.
     pub fn skip_getters(&self) -> bool {
-        self.skip
+        let skipped = self
+            .skip
             .as_ref()
             .map(|config| config.value)
             .map(SkipWhich::skip_getters)
-            .unwrap_or(false)
+            .unwrap_or(false);
+        skipped || self.access_kind() == AccessKind::WriteOnly
+    }
+
+    /// Returns `true` if the write one accessors of `#[access(w1c)]` or `#[access(w1s)]`
+    /// fields should be generated.
+    pub fn write_one_accessors(&self) -> bool {
+        let skipped = self
+            .skip
+            .as_ref()
+            .map(|config| config.value)
+            .map(SkipWhich::skip_setters)
+            .unwrap_or(false);
+        !skipped && self.access_kind().is_write_one()
     }
 }
@@ -3,6 +3,16 @@ use super::{
     BitfieldStruct,
     Config,
 };
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+};
+use std::collections::HashMap;
+use syn::{
+    punctuated::Punctuated,
+    Token,
+};
 
 /// Compactly stores all shared and useful information about a single `#[bitfield]` field.
 pub struct FieldInfo<'a> {
@@ -32,6 +42,103 @@ impl<'a> FieldInfo<'a> {
         }
     }
 
+    /// Returns the identifier of the getter of this field.
+    ///
+    /// This is the field's identifier or `get_N` for unnamed fields.
+    pub fn get_ident(&self) -> syn::Ident {
+        self.field
+            .ident
+            .as_ref()
+            .cloned()
+            .unwrap_or_else(|| format_ident!("get_{}", self.ident_frag()))
+    }
+
+    /// Returns the identifier of the checked getter of this field.
+    ///
+    /// This is `f_or_err` for a field `f` or `get_N_or_err` for unnamed fields.
+    pub fn get_checked_ident(&self) -> syn::Ident {
+        self.field
+            .ident
+            .as_ref()
+            .map(|_| format_ident!("{}_or_err", self.ident_frag()))
+            .unwrap_or_else(|| format_ident!("get_{}_or_err", self.ident_frag()))
+    }
+
+    /// Returns the amount of bits of the field's type if it can be determined at expansion time.
+    ///
+    /// This is the case for the predefined `B1` to `B128` specifiers as well as
+    /// for `bool` and the unsigned primitive integers.
+    pub fn specifier_bits(&self) -> Option<usize> {
+        let path = match &self.field.ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+            _ => return None,
+        };
+        let segment = path.segments.last()?;
+        if !segment.arguments.is_empty() {
+            return None
+        }
+        match segment.ident.to_string().as_str() {
+            "bool" => Some(1),
+            "u8" => Some(8),
+            "u16" => Some(16),
+            "u32" => Some(32),
+            "u64" => Some(64),
+            "u128" => Some(128),
+            name => {
+                name.strip_prefix('B')
+                    .and_then(|bits| bits.parse::<usize>().ok())
+                    .filter(|bits| (1..=128).contains(bits))
+            }
+        }
+    }
+
+    /// Returns the amount of bits of the field if it can be determined at expansion time.
+    ///
+    /// Prefers the value of a `#[bits = N]` attribute over [`FieldInfo::specifier_bits`].
+    /// The amount is unknown for fields with `#[cfg(..)]` attributes.
+    pub fn known_bits(&self) -> Option<usize> {
+        if self.cfg_attrs().next().is_some() {
+            return None
+        }
+        self.config
+            .bits
+            .as_ref()
+            .map(|bits| bits.value)
+            .or_else(|| self.specifier_bits())
+    }
+
+    /// Returns the `#[cfg(..)]` attributes of the field.
+    pub fn cfg_attrs(&self) -> impl Iterator<Item = &syn::Attribute> {
+        self.config
+            .retained_attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("cfg"))
+    }
+
+    /// Returns an expression evaluating to the amount of bits occupied by the field.
+    ///
+    /// Evaluates to 0 if the field is disabled by its `#[cfg(..)]` attributes so that
+    /// the size of the bitfield and the offsets of later fields respect conditional compilation.
+    pub fn bits_expr(&self) -> TokenStream2 {
+        let ty = &self.field.ty;
+        let conditions = self
+            .cfg_attrs()
+            .map(|attr| {
+                let predicate = &attr.tokens;
+                quote! { ::core::cfg!#predicate }
+            })
+            .collect::<Vec<_>>();
+        match conditions.is_empty() {
+            true => quote! { <#ty as ::modular_bitfield::Specifier>::BITS },
+            false => {
+                quote! {
+                    (<#ty as ::modular_bitfield::Specifier>::BITS
+                        * ((true #( && #conditions )*) as ::core::primitive::usize))
+                }
+            }
+        }
+    }
+
     /// Returns the field's identifier as `String`.
     pub fn name(&self) -> String {
         Self::ident_as_string(self.field, self.index)
@@ -78,4 +185,60 @@ impl BitfieldStruct {
             FieldInfo::new(n, field, field_config)
         })
     }
+
+    /// Returns an iterator over the field infos paired with an expression
+    /// evaluating to the bit offset of the respective field.
+    pub fn field_infos_with_offsets<'a, 'b: 'a>(
+        &'a self,
+        config: &'b Config,
+    ) -> impl Iterator<Item = (FieldInfo<'a>, TokenStream2)> {
+        self.field_infos_with_known_offsets(config)
+            .map(|(info, offset, _)| (info, quote! { #offset }))
+    }
+
+    /// Returns the total amount of bits of the bitfield if it can be determined at expansion time.
+    ///
+    /// Prefers the value of the `bits = N` parameter over the sum of the known bits of all fields.
+    pub fn known_bitfield_size(&self, config: &Config) -> Option<usize> {
+        if let Some(bits) = &config.bits {
+            return Some(bits.value)
+        }
+        self.field_infos(config)
+            .filter(|info| info.config.overlapped_field().is_none())
+            .map(|info| info.known_bits())
+            .sum()
+    }
+
+    /// Returns an iterator over the field infos paired with an expression evaluating
+    /// to the bit offset of the respective field and the offset itself if it can be
+    /// determined at expansion time.
+    ///
+    /// Fields with an `#[overlaps(f)]` attribute start at the offset of field `f`
+    /// and do not occupy any bits of their own.
+    pub fn field_infos_with_known_offsets<'a, 'b: 'a>(
+        &'a self,
+        config: &'b Config,
+    ) -> impl Iterator<Item = (FieldInfo<'a>, Punctuated<syn::Expr, Token![+]>, Option<usize>)>
+    {
+        let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
+        offset.push(syn::parse_quote! { 0usize });
+        let mut known_offset = Some(0);
+        let mut offsets = HashMap::new();
+        self.field_infos(config).map(move |info| {
+            if let Some(target) = info.config.overlapped_field() {
+                // The analysis guarantees that the target is a previously declared field.
+                let (target_offset, target_known_offset) = offsets
+                    .get(&target.to_string())
+                    .cloned()
+                    .unwrap_or_else(|| (offset.clone(), None));
+                return (info, target_offset, target_known_offset)
+            }
+            let current = (offset.clone(), known_offset);
+            offsets.insert(info.name(), current.clone());
+            let bits = info.bits_expr();
+            offset.push(syn::parse_quote! { #bits });
+            known_offset = known_offset.and_then(|offset| Some(offset + info.known_bits()?));
+            (info, current.0, current.1)
+        })
+    }
 }
@@ -4,10 +4,12 @@ mod expand;
 mod field_config;
 mod field_info;
 mod params;
+mod union;
 
 use self::{
     config::Config,
     params::ParamArgs,
+    union::BitfieldUnion,
 };
 use core::convert::TryFrom;
 use proc_macro2::TokenStream as TokenStream2;
@@ -33,8 +35,19 @@ fn analyse_and_expand_or_error(
     args: TokenStream2,
     input: TokenStream2,
 ) -> Result<TokenStream2> {
-    let input = syn::parse::<syn::ItemStruct>(input.into())?;
     let params = syn::parse::<ParamArgs>(args.into())?;
+    let input = match syn::parse::<syn::Item>(input.into())? {
+        syn::Item::Struct(item_struct) => item_struct,
+        syn::Item::Union(item_union) => {
+            return Ok(BitfieldUnion::try_from(params, item_union)?.expand())
+        }
+        item => {
+            return Err(format_err_spanned!(
+                item,
+                "encountered invalid #[bitfield] item: expected a struct or union"
+            ))
+        }
+    };
     let mut config = Config::default();
     config.feed_params(params)?;
     let bitfield = BitfieldStruct::try_from((&mut config, input))?;
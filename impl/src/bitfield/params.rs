@@ -1,4 +1,11 @@
-use super::config::Config;
+use super::config::{
+    AccessorsKind,
+    BytePermKind,
+    CellKind,
+    Config,
+    DebugKind,
+    InlineKind,
+};
 use proc_macro2::Span;
 use syn::{
     parse::Result,
@@ -85,6 +92,31 @@ impl Config {
         Ok(())
     }
 
+    /// Feeds a parameter that takes a string value to the `#[bitfield]` configuration.
+    fn feed_str_param<F>(
+        name_value: syn::MetaNameValue,
+        name: &str,
+        on_success: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(syn::LitStr, Span) -> Result<()>,
+    {
+        assert!(name_value.path.is_ident(name));
+        match &name_value.lit {
+            syn::Lit::Str(lit_str) => {
+                on_success(lit_str.clone(), name_value.span())?;
+            }
+            invalid => {
+                return Err(format_err!(
+                    invalid,
+                    "encountered invalid value argument for #[bitfield] `{}` parameter",
+                    name
+                ))
+            }
+        }
+        Ok(())
+    }
+
     /// Feeds a `bytes: int` parameter to the `#[bitfield]` configuration.
     fn feed_bytes_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
         Self::feed_int_param(name_value, "bytes", |value, span| self.bytes(value, span))
@@ -95,23 +127,475 @@ impl Config {
         Self::feed_int_param(name_value, "bits", |value, span| self.bits(value, span))
     }
 
-    /// Feeds a `filled: bool` parameter to the `#[bitfield]` configuration.
-    fn feed_filled_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
-        assert!(name_value.path.is_ident("filled"));
+    /// Feeds a parameter that takes a boolean value to the `#[bitfield]` configuration.
+    fn feed_bool_param<F>(
+        name_value: syn::MetaNameValue,
+        name: &str,
+        on_success: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(bool, Span) -> Result<()>,
+    {
+        assert!(name_value.path.is_ident(name));
         match &name_value.lit {
             syn::Lit::Bool(lit_bool) => {
-                self.filled(lit_bool.value, name_value.span())?;
+                on_success(lit_bool.value, name_value.span())?;
             }
             invalid => {
                 return Err(format_err!(
-                invalid,
-                "encountered invalid value argument for #[bitfield] `filled` parameter",
-            ))
+                    invalid,
+                    "encountered invalid value argument for #[bitfield] `{}` parameter",
+                    name
+                ))
             }
         }
         Ok(())
     }
 
+    /// Feeds a `filled: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_filled_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "filled", |value, span| {
+            self.filled(value, span)
+        })
+    }
+
+    /// Feeds an `as_ref: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_as_ref_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "as_ref", |value, span| {
+            self.as_ref(value, span)
+        })
+    }
+
+    /// Feeds an `unaligned: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_unaligned_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "unaligned", |value, span| {
+            self.unaligned(value, span)
+        })
+    }
+
+    /// Feeds a `field_errors: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_field_errors_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "field_errors", |value, span| {
+            self.field_errors(value, span)
+        })
+    }
+
+    /// Feeds a `text: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_text_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "text", |value, span| self.text(value, span))
+    }
+
+    /// Feeds a `gen_tests: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_gen_tests_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "gen_tests", |value, span| {
+            self.gen_tests(value, span)
+        })
+    }
+
+    /// Feeds a `bit_ops: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_bit_ops_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "bit_ops", |value, span| {
+            self.bit_ops(value, span)
+        })
+    }
+
+    /// Feeds a `bytes_vis: str` parameter to the `#[bitfield]` configuration.
+    fn feed_bytes_vis_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "bytes_vis", |lit_str, span| {
+            let vis = lit_str.parse::<syn::Visibility>().map_err(|err| {
+                format_err!(
+                    lit_str,
+                    "encountered malformatted visibility for `bytes_vis` parameter: {}",
+                    err
+                )
+            })?;
+            self.bytes_vis(vis, span)
+        })
+    }
+
+    /// Feeds a `bytes_field: str` parameter to the `#[bitfield]` configuration.
+    fn feed_bytes_field_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "bytes_field", |lit_str, span| {
+            let ident = lit_str.parse::<syn::Ident>().map_err(|err| {
+                format_err!(
+                    lit_str,
+                    "encountered malformatted field name for `bytes_field` parameter: {}",
+                    err
+                )
+            })?;
+            self.bytes_field(ident, span)
+        })
+    }
+
+    /// Feeds a `bytes_field_vis: str` parameter to the `#[bitfield]` configuration.
+    fn feed_bytes_field_vis_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "bytes_field_vis", |lit_str, span| {
+            let vis = lit_str.parse::<syn::Visibility>().map_err(|err| {
+                format_err!(
+                    lit_str,
+                    "encountered malformatted visibility for `bytes_field_vis` parameter: {}",
+                    err
+                )
+            })?;
+            self.bytes_field_vis(vis, span)
+        })
+    }
+
+    /// Parses the little-endian bytes of a reset value parameter such as `default`.
+    ///
+    /// The integer is the reset value of the whole bitfield while the string is
+    /// an array of the reset value bytes, e.g. `"[0x21, 0x84]"`.
+    fn parse_reset_value_param(name_value: &syn::MetaNameValue, name: &str) -> Result<Vec<u8>> {
+        let bytes = match &name_value.lit {
+            syn::Lit::Int(lit_int) => {
+                let value = lit_int.base10_parse::<u128>().map_err(|err| {
+                    format_err!(
+                        lit_int,
+                        "encountered malformatted integer value for `{}` parameter: {}",
+                        name,
+                        err
+                    )
+                })?;
+                let mut bytes = value.to_le_bytes().to_vec();
+                while bytes.last() == Some(&0) {
+                    bytes.pop();
+                }
+                bytes
+            }
+            syn::Lit::Str(lit_str) => {
+                let array = lit_str.parse::<syn::ExprArray>().map_err(|err| {
+                    format_err!(
+                        lit_str,
+                        "encountered malformatted byte array for `{}` parameter: {}",
+                        name,
+                        err
+                    )
+                })?;
+                array
+                    .elems
+                    .iter()
+                    .map(|elem| {
+                        match elem {
+                            syn::Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Int(lit_int),
+                                ..
+                            }) => lit_int.base10_parse::<u8>(),
+                            invalid => Err(format_err!(invalid, "expected a byte literal")),
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+            invalid => {
+                return Err(format_err!(
+                    invalid,
+                    "encountered invalid value argument for #[bitfield] `{}` parameter",
+                    name
+                ))
+            }
+        };
+        Ok(bytes)
+    }
+
+    /// Feeds a `default: int` or `default: str` parameter to the `#[bitfield]` configuration.
+    fn feed_default_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        let bytes = Self::parse_reset_value_param(&name_value, "default")?;
+        self.default_value(bytes, name_value.span())
+    }
+
+    /// Feeds a `reset: int` or `reset: str` parameter to the `#[bitfield]` configuration.
+    fn feed_reset_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        let bytes = Self::parse_reset_value_param(&name_value, "reset")?;
+        self.reset(bytes, name_value.span())
+    }
+
+    /// Feeds an `accessor_trait: str` parameter to the `#[bitfield]` configuration.
+    fn feed_accessor_trait_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "accessor_trait", |lit_str, span| {
+            let ident = lit_str.parse::<syn::Ident>().map_err(|err| {
+                format_err!(
+                    lit_str,
+                    "encountered malformatted trait name for `accessor_trait` parameter: {}",
+                    err
+                )
+            })?;
+            self.accessor_trait(ident, span)
+        })
+    }
+
+    /// Feeds a `migrate_from: str` parameter to the `#[bitfield]` configuration.
+    fn feed_migrate_from_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "migrate_from", |lit_str, span| {
+            let types = lit_str
+                .parse_with(
+                    syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                )
+                .map_err(|err| {
+                    format_err!(
+                        lit_str,
+                        "encountered malformatted bitfield types for `migrate_from` parameter: {}",
+                        err
+                    )
+                })?;
+            if types.is_empty() {
+                return Err(format_err!(
+                    lit_str,
+                    "expected at least one bitfield type for `migrate_from` parameter"
+                ))
+            }
+            self.migrate_from(types.into_iter().collect(), span)
+        })
+    }
+
+    /// Feeds an `impl_trait: str` parameter to the `#[bitfield]` configuration.
+    fn feed_impl_trait_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "impl_trait", |lit_str, span| {
+            let traits = lit_str
+                .parse_with(
+                    syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                )
+                .map_err(|err| {
+                    format_err!(
+                        lit_str,
+                        "encountered malformatted trait paths for `impl_trait` parameter: {}",
+                        err
+                    )
+                })?;
+            if traits.is_empty() {
+                return Err(format_err!(
+                    lit_str,
+                    "expected at least one trait path for `impl_trait` parameter"
+                ))
+            }
+            self.impl_trait(traits.into_iter().collect(), span)
+        })
+    }
+
+    /// Feeds an `ord_by: str` parameter to the `#[bitfield]` configuration.
+    fn feed_ord_by_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "ord_by", |lit_str, span| {
+            let fields = lit_str
+                .parse_with(
+                    syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated,
+                )
+                .map_err(|err| {
+                    format_err!(
+                        lit_str,
+                        "encountered malformatted field names for `ord_by` parameter: {}",
+                        err
+                    )
+                })?;
+            if fields.is_empty() {
+                return Err(format_err!(
+                    lit_str,
+                    "expected at least one field name for `ord_by` parameter"
+                ))
+            }
+            let fields = fields
+                .into_iter()
+                .map(|field| syn::Ident::new(&field.to_string(), lit_str.span()))
+                .collect();
+            self.ord_by(fields, span)
+        })
+    }
+
+    /// Feeds a `byte_perm: str` parameter to the `#[bitfield]` configuration.
+    fn feed_byte_perm_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "byte_perm", |lit_str, span| {
+            let kind = match lit_str.value().as_str() {
+                "swap16" => BytePermKind::Swap16,
+                "swap32" => BytePermKind::Swap32,
+                _ => {
+                    return Err(format_err!(
+                        lit_str,
+                        "encountered invalid value for `byte_perm` parameter: expected \"swap16\" or \"swap32\""
+                    ))
+                }
+            };
+            self.byte_perm(kind, span)
+        })
+    }
+
+    /// Feeds an `inline: str` parameter to the `#[bitfield]` configuration.
+    fn feed_inline_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "inline", |lit_str, span| {
+            let kind = match lit_str.value().as_str() {
+                "always" => InlineKind::Always,
+                "never" => InlineKind::Never,
+                _ => {
+                    return Err(format_err!(
+                        lit_str,
+                        "encountered invalid value for `inline` parameter: expected \"always\" or \"never\""
+                    ))
+                }
+            };
+            self.inline(kind, span)
+        })
+    }
+
+    /// Feeds a `min_code_size: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_min_code_size_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "min_code_size", |value, span| {
+            self.min_code_size(value, span)
+        })
+    }
+
+    /// Feeds an `accessors: str` parameter to the `#[bitfield]` configuration.
+    fn feed_accessors_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "accessors", |lit_str, span| {
+            let kind = match lit_str.value().as_str() {
+                "inline" => AccessorsKind::Inline,
+                "outline" => AccessorsKind::Outline,
+                _ => {
+                    return Err(format_err!(
+                        lit_str,
+                        "encountered invalid value for `accessors` parameter: expected \"inline\" or \"outline\""
+                    ))
+                }
+            };
+            self.accessors(kind, span)
+        })
+    }
+
+    /// Feeds a `debug: str` parameter to the `#[bitfield]` configuration.
+    fn feed_debug_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "debug", |lit_str, span| {
+            let kind = match lit_str.value().as_str() {
+                "fields" => DebugKind::Fields,
+                "raw" => DebugKind::Raw,
+                "verbose" => DebugKind::Verbose,
+                _ => {
+                    return Err(format_err!(
+                        lit_str,
+                        "encountered invalid value for `debug` parameter: expected \"fields\", \"raw\" or \"verbose\""
+                    ))
+                }
+            };
+            self.debug(kind, span)
+        })
+    }
+
+    /// Feeds a `views: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_views_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "views", |value, span| {
+            self.views(value, span)
+        })
+    }
+
+    /// Feeds a `ptr_view: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_ptr_view_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "ptr_view", |value, span| {
+            self.ptr_view(value, span)
+        })
+    }
+
+    /// Feeds an `atomic: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_atomic_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "atomic", |value, span| {
+            self.atomic(value, span)
+        })
+    }
+
+    /// Feeds an `export_c: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_export_c_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "export_c", |value, span| {
+            self.export_c(value, span)
+        })
+    }
+
+    /// Feeds an `export_c_prefix: str` parameter to the `#[bitfield]` configuration.
+    fn feed_export_c_prefix_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "export_c_prefix", |lit_str, span| {
+            let ident = lit_str.parse::<syn::Ident>().map_err(|err| {
+                format_err!(
+                    lit_str,
+                    "encountered malformatted symbol prefix for `export_c_prefix` parameter: {}",
+                    err
+                )
+            })?;
+            self.export_c_prefix(ident, span)
+        })
+    }
+
+    /// Feeds a `reader_writer: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_reader_writer_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "reader_writer", |value, span| {
+            self.reader_writer(value, span)
+        })
+    }
+
+    /// Feeds a `no_panic: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_no_panic_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "no_panic", |value, span| {
+            self.no_panic(value, span)
+        })
+    }
+
+    /// Feeds a `validated: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_validated_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "validated", |value, span| {
+            self.validated(value, span)
+        })
+    }
+
+    /// Feeds a `check_reserved: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_check_reserved_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "check_reserved", |value, span| {
+            self.check_reserved(value, span)
+        })
+    }
+
+    /// Feeds a `reflect: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_reflect_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "reflect", |value, span| self.reflect(value, span))
+    }
+
+    /// Feeds a `layout_docs: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_layout_docs_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "layout_docs", |value, span| {
+            self.layout_docs(value, span)
+        })
+    }
+
+    /// Feeds a `cell: str` parameter to the `#[bitfield]` configuration.
+    fn feed_cell_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "cell", |lit_str, span| {
+            let kind = match lit_str.value().as_str() {
+                "volatile" => CellKind::Volatile,
+                _ => {
+                    return Err(format_err!(
+                        lit_str,
+                        "encountered invalid value for `cell` parameter: expected \"volatile\""
+                    ))
+                }
+            };
+            self.cell(kind, span)
+        })
+    }
+
+    /// Feeds a `reinterpret: str` parameter to the `#[bitfield]` configuration.
+    fn feed_reinterpret_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "reinterpret", |lit_str, span| {
+            let types = lit_str
+                .parse_with(
+                    syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                )
+                .map_err(|err| {
+                    format_err!(
+                        lit_str,
+                        "encountered malformatted bitfield types for `reinterpret` parameter: {}",
+                        err
+                    )
+                })?;
+            if types.is_empty() {
+                return Err(format_err!(
+                    lit_str,
+                    "expected at least one bitfield type for `reinterpret` parameter"
+                ))
+            }
+            self.reinterpret(types.into_iter().collect(), span)
+        })
+    }
+
     /// Feeds the given parameters to the `#[bitfield]` configuration.
     ///
     /// # Errors
@@ -132,10 +616,92 @@ impl Config {
                                 self.feed_bits_param(name_value)?;
                             } else if name_value.path.is_ident("filled") {
                                 self.feed_filled_param(name_value)?;
+                            } else if name_value.path.is_ident("bytes_vis") {
+                                self.feed_bytes_vis_param(name_value)?;
+                            } else if name_value.path.is_ident("bytes_field") {
+                                self.feed_bytes_field_param(name_value)?;
+                            } else if name_value.path.is_ident("bytes_field_vis") {
+                                self.feed_bytes_field_vis_param(name_value)?;
+                            } else if name_value.path.is_ident("default") {
+                                self.feed_default_param(name_value)?;
+                            } else if name_value.path.is_ident("reset") {
+                                self.feed_reset_param(name_value)?;
+                            } else if name_value.path.is_ident("accessor_trait") {
+                                self.feed_accessor_trait_param(name_value)?;
+                            } else if name_value.path.is_ident("bit_ops") {
+                                self.feed_bit_ops_param(name_value)?;
+                            } else if name_value.path.is_ident("as_ref") {
+                                self.feed_as_ref_param(name_value)?;
+                            } else if name_value.path.is_ident("gen_tests") {
+                                self.feed_gen_tests_param(name_value)?;
+                            } else if name_value.path.is_ident("text") {
+                                self.feed_text_param(name_value)?;
+                            } else if name_value.path.is_ident("field_errors") {
+                                self.feed_field_errors_param(name_value)?;
+                            } else if name_value.path.is_ident("unaligned") {
+                                self.feed_unaligned_param(name_value)?;
+                            } else if name_value.path.is_ident("views") {
+                                self.feed_views_param(name_value)?;
+                            } else if name_value.path.is_ident("ptr_view") {
+                                self.feed_ptr_view_param(name_value)?;
+                            } else if name_value.path.is_ident("inline") {
+                                self.feed_inline_param(name_value)?;
+                            } else if name_value.path.is_ident("min_code_size") {
+                                self.feed_min_code_size_param(name_value)?;
+                            } else if name_value.path.is_ident("accessors") {
+                                self.feed_accessors_param(name_value)?;
+                            } else if name_value.path.is_ident("debug") {
+                                self.feed_debug_param(name_value)?;
+                            } else if name_value.path.is_ident("atomic") {
+                                self.feed_atomic_param(name_value)?;
+                            } else if name_value.path.is_ident("export_c") {
+                                self.feed_export_c_param(name_value)?;
+                            } else if name_value.path.is_ident("export_c_prefix") {
+                                self.feed_export_c_prefix_param(name_value)?;
+                            } else if name_value.path.is_ident("reader_writer") {
+                                self.feed_reader_writer_param(name_value)?;
+                            } else if name_value.path.is_ident("cell") {
+                                self.feed_cell_param(name_value)?;
+                            } else if name_value.path.is_ident("no_panic") {
+                                self.feed_no_panic_param(name_value)?;
+                            } else if name_value.path.is_ident("validated") {
+                                self.feed_validated_param(name_value)?;
+                            } else if name_value.path.is_ident("check_reserved") {
+                                self.feed_check_reserved_param(name_value)?;
+                            } else if name_value.path.is_ident("reflect") {
+                                self.feed_reflect_param(name_value)?;
+                            } else if name_value.path.is_ident("byte_perm") {
+                                self.feed_byte_perm_param(name_value)?;
+                            } else if name_value.path.is_ident("migrate_from") {
+                                self.feed_migrate_from_param(name_value)?;
+                            } else if name_value.path.is_ident("impl_trait") {
+                                self.feed_impl_trait_param(name_value)?;
+                            } else if name_value.path.is_ident("ord_by") {
+                                self.feed_ord_by_param(name_value)?;
+                            } else if name_value.path.is_ident("layout_docs") {
+                                self.feed_layout_docs_param(name_value)?;
+                            } else if name_value.path.is_ident("reinterpret") {
+                                self.feed_reinterpret_param(name_value)?;
                             } else {
                                 return Err(unsupported_argument(name_value))
                             }
                         }
+                        syn::Meta::Path(path) if path.is_ident("atomic") => {
+                            // `atomic` is a shorthand for `atomic = true`.
+                            self.atomic(true, path.span())?;
+                        }
+                        syn::Meta::Path(path) if path.is_ident("export_c") => {
+                            // `export_c` is a shorthand for `export_c = true`.
+                            self.export_c(true, path.span())?;
+                        }
+                        syn::Meta::Path(path) if path.is_ident("no_panic") => {
+                            // `no_panic` is a shorthand for `no_panic = true`.
+                            self.no_panic(true, path.span())?;
+                        }
+                        syn::Meta::Path(path) if path.is_ident("gen_tests") => {
+                            // `gen_tests` is a shorthand for `gen_tests = true`.
+                            self.gen_tests(true, path.span())?;
+                        }
                         unsupported => return Err(unsupported_argument(unsupported)),
                     }
                 }
@@ -0,0 +1,163 @@
+use super::params::ParamArgs;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote_spanned,
+};
+use syn::{
+    parse::Result,
+    spanned::Spanned as _,
+};
+
+/// Type used to guide analysis and expansion of `#[bitfield]` unions.
+///
+/// Every member of the union is a `#[bitfield]` type describing an alternate
+/// layout of the same bytes.
+pub struct BitfieldUnion {
+    /// The input `union` item.
+    item_union: syn::ItemUnion,
+}
+
+impl BitfieldUnion {
+    /// Analyses the given `#[bitfield]` union and its parameters.
+    ///
+    /// # Errors
+    ///
+    /// - If parameters have been given to the `#[bitfield]` macro.
+    /// - If the union is generic.
+    pub fn try_from(params: ParamArgs, item_union: syn::ItemUnion) -> Result<Self> {
+        if let Some(param) = params.into_iter().next() {
+            return Err(format_err!(
+                param,
+                "encountered unsupported #[bitfield] parameter for union"
+            ))
+        }
+        if !item_union.generics.params.is_empty() {
+            return Err(format_err_spanned!(
+                item_union,
+                "encountered invalid generic bitfield union"
+            ))
+        }
+        Ok(Self { item_union })
+    }
+
+    /// Expands the `#[bitfield]` union into a struct storing the bytes shared by all
+    /// members together with accessors viewing the bytes as each of the members.
+    pub fn expand(&self) -> TokenStream2 {
+        let span = self.item_union.span();
+        let attrs = &self.item_union.attrs;
+        let vis = &self.item_union.vis;
+        let ident = &self.item_union.ident;
+        let fields = &self.item_union.fields.named;
+        let first_ty = &fields
+            .first()
+            .expect("unions always have at least one field")
+            .ty;
+        let size = quote_spanned!(span=> ::core::mem::size_of::<#first_ty>());
+        let size_checks = fields.iter().map(|field| {
+            let ty = &field.ty;
+            quote_spanned!(ty.span()=>
+                ::modular_bitfield::private::static_assertions::assert_eq_size!(
+                    #ty,
+                    [::core::primitive::u8; #size]
+                );
+            )
+        });
+        let accessors = fields.iter().map(|field| {
+            let span = field.span();
+            let vis = &field.vis;
+            let ty = &field.ty;
+            let name = field.ident.as_ref().expect("union fields are always named");
+            let name_str = name.to_string();
+            let name_str = name_str.trim_start_matches("r#");
+            let get_ident = name;
+            let get_checked_ident = format_ident!("{}_or_err", name_str);
+            let set_ident = format_ident!("set_{}", name_str);
+            let with_ident = format_ident!("with_{}", name_str);
+            let getter_docs = format!("Returns the bytes of the union viewed as its `{}` member.", name_str);
+            let checked_getter_docs = format!(
+                "Returns the bytes of the union viewed as its `{}` member.\n\n\
+                 # Errors\n\n\
+                 If the bytes contain bits at positions that are undefined for the member.",
+                name_str,
+            );
+            let setter_docs = format!("Overwrites all bytes of the union with the given `{}` member.", name_str);
+            let get_assert_msg = format!("value contains invalid bit pattern for union member {}.{}", ident, name_str);
+            quote_spanned!(span=>
+                #[doc = #getter_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #get_ident(&self) -> #ty {
+                    self.#get_checked_ident().expect(#get_assert_msg)
+                }
+
+                #[doc = #checked_getter_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #get_checked_ident(&self) -> ::core::result::Result<#ty, ::modular_bitfield::error::OutOfBounds> {
+                    let mut __bf_bytes = <#ty as ::modular_bitfield::Bitfield>::zeroed_bytes();
+                    ::core::convert::AsMut::<[::core::primitive::u8]>::as_mut(&mut __bf_bytes).copy_from_slice(&self.bytes[..]);
+                    <#ty as ::modular_bitfield::Bitfield>::from_bytes(__bf_bytes)
+                }
+
+                #[doc = #setter_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #set_ident(&mut self, new_val: #ty) {
+                    self.bytes.copy_from_slice(::core::convert::AsRef::<[::core::primitive::u8]>::as_ref(
+                        <#ty as ::modular_bitfield::Bitfield>::as_bytes(&new_val),
+                    ));
+                }
+
+                #[doc = #setter_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #with_ident(mut self, new_val: #ty) -> Self {
+                    self.#set_ident(new_val);
+                    self
+                }
+            )
+        });
+        quote_spanned!(span=>
+            #( #attrs )*
+            #vis struct #ident {
+                bytes: [::core::primitive::u8; #size],
+            }
+
+            const _: () = {
+                #( #size_checks )*
+            };
+
+            impl #ident {
+                /// Returns an instance with zero initialized data.
+                #[allow(dead_code)]
+                pub const fn new() -> Self {
+                    Self { bytes: [0u8; #size] }
+                }
+
+                /// Returns an instance from the given bytes shared by all members.
+                #[inline]
+                #[allow(dead_code)]
+                pub const fn from_bytes(bytes: [::core::primitive::u8; #size]) -> Self {
+                    Self { bytes }
+                }
+
+                /// Returns the bytes shared by all members.
+                #[inline]
+                #[allow(dead_code)]
+                pub const fn into_bytes(self) -> [::core::primitive::u8; #size] {
+                    self.bytes
+                }
+
+                /// Returns a reference to the bytes shared by all members.
+                #[inline]
+                #[allow(dead_code)]
+                pub const fn as_bytes(&self) -> &[::core::primitive::u8; #size] {
+                    &self.bytes
+                }
+
+                #( #accessors )*
+            }
+        )
+    }
+}
@@ -0,0 +1,210 @@
+use proc_macro2::{
+    Literal,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    punctuated::Punctuated,
+};
+
+/// Analyses the given `bitfield_layout!` input and expands it into a block expression
+/// evaluating to the constructor of an anonymous layout type.
+pub fn analyse_and_expand(input: TokenStream2) -> TokenStream2 {
+    match analyse_and_expand_or_error(input) {
+        Ok(output) => output,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+/// The `u32 => { a: 6, b: 26 }` input of the `bitfield_layout!` macro.
+struct Layout {
+    ty: syn::Ident,
+    fields: Punctuated<LayoutField, syn::Token![,]>,
+}
+
+/// A single `name: bits` field of the `bitfield_layout!` macro.
+struct LayoutField {
+    ident: syn::Ident,
+    bits: syn::LitInt,
+}
+
+impl Parse for Layout {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse::<syn::Ident>()?;
+        input.parse::<syn::Token![=>]>()?;
+        let content;
+        syn::braced!(content in input);
+        let fields = content.parse_terminated(LayoutField::parse)?;
+        Ok(Self { ty, fields })
+    }
+}
+
+impl Parse for LayoutField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<syn::Ident>()?;
+        input.parse::<syn::Token![:]>()?;
+        let bits = input.parse::<syn::LitInt>()?;
+        Ok(Self { ident, bits })
+    }
+}
+
+/// Returns the smallest unsigned integer type with at least the given number of bits.
+fn unsigned_type_for(bits: usize) -> syn::Ident {
+    let ty = match bits {
+        0..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        _ => "u128",
+    };
+    format_ident!("{}", ty)
+}
+
+fn analyse_and_expand_or_error(input: TokenStream2) -> syn::Result<TokenStream2> {
+    let layout = syn::parse2::<Layout>(input)?;
+    let ty = &layout.ty;
+    let ty_bits = match ty.to_string().as_str() {
+        "u8" => 8,
+        "u16" => 16,
+        "u32" => 32,
+        "u64" => 64,
+        "u128" => 128,
+        _ => {
+            return Err(format_err_spanned!(
+                ty,
+                "expected one of `u8`, `u16`, `u32`, `u64` or `u128` for bitfield_layout!"
+            ))
+        }
+    };
+    let widths = layout
+        .fields
+        .iter()
+        .map(|field| field.bits.base10_parse::<usize>())
+        .collect::<syn::Result<Vec<_>>>()?;
+    let total_bits = widths.iter().sum::<usize>();
+    let mut offset = 0usize;
+    let mut accessors = Vec::new();
+    let mut debug_fields = Vec::new();
+    for (field, &bits) in layout.fields.iter().zip(&widths) {
+        if bits == 0 {
+            return Err(format_err_spanned!(
+                field.bits,
+                "encountered field with zero bits in bitfield_layout!"
+            ))
+        }
+        if offset + bits > ty_bits {
+            return Err(format_err_spanned!(
+                field.bits,
+                "encountered fields with {} bits in total which exceed the {} bits of `{}`",
+                total_bits,
+                ty_bits,
+                ty,
+            ))
+        }
+        let span = field.ident.span();
+        let ident = &field.ident;
+        let name = ident.to_string();
+        let getter_docs = format!("Returns the value of {}.", name);
+        let setter_docs = format!(
+            "Sets the value of {} to the given value.\n\n\
+             # Panics\n\n\
+             If the given value is out of bounds for {}.",
+            name, name,
+        );
+        let with_docs = format!(
+            "Returns a copy of the bitfield with the value of {} set to the given value.\n\n\
+             # Panics\n\n\
+             If the given value is out of bounds for {}.",
+            name, name,
+        );
+        let setter_ident = format_ident!("set_{}", ident);
+        let with_ident = format_ident!("with_{}", ident);
+        let out_ty = unsigned_type_for(bits);
+        let mask = match bits {
+            128 => Literal::u128_unsuffixed(u128::MAX),
+            bits => Literal::u128_unsuffixed((1u128 << bits) - 1),
+        };
+        let offset_lit = Literal::usize_unsuffixed(offset);
+        let bounds_check = match bits < out_ty_bits(bits) {
+            true => {
+                let message = format!("value out of bounds for field {}", name);
+                Some(quote! { assert!((new_val as #ty) <= #mask, #message); })
+            }
+            false => None,
+        };
+        accessors.push(quote_spanned!(span=>
+            #[doc = #getter_docs]
+            #[inline]
+            #[allow(dead_code, clippy::identity_op)]
+            const fn #ident(&self) -> #out_ty {
+                ((self.bits >> #offset_lit) & #mask) as #out_ty
+            }
+
+            #[doc = #setter_docs]
+            #[inline]
+            #[allow(dead_code, clippy::identity_op)]
+            fn #setter_ident(&mut self, new_val: #out_ty) {
+                #bounds_check
+                self.bits = (self.bits & !(#mask << #offset_lit)) | ((new_val as #ty) << #offset_lit);
+            }
+
+            #[doc = #with_docs]
+            #[inline]
+            #[allow(dead_code)]
+            fn #with_ident(mut self, new_val: #out_ty) -> Self {
+                self.#setter_ident(new_val);
+                self
+            }
+        ));
+        debug_fields.push(quote! { .field(#name, &self.#ident()) });
+        offset += bits;
+    }
+    let span = layout.ty.span();
+    Ok(quote_spanned!(span=> {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        struct BitfieldLayout {
+            bits: #ty,
+        }
+
+        impl BitfieldLayout {
+            /// Creates the layout over the given value.
+            #[inline]
+            #[allow(dead_code)]
+            const fn from_bits(bits: #ty) -> Self {
+                Self { bits }
+            }
+
+            /// Returns the underlying value of the layout.
+            #[inline]
+            #[allow(dead_code)]
+            const fn into_bits(self) -> #ty {
+                self.bits
+            }
+
+            #( #accessors )*
+        }
+
+        impl ::core::fmt::Debug for BitfieldLayout {
+            fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                __bf_f.debug_struct("BitfieldLayout")
+                    #( #debug_fields )*
+                    .finish()
+            }
+        }
+
+        BitfieldLayout::from_bits
+    }))
+}
+
+/// Returns the number of bits of the type returned by `unsigned_type_for`.
+fn out_ty_bits(bits: usize) -> usize {
+    bits.next_power_of_two().max(8)
+}
@@ -1,6 +1,15 @@
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote_spanned;
-use syn::spanned::Spanned as _;
+use proc_macro2::{
+    Span,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote_spanned,
+};
+use syn::{
+    ext::IdentExt as _,
+    spanned::Spanned as _,
+};
 
 pub fn generate(input: TokenStream2) -> TokenStream2 {
     match generate_or_error(input) {
@@ -39,6 +48,7 @@ fn generate_or_error(input: TokenStream2) -> syn::Result<TokenStream2> {
 }
 struct Attributes {
     bits: Option<usize>,
+    bits_span: Option<Span>,
 }
 
 fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
@@ -46,7 +56,10 @@ fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
         .iter()
         .filter(|attr| attr.path.is_ident("bits"))
         .fold(
-            Ok(Attributes { bits: None }),
+            Ok(Attributes {
+                bits: None,
+                bits_span: None,
+            }),
             |acc: syn::Result<Attributes>, attr| {
                 let mut acc = acc?;
                 if acc.bits.is_some() {
@@ -56,6 +69,7 @@ fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
                     ))
                 }
                 let meta = attr.parse_meta()?;
+                acc.bits_span = Some(attr.span());
                 acc.bits = match meta {
                     syn::Meta::NameValue(syn::MetaNameValue {
                         lit: syn::Lit::Int(lit),
@@ -74,6 +88,31 @@ fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
     Ok(attributes)
 }
 
+/// Returns `true` if the enum has a signed `#[repr(iN)]` and thus may have negative discriminants.
+fn has_signed_repr(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .any(|meta| {
+            match meta {
+                syn::Meta::List(list) => {
+                    list.nested.iter().any(|nested| {
+                        match nested {
+                            syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                                ["i8", "i16", "i32", "i64", "i128", "isize"]
+                                    .iter()
+                                    .any(|signed| path.is_ident(signed))
+                            }
+                            _ => false,
+                        }
+                    })
+                }
+                _ => false,
+            }
+        })
+}
+
 fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
     let span = input.span();
     let attributes = parse_attrs(&input.attrs)?;
@@ -103,6 +142,14 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
         }
     };
 
+    let signed = has_signed_repr(&input.attrs);
+    if signed && bits == 0 {
+        return Err(format_err!(
+            span,
+            "BitfieldSpecifier requires at least 1 bit for enums with a signed #[repr(iN)]",
+        ))
+    }
+
     let variants = input
         .variants
         .iter()
@@ -116,23 +163,95 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
 
     let check_discriminants = variants.iter().map(|ident| {
         let span = ident.span();
+        let in_range = match signed {
+            true => {
+                // Two's complement values of `bits` width are those whose bits above
+                // the sign bit are all equal to the sign bit.
+                let sign_bit = bits - 1;
+                quote_spanned!(span=>
+                    (((Self::#ident as i128) >> #sign_bit) == 0) | (((Self::#ident as i128) >> #sign_bit) == -1)
+                )
+            }
+            false => quote_spanned!(span=> ((Self::#ident as usize) < (0x01_usize << #bits))),
+        };
         quote_spanned!(span =>
             impl ::modular_bitfield::private::checks::CheckDiscriminantInRange<[(); Self::#ident as usize]> for #enum_ident {
-                type CheckType = [(); ((Self::#ident as usize) < (0x01_usize << #bits)) as usize ];
+                type CheckType = [(); (#in_range) as usize ];
+            }
+        )
+    });
+    // Negative discriminants are stored in two's complement truncated to `bits`.
+    let to_bytes = |value: TokenStream2| {
+        match signed {
+            true => {
+                quote_spanned!(span=>
+                    ((#value as i128 as u128) & (::core::primitive::u128::MAX >> (128 - #bits)))
+                        as <#enum_ident as ::modular_bitfield::Specifier>::Bytes
+                )
             }
+            false => quote_spanned!(span=> #value as <#enum_ident as ::modular_bitfield::Specifier>::Bytes),
+        }
+    };
+    // If the variants cover every bit pattern of the specifier there cannot be
+    // invalid inputs and the last variant handles all remaining patterns.
+    let covers_all_patterns = 1_usize
+        .checked_shl(bits as u32)
+        .map(|patterns| patterns == variants.len())
+        .unwrap_or(false);
+    let (matched_variants, fallback_arm) = match (covers_all_patterns, variants.split_last()) {
+        (true, Some((last, rest))) => {
+            let span = last.span();
+            let range_check = match bits {
+                8 | 16 | 32 | 64 | 128 => None,
+                _ => Some(quote_spanned!(span=> ::core::debug_assert!(bytes < (0x01 << #bits));)),
+            };
+            let fallback = quote_spanned!(span=>
+                _ => {
+                    #range_check
+                    ::core::result::Result::Ok(Self::#last)
+                }
+            );
+            (rest, fallback)
+        }
+        _ => {
+            let fallback = quote_spanned!(span=>
+                invalid_bytes => {
+                    ::core::result::Result::Err(
+                        <::modular_bitfield::error::InvalidBitPattern<Self::Bytes>>::new(invalid_bytes)
+                    )
+                }
+            );
+            (&variants[..], fallback)
+        }
+    };
+    // The discriminants of the variants are bound to constants so that they
+    // can be used as patterns of a single `match` which compiles to a jump table.
+    let discriminants = matched_variants
+        .iter()
+        .map(|ident| format_ident!("__BF_DISCRIMINANT_{}", ident.unraw()))
+        .collect::<Vec<_>>();
+    let define_discriminants = matched_variants.iter().zip(&discriminants).map(|(ident, discriminant)| {
+        let span = ident.span();
+        let value = to_bytes(quote_spanned!(span=> #enum_ident::#ident));
+        quote_spanned!(span=>
+            const #discriminant: <#enum_ident as ::modular_bitfield::Specifier>::Bytes = #value;
         )
     });
-    let from_bytes_arms = variants.iter().map(|ident| {
+    let input_bytes = to_bytes(quote_spanned!(span=> input));
+    let from_bytes_arms = matched_variants.iter().zip(&discriminants).map(|(ident, discriminant)| {
         let span = ident.span();
         quote_spanned!(span=>
-            __bitfield_binding if __bitfield_binding == Self::#ident as <Self as ::modular_bitfield::Specifier>::Bytes => {
-                ::core::result::Result::Ok(Self::#ident)
-            }
+            #discriminant => ::core::result::Result::Ok(Self::#ident),
         )
     });
 
+    let wide_bits_warning = attributes
+        .bits_span
+        .and_then(|bits_span| expand_wide_bits_warning(&input, bits, bits_span));
+
     Ok(quote_spanned!(span=>
         #( #check_discriminants )*
+        #wide_bits_warning
 
         impl ::modular_bitfield::Specifier for #enum_ident {
             const BITS: usize = #bits;
@@ -141,20 +260,124 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
 
             #[inline]
             fn into_bytes(input: Self::InOut) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
-                ::core::result::Result::Ok(input as Self::Bytes)
+                ::core::result::Result::Ok(#input_bytes)
             }
 
             #[inline]
+            #[allow(non_upper_case_globals)]
             fn from_bytes(bytes: Self::Bytes) -> ::core::result::Result<Self::InOut, ::modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
+                #( #define_discriminants )*
                 match bytes {
-                    #( #from_bytes_arms ),*
-                    invalid_bytes => {
-                        ::core::result::Result::Err(
-                            <::modular_bitfield::error::InvalidBitPattern<Self::Bytes>>::new(invalid_bytes)
-                        )
-                    }
+                    #( #from_bytes_arms )*
+                    #fallback_arm
                 }
             }
         }
     ))
 }
+
+/// Returns the discriminants of all variants if they are known at expansion time.
+///
+/// This is the case if all explicit discriminants are integer literals.
+fn known_discriminants(input: &syn::ItemEnum) -> Option<Vec<u128>> {
+    let mut next = 0_u128;
+    let mut discriminants = Vec::new();
+    for variant in &input.variants {
+        let discriminant = match &variant.discriminant {
+            Some((
+                _,
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(lit_int),
+                    ..
+                }),
+            )) => lit_int.base10_parse::<u128>().ok()?,
+            Some(_) => return None,
+            None => next,
+        };
+        discriminants.push(discriminant);
+        next = discriminant.checked_add(1)?;
+    }
+    Some(discriminants)
+}
+
+/// Formats the bit patterns of a `bits` wide specifier that no discriminant matches.
+///
+/// Consecutive patterns are combined into ranges and only the first few ranges are listed.
+fn format_unreachable_patterns(discriminants: &[u128], bits: usize) -> String {
+    const MAX_RANGES: usize = 4;
+    let max = match bits {
+        128 => u128::MAX,
+        _ => (1_u128 << bits) - 1,
+    };
+    let mut sorted = discriminants.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let mut ranges = Vec::new();
+    let mut start = Some(0_u128);
+    for discriminant in sorted {
+        if let Some(first) = start {
+            if first < discriminant {
+                ranges.push((first, discriminant - 1));
+            }
+        }
+        start = discriminant.checked_add(1);
+    }
+    if let Some(first) = start {
+        if first <= max {
+            ranges.push((first, max));
+        }
+    }
+    let mut formatted = ranges
+        .iter()
+        .take(MAX_RANGES)
+        .map(|&(first, last)| {
+            match first == last {
+                true => format!("{}", first),
+                false => format!("{}..={}", first, last),
+            }
+        })
+        .collect::<Vec<_>>();
+    if ranges.len() > MAX_RANGES {
+        formatted.push("..".to_string());
+    }
+    formatted.join(", ")
+}
+
+/// Generates a deprecation warning if the `#[bits = N]` attribute of the enum is more than one
+/// bit wider than required by its discriminants, listing the bit patterns that no variant matches.
+///
+/// A single spare bit is tolerated since it is commonly reserved for future variants.
+///
+/// The warning is reported through the `deprecated` lint so that it can be denied as well
+/// as allowed by the lint attributes of the enum which are forwarded to the warning.
+fn expand_wide_bits_warning(input: &syn::ItemEnum, bits: usize, span: Span) -> Option<TokenStream2> {
+    let discriminants = known_discriminants(input)?;
+    let max = discriminants.iter().copied().max()?;
+    let required_bits = 128 - max.leading_zeros() as usize;
+    if required_bits + 1 >= bits {
+        return None
+    }
+    let note = format!(
+        "#[bits = {}] of `{}` is wider than the {} bits required by its variants, \
+         no variant matches the bit patterns {}; \
+         use #[bits = {}] or #[allow(deprecated)] on the enum if this is intended",
+        bits,
+        input.ident,
+        required_bits,
+        format_unreachable_patterns(&discriminants, bits),
+        required_bits,
+    );
+    let lint_attrs = input.attrs.iter().filter(|attr| {
+        ["allow", "warn", "deny", "forbid"]
+            .iter()
+            .any(|lint| attr.path.is_ident(lint))
+    });
+    Some(quote_spanned!(span=>
+        #( #lint_attrs )*
+        const _: () = {
+            #[deprecated(note = #note)]
+            const BITS_WIDER_THAN_NEEDED: () = ();
+            BITS_WIDER_THAN_NEEDED
+        };
+    ))
+}
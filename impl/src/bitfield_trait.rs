@@ -0,0 +1,104 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote_spanned,
+};
+use syn::spanned::Spanned as _;
+
+/// Analyses the given `#[bitfield_trait]` trait and expands it together with the
+/// hidden macro implementing the trait for `#[bitfield(impl_trait = "..")]` structs.
+pub fn analyse_and_expand(args: TokenStream2, input: TokenStream2) -> TokenStream2 {
+    match analyse_and_expand_or_error(args, input) {
+        Ok(output) => output,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+fn analyse_and_expand_or_error(args: TokenStream2, input: TokenStream2) -> syn::Result<TokenStream2> {
+    if !args.is_empty() {
+        return Err(format_err_spanned!(
+            args,
+            "encountered unexpected arguments for #[bitfield_trait]"
+        ))
+    }
+    let item_trait = syn::parse2::<syn::ItemTrait>(input)?;
+    if !item_trait.generics.params.is_empty() {
+        return Err(format_err_spanned!(
+            item_trait.generics,
+            "generic traits are not supported by #[bitfield_trait]"
+        ))
+    }
+    let forwards = item_trait
+        .items
+        .iter()
+        .map(expand_forward)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let span = item_trait.span();
+    let trait_ident = &item_trait.ident;
+    let macro_ident = format_ident!("__bf_impl_{}", trait_ident);
+    Ok(quote_spanned!(span=>
+        #item_trait
+
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        macro_rules! #macro_ident {
+            ( $ty:ty, $trait_path:path ) => {
+                impl $trait_path for $ty {
+                    #( #forwards )*
+                }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #macro_ident as #trait_ident;
+    ))
+}
+
+/// Expands the method of the trait impl that forwards to the inherent method of the same name.
+///
+/// Returns `None` for provided methods since their default implementation is used.
+fn expand_forward(item: &syn::TraitItem) -> syn::Result<Option<TokenStream2>> {
+    let method = match item {
+        syn::TraitItem::Method(method) => method,
+        unsupported => {
+            return Err(format_err_spanned!(
+                unsupported,
+                "only methods are supported by #[bitfield_trait]"
+            ))
+        }
+    };
+    if method.default.is_some() {
+        return Ok(None)
+    }
+    let span = method.span();
+    let mut sig = method.sig.clone();
+    match sig.inputs.first_mut() {
+        Some(syn::FnArg::Receiver(receiver)) => {
+            if receiver.reference.is_none() {
+                receiver.mutability = None;
+            }
+        }
+        _ => {
+            return Err(format_err_spanned!(
+                sig,
+                "expected a method with a `self` receiver for #[bitfield_trait]"
+            ))
+        }
+    }
+    let mut args = Vec::new();
+    for (n, input) in sig.inputs.iter_mut().enumerate() {
+        if let syn::FnArg::Typed(pat_type) = input {
+            let arg = format_ident!("__bf_arg{}", n);
+            *pat_type.pat = syn::parse_quote!(#arg);
+            args.push(arg);
+        }
+    }
+    let ident = &sig.ident;
+    Ok(Some(quote_spanned!(span=>
+        #[inline]
+        #sig {
+            <$ty>::#ident(self #( , #args )*)
+        }
+    )))
+}
@@ -6,11 +6,43 @@ use quote::{
 
 pub fn generate(_input: TokenStream2) -> TokenStream2 {
     let specifiers = (1usize..=128).map(generate_specifier_for);
+    let zero_width = generate_zero_width_specifier();
     quote! {
+        #zero_width
         #( #specifiers )*
     }
 }
 
+/// Generates the `B0` specifier that occupies no bits at all.
+///
+/// Its accessors take and return `()` so that marker fields do not affect the layout.
+fn generate_zero_width_specifier() -> TokenStream2 {
+    quote! {
+        /// Specifier for zero bits.
+        ///
+        /// Useful for marker fields that must not affect the layout of a bitfield,
+        /// e.g. as placeholders for conditionally compiled fields.
+        #[derive(Copy, Clone)]
+        pub enum B0 {}
+
+        impl crate::Specifier for B0 {
+            const BITS: usize = 0;
+            type Bytes = ::core::primitive::u8;
+            type InOut = ();
+
+            #[inline]
+            fn into_bytes(_input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+                Ok(0)
+            }
+
+            #[inline]
+            fn from_bytes(_bytes: Self::Bytes) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+                Ok(())
+            }
+        }
+    }
+}
+
 fn generate_specifier_for(bits: usize) -> TokenStream2 {
     let in_out = match bits {
         1..=8 => quote! { ::core::primitive::u8 },
@@ -46,7 +78,7 @@ fn generate_specifier_for(bits: usize) -> TokenStream2 {
             #[inline]
             fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
                 if input > #max_value {
-                    return Err(crate::OutOfBounds)
+                    return Err(crate::OutOfBounds::with_value(input as u128, #bits))
                 }
                 Ok(input)
             }
@@ -54,7 +86,7 @@ fn generate_specifier_for(bits: usize) -> TokenStream2 {
             #[inline]
             fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
                 if bytes > #max_value {
-                    return Err(crate::InvalidBitPattern { invalid_bytes: bytes })
+                    return Err(crate::InvalidBitPattern::new(bytes))
                 }
                 Ok(bytes)
             }
@@ -6,8 +6,11 @@ extern crate proc_macro;
 #[macro_use]
 mod errors;
 mod bitfield;
+mod bitfield_layout;
 mod bitfield_specifier;
+mod bitfield_trait;
 mod define_specifiers;
+mod svd_registers;
 
 use proc_macro::TokenStream;
 
@@ -31,172 +34,1488 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 ///        Note that invalid bit patterns are supported in that getters and setters will
 ///        be protecting accesses.
 ///
+/// - **Reset:**
+///
+///     - `clear()`: Resets all bits of the bitfield to 0.
+///
+/// - **Bit Access:**
+///
+///     - `bit(index)`: Returns the bit at position `index` and panics if `index` is out of bounds.
+///     - `set_bit(index, value)`: Sets the bit at position `index` and panics if `index` is out of bounds.
+///
 /// - **Getters:**
 ///
-///     For every field `f` we generate the following getters:
+///     For every field `f` we generate the following getters:
+///
+///     1. `f()`: Returns the value of `f` and might panic
+///        if the value contains an invalid bit pattern.
+///     2. `f_or_err()`: Returns the value of `f` or an error
+///        if the value contains an invalid bit pattern.
+///
+/// - **Setters:**
+///
+///     For every field `f` we generate the following setters:
+///
+///     1. `set_f(new_value)`: Sets the value of `f` to `new_value` and might panic
+///        if `new_value` is out of bounds for the bit width of `f`.
+///     2. `set_f_checked(new_value)`: Sets the value of `f` to `new` or returns an error
+///        if `new_value` if out of bounds for the bit width of `f`.
+///     3. `with_f(new_value)`: Similar to `set_f` but consumes and returns `Self`.
+///        Primarily useful for method chaining.
+///     4. `with_f_checked(new_value)`: Similar to `set_f_checked` but consumes and returns `Self`.
+///        Primarily useful for method chaining.
+///     5. `clear_f()`: Resets all bits of `f` to 0 or to the `default` parameter if given.
+///     6. `set_f_from(new_value)`: Similar to `set_f` but accepts any value that converts
+///        `Into` the type of `f`, e.g. a `u8` for a `B12` field.
+///     7. `with_f_from(new_value)`: Similar to `set_f_from` but consumes and returns `Self`.
+///
+/// - **Conversions:**
+///
+///     - `from_bytes(bytes)`: Allows to constructor the bitfield type from a fixed array of bytes.
+///     - `into_bytes()`: Allows to convert the bitfield into its underlying byte representation.
+///     - `from_bytes_slice(bytes)`: Allows to construct the bitfield type from the prefix of a byte slice.
+///       Returns an error if the slice is too short or contains invalid padding bits.
+///     - `to_le_bytes()`, `to_be_bytes()` and `to_ne_bytes()`: Convert the bitfield into its underlying
+///       byte representation in little-endian, big-endian or native byte order respectively.
+///       The internal byte representation of a bitfield is little-endian.
+///     - `from_le_bytes(bytes)`, `from_be_bytes(bytes)` and `from_ne_bytes(bytes)`: Counterparts to the
+///       above that mirror the signature of `from_bytes`.
+///     - `as_bytes()`: Returns a reference to the underlying byte representation.
+///     - `as_bytes_mut()`: Returns a mutable reference to the underlying byte representation.
+///       Only generated for bitfields with `filled = true`.
+///     - `From<[u8; N]>` and `From<Self> for [u8; N]` trait implementations mirroring
+///       `from_bytes` and `into_bytes`. For bitfields with `filled = false` a `TryFrom<[u8; N]>`
+///       implementation is generated instead of the `From<[u8; N]>` one.
+///       These are not generated if `bytes_vis` restricts the visibility of the conversions.
+///     - `modular_bitfield::Bitfield` trait implementation that allows to generically operate on
+///       the underlying bytes. With the `std` crate feature enabled this provides `read_from(reader)`
+///       and `write_to(writer)` through the `modular_bitfield::std_io::BitfieldIo` extension trait.
+///       The same is provided for `embedded-io` streams with the `embedded-io` crate feature through
+///       the `modular_bitfield::embedded_io::BitfieldEmbeddedIo` extension trait.
+///       With the `alloc` crate feature enabled `to_vec()`, `extend_bytes(buffer)` and
+///       `from_slice(bytes)` are provided through the `modular_bitfield::vec::BitfieldVec` extension trait.
+///       Not generated if `bytes_vis` restricts the visibility of the conversions.
+///     - `hash32::Hash` trait implementation hashing the underlying bytes so that the bitfield
+///       can be used as key of `heapless` maps. Requires the `hash32` crate feature.
+///
+/// # Parameters
+///
+/// The following parameters for the `#[bitfield]` macro are supported:
+///
+/// ## Parameter: `bytes = N`
+///
+/// This ensures at compilation time that the resulting `#[bitfield]` struct consists of
+/// exactly `N` bytes. Yield a compilation error if this does not hold true.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bytes = 2)]
+/// pub struct SingedInt {
+///     sign: bool, //  1 bit
+///     value: B15, // 15 bits
+/// }
+/// ```
+///
+/// ## Parameter: `filled: bool`
+///
+/// If `filled` is `true` ensures that the `#[bitfield]` struct defines all bits and
+/// therefore has a bitwidth that is divisible by 8. If `filled` is `false` ensures the
+/// exact opposite. The `from_bytes` constructor of a bitfield with `filled = false` returns
+/// an error for bytes with bits set beyond its bitwidth and is `const` nonetheless.
+/// The additional `from_bytes_unchecked` constructor skips this check.
+///
+/// The default value is: `true`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(filled = false)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B2,        // 2 bits
+/// }
+///
+/// const ALIVE: Package = Package::from_bytes_unchecked([0b0000_0010]);
+/// assert!(ALIVE.is_alive());
+/// assert!(Package::from_bytes([0b0001_0000]).is_err());
+/// ```
+///
+/// ## Parameter: `bits = N`
+///
+/// With the `bits: int` parameter it is possible to control the targeted bit width of
+/// a `#[bitfield]` annoated struct. Using `bits = N` guarantees that the resulting bitfield
+/// struct will have a bit width of exactly `N`.
+///
+/// ### Example 1
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bits = 16)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B14,       // 14 bits
+/// }
+/// ```
+///
+/// ### Example 2
+///
+/// The `bits: int` parameter is especially useful when using this in conjunction with
+/// `#[derive(BitfieldSpecifier)] and `filled = false` as shown in the below example.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bits = 5)]
+/// #[derive(BitfieldSpecifier)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B3,        // 3 bits
+/// }
+/// ```
+///
+/// ## Parameter: `bytes_vis: str`
+///
+/// Controls the visibility of the generated `from_bytes`, `from_bytes_slice`, `into_bytes`,
+/// `as_bytes`, `as_bytes_mut`, `bit` and `set_bit` methods.
+/// The string is parsed as a Rust visibility, so an empty string makes them private.
+/// A visibility other than `pub` also disables the `Reflect` impl unless `reflect = true` is given.
+///
+/// The default value is: `"pub"`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bytes_vis = "pub(crate)")]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+/// ```
+///
+/// ## Parameter: `bytes_field: str` and `bytes_field_vis: str`
+///
+/// The bits of a `#[bitfield]` struct are stored in its only field, a byte array named `bytes`
+/// that is private by default. With `bytes_field` the field can be given another name and with
+/// `bytes_field_vis` it can be made visible, e.g. for FFI or for constructing bitfields in
+/// constant expressions.
+///
+/// The field always is a `[u8; N]` where `N` is the number of bytes of the bitfield and the bits
+/// are stored in the same order as returned by `into_bytes`. The struct is `#[repr(C)]` by default
+/// and thus has the layout of the byte array. Add `#[repr(transparent)]` to the struct if also its
+/// ABI must be guaranteed to equal that of the byte array.
+///
+/// The default values are: `"bytes"` and `""`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bytes_field = "raw", bytes_field_vis = "pub")]
+/// #[repr(transparent)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// const PACKAGE: Package = Package { raw: [0b0000_0101] };
+/// assert!(PACKAGE.is_received());
+/// assert_eq!(PACKAGE.status(), 1);
+/// ```
+///
+/// ## Parameter: `default: int` or `default: str`
+///
+/// Sets the reset value that `new()` initializes the bitfield with instead of all zeros,
+/// e.g. to match the reset state of a hardware register as given in its datasheet.
+/// The reset value is either an integer with the first field in its least significant bits or a
+/// string containing an array of the bytes in the order of `into_bytes`.
+/// A `#[derive(Default)]` on the struct and the `clear_f()` methods of its fields use the reset
+/// value as well while `clear()` still resets all bits to zero.
+/// Setting bits beyond the fields of the bitfield is a compile time error.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(default = 0x8421)]
+/// #[derive(Default)]
+/// pub struct Control {
+///     mode: B4,
+///     prescaler: B8,
+///     enabled: B4,
+/// }
+///
+/// let control = Control::default();
+/// assert_eq!(control.mode(), 0x1);
+/// assert_eq!(control.prescaler(), 0x42);
+/// assert_eq!(control.enabled(), 0x8);
+///
+/// #[bitfield(default = "[0x21, 0x84]")]
+/// pub struct SameControl {
+///     mode: B4,
+///     prescaler: B8,
+///     enabled: B4,
+/// }
+///
+/// assert_eq!(SameControl::new().into_bytes(), Control::new().into_bytes());
+/// ```
+///
+/// ## Parameter: `reset: int` or `reset: str`
+///
+/// Generates a `RESET` associated constant holding the given reset value and a
+/// `reset(&mut self)` method restoring it, e.g. to return a register image to its power-on
+/// state. Unlike `default` this leaves `new()` zero initialized.
+/// The reset value is given in the same format as for the `default` parameter.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(reset = 0x1F)]
+/// pub struct Status {
+///     flags: B5,
+///     count: B3,
+/// }
+///
+/// let mut status = Status::new();
+/// assert_eq!(status.flags(), 0);
+/// status.set_count(5);
+/// status.reset();
+/// assert_eq!(status.flags(), 0x1F);
+/// assert_eq!(status.count(), 0);
+/// assert_eq!(Status::RESET.into_bytes(), [0x1F]);
+/// ```
+///
+/// ## Parameter: `accessor_trait: str`
+///
+/// With `accessor_trait = "FooAccess"` the getters and setters of all fields are declared in a
+/// generated trait `FooAccess` which is implemented for the bitfield instead of being generated
+/// as inherent methods. This avoids collisions with methods of other inherent impl blocks and
+/// allows generic code to accept any type providing the same accessors.
+///
+/// The trait has the visibility of the bitfield struct and must be in scope to use the accessors.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(accessor_trait = "PackageAccess")]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// impl Package {
+///     // Does not collide with the `status` getter of `PackageAccess`.
+///     pub fn status(&self) -> &'static str {
+///         "package"
+///     }
+/// }
+///
+/// fn is_alive(access: &impl PackageAccess) -> bool {
+///     access.is_alive()
+/// }
+///
+/// let package = Package::new().with_is_alive(true).with_status(3);
+/// assert!(is_alive(&package));
+/// assert_eq!(PackageAccess::status(&package), 3);
+/// assert_eq!(package.status(), "package");
+/// ```
+///
+/// ## Parameter: `byte_perm: str`
+///
+/// Generates `to_perm_bytes` and `from_perm_bytes` conversions for formats that store
+/// little-endian words in big-endian word order (middle-endian), as found in some console and PLC formats.
+/// With `"swap16"` the order of the 16-bit words is reversed and with `"swap32"` the order of
+/// the 32-bit words. The size of the bitfield must be a multiple of the word size.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(byte_perm = "swap16")]
+/// pub struct Counter {
+///     value: u32,
+/// }
+///
+/// let counter = Counter::new().with_value(0xAABB_CCDD);
+/// assert_eq!(counter.to_perm_bytes(), [0xBB, 0xAA, 0xDD, 0xCC]);
+/// assert_eq!(Counter::from_perm_bytes([0xBB, 0xAA, 0xDD, 0xCC]).value(), 0xAABB_CCDD);
+/// ```
+///
+/// ## Parameter: `unaligned: bool`
+///
+/// With `unaligned = true` the alignment of 1 of the generated struct becomes part of its API:
+/// a static assertion rejects the struct if it is over-aligned, e.g. by `#[repr(align(N))]`.
+/// Such a bitfield can be read from a byte buffer at any offset.
+/// If the `zerocopy` crate feature is enabled, `zerocopy::Unaligned` is implemented for it as well.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(unaligned = true)]
+/// pub struct Header {
+///     version: B4,
+///     kind: B4,
+///     length: u16,
+/// }
+///
+/// assert_eq!(core::mem::align_of::<Header>(), 1);
+/// ```
+///
+/// ## Parameter: `as_ref: bool`
+///
+/// If `as_ref` is `true` the `#[bitfield]` struct implements `AsRef<[u8]>` and `AsMut<[u8]>`
+/// so that it can be handed directly to APIs operating on byte slices.
+/// The `AsMut<[u8]>` implementation is only generated for bitfields with `filled = true`.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(as_ref = true)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// let package = Package::new().with_status(1);
+/// assert_eq!(package.as_ref(), &[0b0000_0100]);
+/// ```
+///
+/// ## Parameter: `bit_ops: bool`
+///
+/// If `bit_ops` is `true` the `#[bitfield]` struct implements `BitAnd`, `BitOr`, `BitXor` and `Not`
+/// as well as their assigning variants which operate on the underlying bytes. This allows to
+/// combine flag masks like `READ | WRITE` without converting them to integers first.
+/// The operators do not check the resulting bit patterns of the fields.
+/// For bitfields with `filled = false` the `Not` operator keeps the undefined bits zeroed.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bit_ops = true)]
+/// #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+/// pub struct Access {
+///     read: bool,
+///     write: bool,
+///     execute: bool,
+///     #[skip] __: B5,
+/// }
+///
+/// let read = Access::new().with_read(true);
+/// let write = Access::new().with_write(true);
+/// let read_write = read | write;
+/// assert!(read_write.read() && read_write.write());
+/// assert_eq!(read_write & write, write);
+/// assert!(!(!read_write).read());
+/// ```
+///
+/// ## Parameter: `field_errors: bool`
+///
+/// With `field_errors = true` a `FooError` enum is generated for a `#[bitfield]` struct `Foo`
+/// with one variant per field that might contain an invalid bit pattern, i.e. every field that
+/// is neither a `bool` nor an unsigned integer. The variants are named after their fields in
+/// `CamelCase` and carry the invalid bits. The generated `try_get_all()` method checks all
+/// fields and returns the error of the first invalid one which can be matched on.
+/// `FooError` converts into the `DecodeError` returned by `try_from_bytes`.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug)]
+/// #[bits = 2]
+/// pub enum Mode {
+///     Off,
+///     Slow,
+///     Fast,
+/// }
+///
+/// #[bitfield(field_errors = true)]
+/// pub struct Control {
+///     enabled: bool,
+///     mode: Mode,
+///     level: B5,
+/// }
+///
+/// assert_eq!(Control::new().try_get_all(), Ok(()));
+/// assert_eq!(Control::from_bytes([0b0000_0110]).try_get_all(), Err(ControlError::Mode(0b11)));
+/// ```
+///
+/// ## Parameter: `gen_tests: bool`
+///
+/// With `gen_tests = true` a `#[cfg(test)]` module is generated next to the `#[bitfield]`
+/// struct `Foo` that is picked up by `cargo test`. Its tests check for every field with getters
+/// and setters that setting the field to the bit patterns of its minimum, middle and maximum value
+/// returns the same bits from its getter without changing any other bits. Bit patterns that the
+/// field's specifier or `validate` function rejects, e.g. invalid enum discriminants, are skipped.
+/// Furthermore they check that `from_bytes` and `into_bytes` are symmetric.
+/// All checks start from zeroed bytes and leave the bits of `#[skip]`ped fields clear.
+/// Writing just `gen_tests` is a shorthand for `gen_tests = true`.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(gen_tests)]
+/// pub struct Header {
+///     is_compact: bool,
+///     length: B12,
+///     version: B3,
+/// }
+/// ```
+///
+/// ## Parameter: `text: bool`
+///
+/// With `text = true` the `Display` and `FromStr` traits are implemented for the `#[bitfield]`
+/// struct using a stable `name=value,flag=true` syntax. Fields are written in declaration order
+/// and `#[skip]`ped fields are omitted. `bool` fields are written as `true` or `false` and all
+/// other fields as the decimal value of their raw bits.
+///
+/// When parsing, the pairs may come in any order and surrounding whitespace is ignored.
+/// Values may also be given as `0x` prefixed hexadecimal integers and fields that are not
+/// mentioned are zero initialized. Errors are reported as `ParseTextError`.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(text = true)]
+/// #[derive(Debug, PartialEq)]
+/// pub struct Control {
+///     enabled: bool,
+///     #[skip]
+///     __: B3,
+///     level: B4,
+/// }
+///
+/// let control = Control::new().with_enabled(true).with_level(9);
+/// assert_eq!(control.to_string(), "enabled=true,level=9");
+/// assert_eq!("level=0x9, enabled=true".parse::<Control>(), Ok(control));
+/// assert!("level=16".parse::<Control>().is_err());
+/// ```
+///
+/// ## Parameter: `views: bool`
+///
+/// If `views` is `true` two additional types are generated for a `#[bitfield]` struct `Foo`:
+///
+/// - `FooView<'a>` wraps a `&'a [u8; N]` and provides the getters of `Foo`.
+/// - `FooViewMut<'a>` wraps a `&'a mut [u8; N]` and provides the getters and setters of `Foo`.
+///
+/// Both operate in place on caller owned memory instead of copying the bytes into a `Foo`.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(views = true)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// let mut buffer = [0x00];
+/// PackageViewMut::new(&mut buffer).set_status(3);
+/// assert_eq!(PackageView::new(&buffer).status(), 3);
+/// ```
+///
+/// ## Parameter: `ptr_view: bool`
+///
+/// If `ptr_view` is `true` an additional `FooPtr` type is generated for a `#[bitfield]`
+/// struct `Foo` that is created with `unsafe fn from_ptr(*mut u8)`. Its getters and setters
+/// use volatile reads and writes through the raw pointer and only touch the bytes covered
+/// by the accessed field. This is useful for memory mapped registers and FFI owned buffers.
+/// The generated type implements the `modular_bitfield::VolatileAccess` trait. With the
+/// `critical-section` crate feature enabled this provides `modify_cs(|value| ..)` through the
+/// `modular_bitfield::critical_section::ModifyCs` extension trait which performs the whole
+/// read-modify-write within a critical section.
+///
+/// The setters read the bytes covered by the field, modify the field and write all of these
+/// bytes back. They are therefore unsuitable for registers with write-1-to-clear bits since
+/// writing back a set bit of another field clears it. For such registers use the additionally
+/// generated `write_f(value)` methods which do not read the register and write zeros to all
+/// other bits within the written bytes.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(ptr_view = true)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// let mut register = [0x00];
+/// let package = unsafe { PackagePtr::from_ptr(register.as_mut_ptr()) };
+/// package.set_status(3);
+/// assert_eq!(package.status(), 3);
+/// package.write_is_alive(true);
+/// assert_eq!(register, [0b0000_0010]);
+/// ```
+///
+/// ## Parameter: `inline: str`
+///
+/// Controls the `#[inline]` annotations of the generated field accessors.
+/// Use `"always"` for hot code paths and `"never"` to minimize code size.
+/// The `#[inline(..)]` field parameter overrides this for single fields.
+///
+/// By default the field accessors are annotated with `#[inline]`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(inline = "always")]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+/// ```
+///
+/// ## Parameter: `min_code_size: bool`
+///
+/// If `min_code_size` is `true` all field accessors are routed through a small set of shared
+/// non-generic runtime functions that receive the offset and width of the field as arguments
+/// instead of generating specialized code for every field.
+/// This trades access speed for binary size, e.g. for bootloaders with many register structs.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(min_code_size = true)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+/// ```
+///
+/// ## Parameter: `accessors: str`
+///
+/// If `accessors` is `"outline"` the bit access code of every field is generated into hidden
+/// non-generic `#[inline(never)]` functions that are shared by all accessors of the field,
+/// including those of the generated view types. This trades access speed for binary size.
+///
+/// The default value is: `"inline"`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(accessors = "outline")]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+/// ```
+///
+/// ## Parameter: `debug: str`
+///
+/// If `debug` is `"raw"` a `Debug` implementation is generated even without `#[derive(Debug)]`.
+/// It prints the underlying bytes in hexadecimal followed by the best-effort values of all fields.
+/// Fields with invalid bit patterns are printed as errors instead of causing a panic.
+///
+/// If `debug` is `"verbose"` the generated `Debug` implementation additionally prints the
+/// bit range of every field and its raw bits in binary next to the decoded value.
+/// This is useful for diagnosing where each value sits within the underlying bytes.
+///
+/// The default value is: `"fields"`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(debug = "raw")]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// let package = Package::new().with_is_alive(true).with_status(3);
+/// assert_eq!(
+///     format!("{:?}", package),
+///     "Package { bytes: [0x0E], is_received: false, is_alive: true, status: 3 }",
+/// );
+///
+/// #[bitfield(debug = "verbose")]
+/// pub struct Header {
+///     is_compact: bool,
+///     length: B7,
+/// }
+///
+/// let header = Header::new().with_length(5);
+/// assert_eq!(
+///     format!("{:?}", header),
+///     "Header { is_compact: false @ 0..1 = 0b0, length: 5 @ 1..8 = 0b0000101 }",
+/// );
+/// ```
+///
+/// ## Parameter: `atomic: bool`
+///
+/// If `atomic` is `true` an additional `AtomicFoo` type is generated for a `#[bitfield]`
+/// struct `Foo` that stores its bytes in an `AtomicU8`, `AtomicU16`, `AtomicU32` or `AtomicU64`.
+/// It provides `load`, `store`, `fetch_update` and `compare_exchange` for the whole bitfield as
+/// well as getters, setters, `fetch_update_foo` and `compare_exchange_foo` methods per field that
+/// atomically update only the bits of the field. `compare_exchange_foo` only fails if the field
+/// differs from the expected value and retries if just other fields changed concurrently. This allows sharing packed status words between interrupt handlers and main code.
+/// Writing just `atomic` is a shorthand for `atomic = true`.
+///
+/// Only bitfields with a size of 1, 2, 4 or 8 bytes that the target supports atomics for
+/// are supported.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use core::sync::atomic::Ordering;
+///
+/// #[bitfield(atomic)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// static PACKAGE: AtomicPackage = AtomicPackage::new();
+///
+/// PACKAGE.set_status(3, Ordering::Relaxed);
+/// PACKAGE.set_is_alive(true, Ordering::Relaxed);
+/// assert_eq!(PACKAGE.status(Ordering::Relaxed), 3);
+/// assert!(PACKAGE.load(Ordering::Relaxed).is_alive());
+/// assert_eq!(
+///     PACKAGE.compare_exchange_status(3, 5, Ordering::Relaxed, Ordering::Relaxed),
+///     Ok(3),
+/// );
+/// assert_eq!(
+///     PACKAGE.compare_exchange_status(3, 7, Ordering::Relaxed, Ordering::Relaxed),
+///     Err(5),
+/// );
+/// ```
+///
+/// ## Parameter: `export_c: bool`
+///
+/// If `export_c` is `true` additional `#[no_mangle] extern "C"` free functions are generated for
+/// a `#[bitfield]` struct `Foo` that wrap the accessors of each `pub` field `f`. They are named
+/// after the `snake_case` name of the struct: `foo_get_f(ptr)` returns the value of the field and
+/// `foo_set_f(ptr, val)` sets it. Since panics must not unwind into C the setter returns `false`
+/// and leaves the bitfield unchanged if the value is out of bounds. Private fields are not exported
+/// and fields with skipped getters or setters do not get the respective function. Writing just
+/// `export_c` is a shorthand for `export_c = true`.
+///
+/// On the C side the pointers are `Foo *` and the values are passed by value. Therefore only
+/// `pub` fields of type `bool`, `u8` to `u64` or `B1` to `B64` are supported and other types
+/// are rejected at compile time.
+///
+/// Since the symbols are global the prefix of the names can be changed with the
+/// `export_c_prefix: str` parameter, e.g. to tell apart two bitfields of the same name in
+/// different modules. Use it like: `#[bitfield(export_c, export_c_prefix = "uart0_ctrl")]`.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(export_c)]
+/// pub struct Status {
+///     pub ready: bool,
+///     pub code: B7,
+/// }
+///
+/// // Callable from C as `status_set_code(&status, 42)` and `status_get_code(&status)`.
+/// let mut status = Status::new();
+/// assert!(status_set_code(&mut status, 42));
+/// assert!(!status_set_code(&mut status, 200));
+/// assert_eq!(status_get_code(&status), 42);
+/// ```
+///
+/// ## Parameter: `reader_writer: bool`
+///
+/// If `reader_writer` is `true` the additional `FooR` and `FooW` proxy types are generated for a
+/// `#[bitfield]` struct `Foo` in the style of `svd2rust`. `FooR` provides the getters and `FooW`
+/// provides chainable setters returning `&mut Self`. Their proxies are used by the generated
+/// `read()`, `write(|w| ..)` and `modify(|r, w| ..)` methods of `Foo`. The writer of `write`
+/// starts from all bits set to zero while the writer of `modify` starts from the current value.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(reader_writer = true)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// let mut package = Package::new();
+/// package.write(|w| w.set_is_alive(true).set_status(3));
+/// package.modify(|r, w| w.set_status(r.status() + 1));
+/// assert!(package.read().is_alive());
+/// assert_eq!(package.read().status(), 4);
+/// ```
+///
+/// ## Parameter: `validated: bool`
+///
+/// If `validated` is `true` an additional `ValidFoo` wrapper type is generated for a `#[bitfield]`
+/// struct `Foo`. It can only be constructed via `Foo::validated` which checks the padding bits and
+/// the bit patterns of all fields up front, the same as `try_from_bytes`. It stores the decoded
+/// values of all fields so that the getters of `ValidFoo` neither fail nor panic and the validation
+/// cost is paid once at the trust boundary. The getters return clones of the stored values which
+/// requires the in-out types of all fields with getters to implement `Clone`.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq)]
+/// #[bits = 2]
+/// pub enum Status {
+///     Red,
+///     Green,
+///     Yellow,
+/// }
+///
+/// #[bitfield(validated = true)]
+/// pub struct Package {
+///     status: Status,
+///     contents: B6,
+/// }
+///
+/// let package = Package::from_bytes([0b0000_0101]).validated().unwrap();
+/// assert_eq!(package.status(), Status::Green);
+/// assert_eq!(package.contents(), 1);
+/// assert!(Package::from_bytes([0b0000_0011]).validated().is_err());
+/// ```
+///
+/// ## Parameter: `check_reserved: bool`
+///
+/// If `check_reserved` is `true` the setters, `set_bit` and `from_bytes` of the `#[bitfield]`
+/// struct `debug_assert!` that the bits of all fields that are entirely skipped via `#[skip]`
+/// are zero. This catches silent corruption of reserved "write as zero" regions in debug builds.
+/// Note that `from_bytes` is no longer `const` with this parameter.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(check_reserved = true)]
+/// pub struct Register {
+///     enable: bool,
+///     #[skip]
+///     __: B3,
+///     mode: B4,
+/// }
+///
+/// let register = Register::from_bytes([0b1111_0001]);
+/// assert!(register.enable());
+/// assert_eq!(register.mode(), 0b1111);
+/// ```
+///
+/// ## Parameter: `reflect: bool`
+///
+/// Controls whether the `modular_bitfield::layout::Reflect` trait is implemented for the
+/// `#[bitfield]` struct which reads and writes the raw bits of its fields by their names.
+/// Since this gives access to all bits of the bitfield it is only implemented by default
+/// if `bytes_vis` does not restrict the visibility of the byte conversions.
+///
+/// The default value is: `true` if the byte conversions are `pub` and otherwise `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::layout::Reflect;
+///
+/// #[bitfield(bytes_vis = "pub(crate)", reflect = true)]
+/// pub struct Control {
+///     enabled: bool,
+///     level: B7,
+/// }
+///
+/// let mut control = Control::new();
+/// control.set_field("level", 9).unwrap();
+/// assert_eq!(control.get_field("level"), Some(9));
+/// ```
+///
+/// ## Parameter: `layout_docs: bool`
+///
+/// If `layout_docs` is `true` a `# Layout` section with a table of the bit positions of
+/// every field is appended to the documentation of the `#[bitfield]` struct.
+/// The table is only generated if the bit widths of all fields are known to the macro,
+/// i.e. for `bool`, `uN` and `BN` fields as well as fields annotated with `#[bits = N]`.
+///
+/// The default value is: `true`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// /// Documented without a layout table.
+/// #[bitfield(layout_docs = false)]
+/// pub struct Register {
+///     enable: bool,
+///     mode: B7,
+/// }
+/// ```
+///
+/// ## Parameter: `migrate_from: str`
+///
+/// Declares older versions of a `#[bitfield]` struct, e.g. of a saved game or configuration
+/// format, that the struct can be migrated from. For every listed type `T` a `From<T> for Self`
+/// impl is generated which copies the fields with matching names via
+/// `Reflect::copy_fields_from`. Fields that only exist in `Self` are zero initialized
+/// and fields that only exist in `T` are dropped.
+///
+/// Fields with matching names must be of the same kind and have the same number of bits,
+/// except that unsigned integer fields such as `B4` may be widened to e.g. `B8`. Otherwise
+/// the migration would lose data or write unvalidated bits into enum fields and is rejected
+/// at compile time.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct ConfigV1 {
+///     volume: B4,
+///     legacy: B4,
+/// }
+///
+/// #[bitfield(migrate_from = "ConfigV1")]
+/// pub struct ConfigV2 {
+///     brightness: B8,
+///     volume: B8,
+/// }
+///
+/// let v2 = ConfigV2::from(ConfigV1::new().with_volume(7).with_legacy(3));
+/// assert_eq!(v2.volume(), 7);
+/// assert_eq!(v2.brightness(), 0);
+/// ```
+///
+/// ## Parameter: `impl_trait: str`
+///
+/// Implements the listed `#[bitfield_trait]` traits for the `#[bitfield]` struct.
+/// Every required method of a trait is forwarded to the generated getter, setter or
+/// other inherent method with the same name, so drivers can be written generically
+/// over several register layouts sharing the same accessors.
+/// The traits must be declared with `#[bitfield_trait]` in the same crate.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// mod regs {
+///     use modular_bitfield::prelude::*;
+///
+///     #[bitfield_trait]
+///     pub trait RegisterOps {
+///         fn ready(&self) -> bool;
+///         fn set_ready(&mut self, new_val: bool);
+///     }
+/// }
+///
+/// #[bitfield(impl_trait = "regs::RegisterOps")]
+/// pub struct StatusV1 {
+///     ready: bool,
+///     error: B7,
+/// }
+///
+/// #[bitfield(impl_trait = "regs::RegisterOps")]
+/// pub struct StatusV2 {
+///     error: B15,
+///     ready: bool,
+/// }
+///
+/// fn mark_ready(register: &mut impl regs::RegisterOps) {
+///     register.set_ready(true);
+/// }
+///
+/// fn main() {
+///     let mut v1 = StatusV1::new();
+///     let mut v2 = StatusV2::new();
+///     mark_ready(&mut v1);
+///     mark_ready(&mut v2);
+///     assert!(v1.ready() && v2.ready());
+///     assert_eq!(v2.into_bytes(), [0x00, 0x80]);
+/// }
+/// ```
+///
+/// ## Parameter: `ord_by: str`
+///
+/// Generates `PartialOrd` and `Ord` implementations comparing the given comma separated
+/// fields in order of priority. The bits of every field are compared as unsigned integers.
+/// If all of them are equal the remaining bits decide so that the ordering agrees with
+/// the equality of the underlying bytes. Requires `PartialEq` and `Eq` to be derived.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(ord_by = "sequence")]
+/// #[derive(Copy, Clone, PartialEq, Eq)]
+/// pub struct Packet {
+///     channel: B4,
+///     sequence: B12,
+/// }
+///
+/// let mut packets = [
+///     Packet::new().with_channel(1).with_sequence(7),
+///     Packet::new().with_channel(9).with_sequence(3),
+/// ];
+/// packets.sort();
+/// assert_eq!(packets[0].sequence(), 3);
+/// assert_eq!(packets[1].sequence(), 7);
+/// ```
+///
+/// ## Parameter: `cell: str`
+///
+/// If `cell` is `"volatile"` the bytes of the `#[bitfield]` struct are stored in a
+/// `vcell::VolatileCell` and all accessors take `&self` and perform volatile reads and
+/// writes of the whole cell. This makes the type directly usable inside peripheral register
+/// block structs. Requires the `vcell` crate feature.
+///
+/// Instead of `as_bytes` and `as_bytes_mut` such bitfields provide `get_bytes` and `set_bytes`.
+/// The parameter conflicts with `views`, `ptr_view`, `atomic`, `reader_writer`, `validated`,
+/// `check_reserved`, `reinterpret`, `as_ref`, `bit_ops`, `#[repr(uN)]` and `#[derive(BitfieldSpecifier)]`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(cell = "volatile")]
+/// pub struct Control {
+///     enable: bool,
+///     prescaler: B7,
+/// }
+///
+/// #[repr(C)]
+/// pub struct RegisterBlock {
+///     control: Control,
+/// }
+///
+/// let block = RegisterBlock { control: Control::new() };
+/// block.control.set_prescaler(42);
+/// assert_eq!(block.control.prescaler(), 42);
+/// ```
+///
+/// ## Parameter: `no_panic: bool`
+///
+/// If `no_panic` is `true` the `#[bitfield]` macro does not generate any accessors that may
+/// panic. This omits the unchecked getters `foo()`, the setters `set_foo`, `with_foo`,
+/// `set_foo_from` and `with_foo_from` as well as the `bit` and `set_bit` methods so that only
+/// the fallible `foo_or_err`, `set_foo_checked` and `with_foo_checked` variants remain.
+/// Writing just `no_panic` is a shorthand for `no_panic = true`.
+///
+/// The parameter conflicts with `views`, `ptr_view`, `atomic`, `reader_writer` and `cell`.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(no_panic)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// let mut package = Package::new().with_status_checked(3).unwrap();
+/// assert!(package.set_is_alive_checked(true).is_ok());
+/// assert!(package.set_status_checked(0xFF).is_err());
+/// assert_eq!(package.status_or_err(), Ok(3));
+/// ```
+///
+/// ## Parameter: `reinterpret: str`
+///
+/// Declares other `#[bitfield]` structs that provide alternate views of the same bytes,
+/// e.g. the read and the write layout of a hardware register sharing a single address.
+/// For every listed type `T` the conversion `From<T> for Self` is generated which reinterprets
+/// the underlying bytes without any checks. For conversions in both directions both bitfields
+/// list each other.
+///
+/// All involved bitfields must have the same size in bytes which is checked at compile time.
+/// The parameter requires infallible byte conversions and thus conflicts with `filled = false`.
+/// The listed bitfields themselves may be unfilled.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(reinterpret = "StatusWrite")]
+/// pub struct StatusRead {
+///     ready: bool,
+///     error: bool,
+///     count: B6,
+/// }
+///
+/// #[bitfield(reinterpret = "StatusRead")]
+/// pub struct StatusWrite {
+///     start: bool,
+///     reset: bool,
+///     #[skip] __: B6,
+/// }
+///
+/// let write = StatusWrite::new().with_start(true);
+/// let read = StatusRead::from(write);
+/// assert!(read.ready());
+/// assert!(!read.error());
+/// assert_eq!(StatusWrite::from(read).into_bytes(), [0b0000_0001]);
+/// ```
+///
+/// ## Field Parameter: `#[bits = N]`
+///
+/// To ensure at compile time that a field of a `#[bitfield]` struct has a bit width of exactly
+/// `N` a user may add `#[bits = N]` to the field in question.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # #[bitfield(filled = false)]
+/// # #[derive(BitfieldSpecifier)]
+/// # pub struct Header {
+/// #     is_received: bool, // 1 bit
+/// #     is_alive: bool,    // 1 bit
+/// #     status: B2,        // 2 bits
+/// # }
+/// #[bitfield]
+/// pub struct Base {
+///     #[bits = 4]
+///     header: Header, //  4 bits
+///     content: B28,   // 28 bits
+/// }
+/// ```
+///
+/// On fields of primitive integer types `#[bits = N]` may also narrow the field down to its
+/// `N` least significant bits. The accessors keep using the primitive type while the setters
+/// reject values that do not fit into `N` bits.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Narrow {
+///     #[bits = 3]
+///     small: u8,  //  3 bits
+///     #[bits = 13]
+///     large: u32, // 13 bits
+/// }
+///
+/// let mut narrow = Narrow::new().with_small(5);
+/// assert_eq!(narrow.small(), 5_u8);
+/// assert!(narrow.set_small_checked(8).is_err());
+/// ```
+///
+/// ## Field Parameter: `#[skip(..)]`
+///
+/// It is possible to skip the entire code generation for getters or setters with the `#[skip]`
+/// field attribute.
+/// This is useful if a field just needs to be read or written exclusively. Skipping both
+/// setters and getters is useful if you want to have undefined blocks within your bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Sparse {
+///     #[skip(getters)]
+///     no_getters: B4,
+///     #[skip(setters)]
+///     no_setters: B4,
+///     #[skip]
+///     skipped_entirely: B4,
+///     #[skip(getters, setters)]
+///     skipped_entirely_2: B2,
+///     #[skip(getters)] #[skip(setters)]
+///     skipped_entirely_2: B2,
+/// }
+/// ```
+///
+/// ### Trick: Wildcards
+///
+/// If you are completely uninterested in a field of a bitfield, for example when specifying
+/// some undefined bits in your bitfield you can use double wildcards as their names.
+/// Fields named only with underscores are anonymous padding that is implicitly `#[skip]`
+/// and may appear any number of times. Note that Rust does not accept a single `_` as field name.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Sparse {
+///     __: B10,
+///     a: bool,
+///     #[skip] __: B10,
+///     b: bool,
+///     ___: B10,
+/// }
+/// ```
+///
+/// ## Field Parameter: `#[inline(..)]`
+///
+/// Controls the `#[inline]` annotations of the generated accessors of a single field.
+/// Supported are `#[inline]`, `#[inline(always)]` and `#[inline(never)]` which take
+/// precedence over the `inline` parameter of the `#[bitfield]` struct.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(inline = "never")]
+/// pub struct Register {
+///     #[inline(always)]
+///     hot: B4,
+///     cold: B4,
+/// }
+/// ```
+///
+/// ## Field Parameter: `#[access(..)]`
+///
+/// Declares the hardware access semantics of a field so that only its legal accessors are generated.
+///
+/// - `#[access(read_write)]`: The default. Generates getters and setters.
+/// - `#[access(read_only)]`: Generates only getters.
+/// - `#[access(write_only)]`: Generates only setters. The field is not printed by `Debug`.
+/// - `#[access(w1c)]`: Generates getters and a `clear_f()` method instead of setters.
+/// - `#[access(w1s)]`: Generates getters and a `set_f()` method instead of setters.
+///
+/// The `clear_f()` and `set_f()` methods of write one to clear and write one to set fields
+/// write all ones to the field and zeros to all other write one fields so that writing back
+/// the bytes does not trigger the actions of sibling fields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Interrupts {
+///     #[access(read_only)]
+///     pending: bool,
+///     #[access(w1c)]
+///     overflow: bool,
+///     #[access(w1c)]
+///     underflow: bool,
+///     #[access(w1s)]
+///     trigger: bool,
+///     enable: B4,
+/// }
 ///
-///     1. `f()`: Returns the value of `f` and might panic
-///        if the value contains an invalid bit pattern.
-///     2. `f_or_err()`: Returns the value of `f` or an error
-///        if the value contains an invalid bit pattern.
+/// let mut interrupts = Interrupts::from_bytes([0b0000_0110]);
+/// interrupts.clear_overflow();
+/// assert_eq!(interrupts.into_bytes(), [0b0000_0010]);
+/// ```
 ///
-/// - **Setters:**
+/// ## Field Parameter: `#[validate(..)]`
 ///
-///     For every field `f` we generate the following setters:
+/// Runs the given function with a reference to the new value of a field inside its checked
+/// setter and with the decoded value inside `try_from_bytes`. The function must return a
+/// `Result<(), E>` where `E` is given by `#[validate(path, error = "E")]` and defaults to `()`.
 ///
-///     1. `set_f(new_value)`: Sets the value of `f` to `new_value` and might panic
-///        if `new_value` is out of bounds for the bit width of `f`.
-///     2. `set_f_checked(new_value)`: Sets the value of `f` to `new` or returns an error
-///        if `new_value` if out of bounds for the bit width of `f`.
-///     3. `with_f(new_value)`: Similar to `set_f` but consumes and returns `Self`.
-///        Primarily useful for method chaining.
-///     4. `with_f_checked(new_value)`: Similar to `set_f_checked` but consumes and returns `Self`.
-///        Primarily useful for method chaining.
+/// The checked setters of the field return a `ValidateError<E>` that carries the error of
+/// the function as `ValidateError::Invalid`. `try_from_bytes` reports rejected values as
+/// `DecodeError::InvalidValue`. The panicking setters require `E` to implement `Debug`.
 ///
-/// - **Conversions:**
+/// ### Example
 ///
-///     - `from_bytes(bytes)`: Allows to constructor the bitfield type from a fixed array of bytes.
-///     - `into_bytes()`: Allows to convert the bitfield into its underlying byte representation.
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::error::ValidateError;
 ///
-/// # Parameters
+/// #[derive(Debug, PartialEq)]
+/// pub struct NotAMonth(u8);
 ///
-/// The following parameters for the `#[bitfield]` macro are supported:
+/// fn is_month(month: &u8) -> Result<(), NotAMonth> {
+///     match (1..=12).contains(month) {
+///         true => Ok(()),
+///         false => Err(NotAMonth(*month)),
+///     }
+/// }
 ///
-/// ## Parameter: `bytes = N`
+/// #[bitfield]
+/// pub struct Date {
+///     #[validate(is_month, error = "NotAMonth")]
+///     month: B4,
+///     day: B5,
+///     year: B7,
+/// }
 ///
-/// This ensures at compilation time that the resulting `#[bitfield]` struct consists of
-/// exactly `N` bytes. Yield a compilation error if this does not hold true.
+/// let mut date = Date::new();
+/// assert!(date.set_month_checked(12).is_ok());
+/// assert_eq!(date.set_month_checked(13), Err(ValidateError::Invalid(NotAMonth(13))));
+/// assert!(Date::try_from_bytes([0x00, 0x00]).is_err());
+/// ```
+///
+/// ## Field Parameter: `#[on_overflow = ".."]`
+///
+/// Selects what the plain setters `set_f`, `with_f`, `set_f_from` and `with_f_from` of a field
+/// do with values that do not fit into the bits of the field. The checked setters are unaffected.
+///
+/// - `#[on_overflow = "error"]`: The default. The setters panic.
+/// - `#[on_overflow = "saturate"]`: The value is clamped to the maximum value of the field.
+/// - `#[on_overflow = "truncate"]`: The bits of the value that do not fit into the field are dropped.
+///
+/// Saturation and truncation are only supported for `B1` to `B128` and `u8` to `u128` fields
+/// and rejected with a compile error for all other fields.
 ///
 /// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// #[bitfield(bytes = 2)]
-/// pub struct SingedInt {
-///     sign: bool, //  1 bit
-///     value: B15, // 15 bits
+/// #[bitfield]
+/// pub struct Levels {
+///     #[on_overflow = "saturate"]
+///     volume: B4,
+///     #[on_overflow = "truncate"]
+///     counter: B4,
 /// }
-/// ```
 ///
-/// ## Parameter: `filled: bool`
+/// let levels = Levels::new().with_volume(100).with_counter(0x1F);
+/// assert_eq!(levels.volume(), 0xF);
+/// assert_eq!(levels.counter(), 0xF);
+/// let levels = levels.with_counter(0x12);
+/// assert_eq!(levels.counter(), 0x2);
+/// ```
 ///
-/// If `filled` is `true` ensures that the `#[bitfield]` struct defines all bits and
-/// therefore has a bitwidth that is divisible by 8. If `filled` is `false` ensures the
-/// exact opposite.
+/// ## Field Parameter: `#[overlaps(f)]`
 ///
-/// The default value is: `true`
+/// Places the annotated field at the bit offset of the previously declared field `f`
+/// instead of after the previous field. The annotated field aliases the bits of `f` and
+/// possibly of the following fields and does not occupy any bits of its own.
+/// It must end within the bits of the bitfield which is checked at compile time.
 ///
 /// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// #[bitfield(filled = false)]
-/// pub struct Package {
-///     is_received: bool, // 1 bit
-///     is_alive: bool,    // 1 bit
-///     status: B2,        // 2 bits
+/// #[bitfield]
+/// pub struct Register {
+///     lo: u8,
+///     hi: u8,
+///     #[overlaps(lo)]
+///     word: u16,
 /// }
+///
+/// let mut register = Register::new().with_lo(0x34).with_hi(0x12);
+/// assert_eq!(register.word(), 0x1234);
+/// register.set_word(0xABCD);
+/// assert_eq!(register.lo(), 0xCD);
+/// assert_eq!(register.hi(), 0xAB);
 /// ```
 ///
-/// ## Parameter: `bits = N`
+/// ## Field Parameter: `#[variant(tag = "t", N => A, M => B, ..)]`
 ///
-/// With the `bits: int` parameter it is possible to control the targeted bit width of
-/// a `#[bitfield]` annoated struct. Using `bits = N` guarantees that the resulting bitfield
-/// struct will have a bit width of exactly `N`.
+/// Interprets the bits of the annotated field by one of several sub-layouts depending on the
+/// value of the tag field `t`. For every variant `N => A` where `A` is a bit specifier with at most
+/// as many bits as the field, the following accessors are generated:
 ///
-/// ### Example 1
+/// - `a()` returns `Some` value of `A` read from the bits of the field if `t` is `N`
+///   and `None` otherwise or if the bits are an invalid bit pattern for `A`.
+/// - `set_a(value)` sets `t` to `N` and the field to the given value of `A`.
+///   Bits of the field that are not covered by `A` are set to zero.
+///
+/// The accessor names are the `snake_case` names of the bit specifiers. The tag values are
+/// compared with the raw bits of the tag field. The accessors are not generated for bitfields with
+/// `cell = "volatile"` and the setters are not generated for bitfields with `no_panic = true`.
+///
+/// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// #[bitfield(bits = 16)]
-/// pub struct Package {
-///     is_received: bool, // 1 bit
-///     is_alive: bool,    // 1 bit
-///     status: B14,       // 14 bits
+/// #[bitfield(bits = 12)]
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// pub struct Immediate {
+///     rd: B4,
+///     imm: B8,
+/// }
+///
+/// #[bitfield(bits = 12)]
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// pub struct Registers {
+///     rd: B4,
+///     rs1: B4,
+///     rs2: B4,
 /// }
+///
+/// #[bitfield]
+/// pub struct Instruction {
+///     opcode: B4,
+///     #[variant(tag = "opcode", 0 => Immediate, 1 => Registers)]
+///     operands: B12,
+/// }
+///
+/// let mut instruction = Instruction::new();
+/// instruction.set_registers(Registers::new().with_rd(1).with_rs1(2).with_rs2(3));
+/// assert_eq!(instruction.opcode(), 1);
+/// assert_eq!(instruction.immediate(), None);
+/// assert_eq!(instruction.registers().map(|registers| registers.rs2()), Some(3));
 /// ```
 ///
-/// ### Example 2
+/// ## Field Parameter: `#[flatten(b: B, ..)]`
 ///
-/// The `bits: int` parameter is especially useful when using this in conjunction with
-/// `#[derive(BitfieldSpecifier)] and `filled = false` as shown in the below example.
+/// Re-exports the accessors of the listed fields of a nested bitfield on the parent bitfield
+/// while keeping the packed layout. For a listed field `b` with bit specifier `B` of the nested
+/// bitfield stored in field `a` the parent provides `a_b`, `set_a_b` and `with_a_b`.
+/// The fields and their bit specifiers need to be listed since the `#[bitfield]` macro
+/// cannot see the definition of the nested bitfield.
+///
+/// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// #[bitfield(bits = 5)]
+/// #[bitfield(bits = 8)]
 /// #[derive(BitfieldSpecifier)]
-/// pub struct Package {
-///     is_received: bool, // 1 bit
-///     is_alive: bool,    // 1 bit
-///     status: B3,        // 3 bits
+/// pub struct Flags {
+///     ready: bool,
+///     level: B7,
+/// }
+///
+/// #[bitfield]
+/// pub struct Header {
+///     #[flatten(ready: bool, level: B7)]
+///     flags: Flags,
+///     length: u8,
 /// }
+///
+/// let header = Header::new().with_flags_level(42);
+/// assert_eq!(header.flags_level(), 42);
+/// assert!(!header.flags_ready());
 /// ```
 ///
-/// ## Field Parameter: `#[bits = N]`
+/// ## Field Parameter: `#[flags]`
 ///
-/// To ensure at compile time that a field of a `#[bitfield]` struct has a bit width of exactly
-/// `N` a user may add `#[bits = N]` to the field in question.
+/// Marks a contiguous run of `bool` fields as a flags group. For a `#[bitfield]` struct `Foo`
+/// this generates a `FooFlags` type similar to the types of the `bitflags` crate with an
+/// associated constant per flag, `contains`, `insert` and `remove` as well as the `|`, `&`
+/// and `!` operators. The bitfield provides `flag_group`, `set_flag_group` and
+/// `with_flag_group` and converts from and to `FooFlags` via `From`.
+///
+/// The `#[flags]` fields must be named, must not overlap other fields and must be declared
+/// next to each other. Not supported for bitfields with `cell = "volatile"`.
 ///
 /// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// # #[bitfield(filled = false)]
-/// # #[derive(BitfieldSpecifier)]
-/// # pub struct Header {
-/// #     is_received: bool, // 1 bit
-/// #     is_alive: bool,    // 1 bit
-/// #     status: B2,        // 2 bits
-/// # }
 /// #[bitfield]
-/// pub struct Base {
-///     #[bits = 4]
-///     header: Header, //  4 bits
-///     content: B28,   // 28 bits
+/// pub struct Interrupt {
+///     vector: B5,
+///     #[flags] timer: bool,
+///     #[flags] uart: bool,
+///     #[flags] dma: bool,
 /// }
+///
+/// let irq = Interrupt::new().with_flag_group(InterruptFlags::TIMER | InterruptFlags::DMA);
+/// assert!(irq.timer() && !irq.uart() && irq.dma());
+/// assert!(irq.flag_group().contains(InterruptFlags::DMA));
+/// assert_eq!(irq.into_bytes(), [0b1010_0000]);
 /// ```
 ///
-/// ## Field Parameter: `#[skip(..)]`
+/// ## Field Parameter: `#[group = ".."]`
 ///
-/// It is possible to skip the entire code generation for getters or setters with the `#[skip]`
-/// field attribute.
-/// This is useful if a field just needs to be read or written exclusively. Skipping both
-/// setters and getters is useful if you want to have undefined blocks within your bitfields.
+/// Adds the field to the named group of fields. For every group `g` the `#[bitfield]` struct
+/// provides a constant `G_MASK` with all bits of the fields of the group set as well as
+/// `clear_group_g` which resets all fields of the group at once.
+/// Additionally `read_group_g` reads the bits covering all fields of the group at once and
+/// returns their values as a tuple in declaration order. This is faster than calling the getters
+/// of adjacent small fields one by one. It is only generated if all fields of the group have
+/// getters and no `#[cfg(..)]` or `#[overlaps(..)]` attributes and if the bitfield does not
+/// use `no_panic = true`, and the fields must be within 128 bits.
+/// Not supported for bitfields with `cell = "volatile"`.
 ///
 /// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
 /// #[bitfield]
-/// pub struct Sparse {
-///     #[skip(getters)]
-///     no_getters: B4,
-///     #[skip(setters)]
-///     no_setters: B4,
-///     #[skip]
-///     skipped_entirely: B4,
-///     #[skip(getters, setters)]
-///     skipped_entirely_2: B2,
-///     #[skip(getters)] #[skip(setters)]
-///     skipped_entirely_2: B2,
+/// pub struct Status {
+///     #[group = "irq"] timer: bool,
+///     enabled: bool,
+///     #[group = "irq"] uart: bool,
+///     #[group = "irq"] dma: B5,
 /// }
+///
+/// assert_eq!(Status::IRQ_MASK, [0b1111_1101]);
+/// let mut status = Status::new().with_timer(true).with_enabled(true).with_dma(3);
+/// assert_eq!(status.read_group_irq(), (true, false, 3));
+/// status.clear_group_irq();
+/// assert_eq!(status.into_bytes(), [0b0000_0010]);
 /// ```
 ///
-/// ### Trick: Wildcards
+/// ## Field Parameter: `#[marker]`
 ///
-/// If you are completely uninterested in a field of a bitfield, for example when specifying
-/// some undefined bits in your bitfield you can use double wildcards as their names:
+/// Fields of type `PhantomData<T>` are not part of the bit layout and are kept as is in the
+/// generated struct. Fields of other zero-sized unit struct types can be excluded from the bit
+/// layout the same way by annotating them with `#[marker]`.
+/// This allows to brand bitfields with types, e.g. to distinguish the registers of different ports.
+///
+/// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
+/// use core::marker::PhantomData;
+///
+/// #[derive(Debug, Copy, Clone)]
+/// pub struct PortA;
+///
 /// #[bitfield]
-/// pub struct Sparse {
-///     #[skip] __: B10,
-///     a: bool,
-///     #[skip] __: B10,
-///     b: bool,
-///     #[skip] __: B10,
+/// pub struct Reg {
+///     enabled: bool,
+///     mode: B7,
+///     #[marker]
+///     port: PortA,
+///     not_send: PhantomData<*const ()>,
 /// }
+///
+/// let reg = Reg::new().with_mode(5);
+/// assert_eq!(core::mem::size_of::<Reg>(), 1);
+/// assert_eq!(reg.into_bytes(), [0b0000_1010]);
 /// ```
 ///
 /// # Features
@@ -271,6 +1590,36 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// );
 /// ```
 ///
+/// ## Support: `extern "C"`
+///
+/// Unless another `#[repr(..)]` such as `#[repr(transparent)]` is given the generated struct is
+/// `#[repr(C)]` with its byte array as only non zero-sized field. It is asserted at compile time
+/// that its size equals the number of bytes of the bitfield and that its alignment is 1.
+/// Therefore the struct can be passed by value across `extern "C"` boundaries where it
+/// corresponds to a C struct holding an `uint8_t` array of the same length.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Status {
+///     ready: bool,
+///     error: B7,
+///     count: B8,
+/// }
+///
+/// // struct Status { uint8_t bytes[2]; };
+/// // struct Status firmware_status(struct Status previous);
+/// pub extern "C" fn firmware_status(previous: Status) -> Status {
+///     let count = previous.count() + 1;
+///     previous.with_count(count)
+/// }
+///
+/// assert_eq!(core::mem::size_of::<Status>(), 2);
+/// assert_eq!(firmware_status(Status::new()).count(), 1);
+/// ```
+///
 /// ## Support: `#[repr(uN)]`
 ///
 /// It is possible to additionally annotate a `#[bitfield]` annotated struct with `#[repr(uN)]`
@@ -281,6 +1630,9 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// and the bitfield as well as ensuring at compile time that the bit width of the bitfield struct
 /// matches the bit width of the primitive.
 ///
+/// Additionally `count_ones`, `count_zeros`, `leading_zeros` and `trailing_zeros` are generated
+/// which delegate to the respective methods of the primitive.
+///
 /// ### Example
 ///
 /// ```
@@ -295,13 +1647,246 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// let sint = SignedU16::from(0b0111_0001);
 /// assert_eq!(sint.sign(), true);
 /// assert_eq!(sint.abs_value(), 0b0011_1000);
+/// assert_eq!(sint.count_ones(), 4);
+/// assert_eq!(sint.trailing_zeros(), 0);
+/// assert_eq!(sint.leading_zeros(), 9);
 /// assert_eq!(u16::from(sint), 0b0111_0001_u16);
 /// ```
+///
+/// ### Oversized Primitives
+///
+/// The primitive may also have more bits than the bitfield, e.g. `#[repr(u32)]` for a 20-bit
+/// bitfield. In this case `From<Bitfield> for uN` zero-extends the bitfield while the conversion
+/// from the primitive becomes a `TryFrom` implementation that rejects values with bits set beyond
+/// the bits of the bitfield. The bit counting methods only count the bits of the bitfield.
+/// The primitive must be the narrowest one the bitfield fits into, so `#[repr(u64)]` is not
+/// allowed for a 32-bit bitfield.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # use core::convert::TryFrom;
+/// #[bitfield(filled = false)]
+/// #[repr(u32)]
+/// pub struct Instruction {
+///     opcode: B4,
+///     operand: B16,
+/// }
+///
+/// let instr = Instruction::try_from(0x0001_2345_u32).unwrap();
+/// assert_eq!(instr.opcode(), 0x5);
+/// assert_eq!(instr.operand(), 0x1234);
+/// assert_eq!(instr.leading_zeros(), 3);
+/// assert_eq!(u32::from(instr), 0x0001_2345);
+/// assert!(Instruction::try_from(0x0010_0000_u32).is_err());
+/// ```
+///
+/// ## Support: `#[bitfield]` unions
+///
+/// Applied to a `union` the `#[bitfield]` macro generates a struct storing the bytes shared by all
+/// members where every member is a `#[bitfield]` type describing an alternate layout of the same
+/// bytes. For every member `m` the getters `m()` and `m_or_err()` view the bytes as the member
+/// while `set_m(value)` and `with_m(value)` overwrite all bytes with the given member.
+/// It is a compile time error if the members differ in size.
+///
+/// This is useful for registers whose meaning depends on a mode bit held elsewhere.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Normal {
+///     value: B12,
+///     mode: B4,
+/// }
+///
+/// #[bitfield]
+/// pub struct Extended {
+///     low: B8,
+///     high: B8,
+/// }
+///
+/// #[bitfield]
+/// pub union Register {
+///     normal: Normal,
+///     extended: Extended,
+/// }
+///
+/// let register = Register::new().with_normal(Normal::new().with_value(0x321));
+/// assert_eq!(register.extended().low(), 0x21);
+/// assert_eq!(register.extended().high(), 0x03);
+/// assert_eq!(register.into_bytes(), [0x21, 0x03]);
+/// ```
+///
+/// ## Support: `#[cfg(..)]` on fields
+///
+/// Fields annotated with `#[cfg(..)]` only occupy bits if they are enabled. The size of the
+/// bitfield, the offsets of all later fields as well as the generated accessors respect the
+/// conditional compilation consistently. Disabled fields are reported with a width of 0 by `LAYOUT`.
+///
+/// The type of a conditional field must be available in all configurations.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Header {
+///     kind: B4,
+///     #[cfg(feature = "checksums")]
+///     checksum: B8,
+///     length: B4,
+/// }
+///
+/// # #[cfg(not(feature = "checksums"))]
+/// assert_eq!(Header::new().with_length(0xF).into_bytes(), [0xF0]);
+/// ```
 #[proc_macro_attribute]
 pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
     bitfield::analyse_and_expand(args.into(), input.into()).into()
 }
 
+/// Slices an integer into named bit ranges without declaring a `#[bitfield]` struct.
+///
+/// Takes an unsigned integer type and a list of fields with their bit widths and evaluates
+/// to the constructor of an anonymous type wrapping a value of the integer type.
+/// Just like for `#[bitfield]` structs the first field occupies the least significant bits.
+///
+/// The anonymous type provides `from_bits` and `into_bits` as well as a getter `f`
+/// and the setters `set_f` and `with_f` for every field `f`. Getters return the smallest
+/// unsigned integer type that fits the bits of the field and setters panic for out of
+/// bounds values. The type also implements `Copy`, `Clone`, `PartialEq`, `Eq` and `Debug`.
+///
+/// The macro is not called `bitfield!` since function-like macros share their namespace
+/// with the `#[bitfield]` attribute.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::prelude::*;
+///
+/// let insn = bitfield_layout! { u32 => { imm: 16, rt: 5, rs: 5, opcode: 6 } };
+/// let addiu = insn(0x2508_0004);
+/// assert_eq!(addiu.opcode(), 0x09);
+/// assert_eq!(addiu.rs(), 8);
+/// assert_eq!(addiu.rt(), 8);
+/// assert_eq!(addiu.imm(), 4);
+/// assert_eq!(addiu.with_imm(8).into_bits(), 0x2508_0008);
+/// ```
+#[proc_macro]
+pub fn bitfield_layout(input: TokenStream) -> TokenStream {
+    bitfield_layout::analyse_and_expand(input.into()).into()
+}
+
+/// Generates `#[bitfield]` structs from a CMSIS-SVD register description.
+///
+/// Takes a string literal with one or more `<register>` elements, e.g. copied from the
+/// `<registers>` of an SVD file, and expands every register into a `#[bitfield]` struct
+/// with `bits` set to the `<size>` of the register (32 if unset).
+///
+/// - The struct is named after the register in `CamelCase` and its fields are named
+///   after the register fields in lower case. Names that are Rust keywords become raw identifiers.
+/// - Fields with a single bit are of type `bool`, all other fields of type `BN`.
+///   Bit positions are taken from either `<bitOffset>` and `<bitWidth>`, `<lsb>` and `<msb>`
+///   or `<bitRange>`.
+/// - `read-only` fields are generated with `#[skip(setters)]` and `write-only` fields
+///   with `#[skip(getters)]`.
+/// - Bits not covered by any field are reserved with anonymous `__` padding fields.
+/// - `<description>` elements turn into doc comments.
+///
+/// The structs derive `Debug`, `Copy`, `Clone`, `PartialEq` and `Eq`.
+/// Register arrays with `<dim>` are not supported.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::prelude::*;
+///
+/// svd_registers!(r#"
+///     <register>
+///         <name>CR1</name>
+///         <description>Control register 1</description>
+///         <size>16</size>
+///         <fields>
+///             <field>
+///                 <name>EN</name>
+///                 <bitOffset>0</bitOffset>
+///                 <bitWidth>1</bitWidth>
+///             </field>
+///             <field>
+///                 <name>MODE</name>
+///                 <bitRange>[5:2]</bitRange>
+///                 <access>read-write</access>
+///             </field>
+///             <field>
+///                 <name>BUSY</name>
+///                 <lsb>15</lsb>
+///                 <msb>15</msb>
+///                 <access>read-only</access>
+///             </field>
+///         </fields>
+///     </register>
+/// "#);
+///
+/// let cr1 = Cr1::from_bytes([0b0001_0101, 0b1000_0000]);
+/// assert!(cr1.en());
+/// assert_eq!(cr1.mode(), 0b0101);
+/// assert!(cr1.busy());
+/// ```
+#[proc_macro]
+pub fn svd_registers(input: TokenStream) -> TokenStream {
+    svd_registers::analyse_and_expand(input.into()).into()
+}
+
+/// Applicable to traits that are implemented by `#[bitfield]` structs through their
+/// `impl_trait` parameter.
+///
+/// Every required method of the trait is implemented by forwarding to the generated
+/// accessor (or any other inherent method) of the same name of the `#[bitfield]` struct.
+/// Provided methods keep their default implementation.
+/// All methods of the trait must have a `self` receiver and the trait must not be generic
+/// or have associated types or constants.
+///
+/// The trait can be used by `#[bitfield]` structs of the same crate only.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::prelude::*;
+///
+/// #[bitfield_trait]
+/// pub trait Enable {
+///     fn enabled(&self) -> bool;
+///     fn set_enabled(&mut self, new_val: bool);
+///
+///     fn toggle(&mut self) {
+///         self.set_enabled(!self.enabled());
+///     }
+/// }
+///
+/// #[bitfield(impl_trait = "Enable")]
+/// pub struct Control {
+///     enabled: bool,
+///     mode: B7,
+/// }
+///
+/// fn enable<T: Enable>(register: &mut T) {
+///     register.set_enabled(true);
+/// }
+///
+/// fn main() {
+///     let mut control = Control::new();
+///     enable(&mut control);
+///     assert!(control.enabled());
+///     control.toggle();
+///     assert!(!control.enabled());
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn bitfield_trait(args: TokenStream, input: TokenStream) -> TokenStream {
+    bitfield_trait::analyse_and_expand(args.into(), input.into()).into()
+}
+
 /// Derive macro for Rust `enums` to implement `Specifier` trait.
 ///
 /// This allows such an enum to be used as a field of a `#[bitfield]` struct.
@@ -343,6 +1928,22 @@ pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// If `#[bits = N]` is more than one bit wider than required by the discriminants of the
+/// variants a warning listing the bit patterns that no variant matches is reported through the
+/// `deprecated` lint. It can be turned into an error with `#[deny(deprecated)]` or silenced with
+/// `#[allow(deprecated)]` on the enum, e.g. for bits that are reserved for future variants.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #
+/// #[derive(BitfieldSpecifier)]
+/// #[bits = 4]
+/// #[allow(deprecated)] // Reserved for more modes.
+/// pub enum Mode {
+///     Off, Slow, Fast
+/// }
+/// ```
+///
 /// ## Example: Discriminants
 ///
 /// It is possible to explicitly assign discriminants to some of the days.
@@ -364,6 +1965,32 @@ pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Example: Signed Discriminants
+///
+/// Enums with a signed `#[repr(iN)]` may have negative discriminants which are
+/// stored in two's complement within the bits of the specifier.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[repr(i8)]
+/// pub enum Trim {
+///     Minus2 = -2,
+///     Minus1 = -1,
+///     Zero = 0,
+///     Plus1 = 1,
+/// }
+///
+/// #[bitfield]
+/// pub struct Calibration {
+///     trim: Trim,
+///     rest: B6,
+/// }
+///
+/// let calibration = Calibration::new().with_trim(Trim::Minus1);
+/// assert_eq!(calibration.into_bytes(), [0b11]);
+/// ```
+///
 /// ## Example: Use in `#[bitfield]`
 ///
 /// Given the above `Weekday` enum that starts at `Sunday` and uses 3 bits in total
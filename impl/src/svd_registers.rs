@@ -0,0 +1,341 @@
+use proc_macro2::{
+    Literal,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+
+/// Analyses the given CMSIS-SVD snippet of the `svd_registers!` macro and expands
+/// every `<register>` element into a `#[bitfield]` struct.
+pub fn analyse_and_expand(input: TokenStream2) -> TokenStream2 {
+    match analyse_and_expand_or_error(input) {
+        Ok(output) => output,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+/// A `<register>` element of an SVD snippet.
+struct Register {
+    name: String,
+    description: Option<String>,
+    size: usize,
+    fields: Vec<Field>,
+}
+
+/// A `<field>` element of an SVD register.
+struct Field {
+    name: String,
+    description: Option<String>,
+    offset: usize,
+    width: usize,
+    access: Access,
+}
+
+/// The `<access>` of an SVD register field.
+#[derive(Copy, Clone)]
+enum Access {
+    ReadWrite,
+    ReadOnly,
+    WriteOnly,
+}
+
+fn analyse_and_expand_or_error(input: TokenStream2) -> syn::Result<TokenStream2> {
+    let lit_str = syn::parse2::<syn::LitStr>(input)?;
+    let svd = strip_comments(&lit_str.value());
+    let registers = elements(&svd, "register")
+        .into_iter()
+        .map(Register::parse)
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(|err| format_err_spanned!(lit_str, "encountered invalid SVD register: {}", err))?;
+    if registers.is_empty() {
+        return Err(format_err_spanned!(
+            lit_str,
+            "expected at least one `<register>` element in SVD snippet"
+        ))
+    }
+    let span = lit_str.span();
+    let structs = registers
+        .iter()
+        .map(|register| register.expand(span))
+        .collect::<Vec<_>>();
+    Ok(quote! { #( #structs )* })
+}
+
+impl Register {
+    /// Parses the contents of a `<register>` element.
+    fn parse(xml: &str) -> Result<Self, String> {
+        let own = without_elements(xml, "fields");
+        let fields = elements(xml, "fields")
+            .into_iter()
+            .flat_map(|fields| elements(fields, "field"))
+            .collect::<Vec<_>>();
+        let name = child_text(&own, "name").ok_or("missing `<name>` of register")?;
+        if child_text(&own, "dim").is_some() {
+            return Err(format!("register arrays are not supported for {}", name))
+        }
+        let description = child_text(&own, "description");
+        let size = match child_text(&own, "size") {
+            Some(size) => parse_number(&size)?,
+            None => 32,
+        };
+        if size == 0 || size > 128 {
+            return Err(format!("expected a size of 1 to 128 bits for {} but found {}", name, size))
+        }
+        let mut fields = fields
+            .into_iter()
+            .map(|field| Field::parse(&name, field))
+            .collect::<Result<Vec<_>, _>>()?;
+        fields.sort_by_key(|field| field.offset);
+        let mut end = 0;
+        for field in &fields {
+            if field.offset < end {
+                return Err(format!("field {}.{} overlaps with a previous field", name, field.name))
+            }
+            end = field.offset + field.width;
+        }
+        if end > size {
+            return Err(format!(
+                "fields of {} require {} bits but the register has only {} bits",
+                name, end, size
+            ))
+        }
+        Ok(Self {
+            name,
+            description,
+            size,
+            fields,
+        })
+    }
+
+    /// Expands the register into a `#[bitfield]` struct filling gaps with reserved fields.
+    fn expand(&self, span: proc_macro2::Span) -> TokenStream2 {
+        let ident = rust_ident(&camel_case(&self.name), span);
+        let docs = self.description.as_ref().map(|docs| quote_spanned!(span=> #[doc = #docs]));
+        let size = Literal::usize_unsuffixed(self.size);
+        let mut offset = 0;
+        let mut fields = Vec::new();
+        for field in &self.fields {
+            if field.offset > offset {
+                fields.push(reserved_field(field.offset - offset, span));
+            }
+            fields.push(field.expand(span));
+            offset = field.offset + field.width;
+        }
+        if self.size > offset {
+            fields.push(reserved_field(self.size - offset, span));
+        }
+        quote_spanned!(span=>
+            #docs
+            #[::modular_bitfield::bitfield(bits = #size)]
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub struct #ident {
+                #( #fields )*
+            }
+        )
+    }
+}
+
+impl Field {
+    /// Parses the contents of a `<field>` element of the given register.
+    fn parse(register: &str, xml: &str) -> Result<Self, String> {
+        let xml = &without_elements(xml, "enumeratedValues");
+        let name = child_text(xml, "name")
+            .ok_or_else(|| format!("missing `<name>` of field in {}", register))?;
+        let number = |tag: &str| child_text(xml, tag).map(|text| parse_number(&text)).transpose();
+        let (offset, width) = match (
+            number("bitOffset")?,
+            number("bitWidth")?,
+            number("lsb")?,
+            number("msb")?,
+            child_text(xml, "bitRange"),
+        ) {
+            (Some(offset), Some(width), _, _, _) => (offset, width),
+            (_, _, Some(lsb), Some(msb), _) if msb >= lsb => (lsb, msb - lsb + 1),
+            (_, _, _, _, Some(range)) => parse_bit_range(&range)?,
+            _ => {
+                return Err(format!(
+                    "missing or invalid bit range of field {}.{}",
+                    register, name
+                ))
+            }
+        };
+        if width == 0 {
+            return Err(format!("field {}.{} has no bits", register, name))
+        }
+        let access = match child_text(xml, "access").as_deref() {
+            None | Some("read-write") | Some("read-writeOnce") => Access::ReadWrite,
+            Some("read-only") => Access::ReadOnly,
+            Some("write-only") | Some("writeOnce") => Access::WriteOnly,
+            Some(unknown) => {
+                return Err(format!(
+                    "unknown access `{}` of field {}.{}",
+                    unknown, register, name
+                ))
+            }
+        };
+        Ok(Self {
+            name,
+            description: child_text(xml, "description"),
+            offset,
+            width,
+            access,
+        })
+    }
+
+    /// Expands the field into a field of a `#[bitfield]` struct.
+    fn expand(&self, span: proc_macro2::Span) -> TokenStream2 {
+        let ident = rust_ident(&self.name.to_lowercase(), span);
+        let docs = self.description.as_ref().map(|docs| quote_spanned!(span=> #[doc = #docs]));
+        let skip = match self.access {
+            Access::ReadWrite => None,
+            Access::ReadOnly => Some(quote_spanned!(span=> #[skip(setters)])),
+            Access::WriteOnly => Some(quote_spanned!(span=> #[skip(getters)])),
+        };
+        let ty = specifier(self.width, span);
+        quote_spanned!(span=>
+            #docs
+            #skip
+            #ident: #ty,
+        )
+    }
+}
+
+/// Returns the anonymous padding field for reserved bits of a register.
+fn reserved_field(width: usize, span: proc_macro2::Span) -> TokenStream2 {
+    let ty = specifier(width, span);
+    quote_spanned!(span=> __: #ty,)
+}
+
+/// Returns the bitfield specifier type with the given number of bits.
+fn specifier(width: usize, span: proc_macro2::Span) -> TokenStream2 {
+    match width {
+        1 => quote_spanned!(span=> bool),
+        width => {
+            let ty = format_ident!("B{}", width, span = span);
+            quote_spanned!(span=> ::modular_bitfield::specifiers::#ty)
+        }
+    }
+}
+
+/// Returns the given name as identifier, escaping it as raw identifier if it is
+/// a keyword or with a trailing underscore if it cannot be a raw identifier.
+fn rust_ident(name: &str, span: proc_macro2::Span) -> syn::Ident {
+    let name = match name.starts_with(|c: char| c.is_ascii_digit()) {
+        true => format!("_{}", name),
+        false => name.to_string(),
+    };
+    if syn::parse_str::<syn::Ident>(&name).is_ok() {
+        return syn::Ident::new(&name, span)
+    }
+    match name.as_str() {
+        "crate" | "self" | "super" | "Self" | "_" => format_ident!("{}_", name, span = span),
+        _ => syn::Ident::new_raw(&name, span),
+    }
+}
+
+/// Converts the given `UPPER_CASE` SVD name into `CamelCase`.
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Parses a decimal, `0x` hexadecimal or `#` binary SVD number.
+fn parse_number(text: &str) -> Result<usize, String> {
+    let result = if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        usize::from_str_radix(hex, 16)
+    } else if let Some(binary) = text.strip_prefix('#') {
+        usize::from_str_radix(binary, 2)
+    } else {
+        text.parse::<usize>()
+    };
+    result.map_err(|_| format!("invalid number `{}`", text))
+}
+
+/// Parses a `[msb:lsb]` bit range into its offset and width.
+fn parse_bit_range(range: &str) -> Result<(usize, usize), String> {
+    let invalid = || format!("invalid bit range `{}`", range);
+    let inner = range
+        .strip_prefix('[')
+        .and_then(|range| range.strip_suffix(']'))
+        .ok_or_else(invalid)?;
+    let (msb, lsb) = inner.split_once(':').ok_or_else(invalid)?;
+    let (msb, lsb) = (parse_number(msb.trim())?, parse_number(lsb.trim())?);
+    if msb < lsb {
+        return Err(invalid())
+    }
+    Ok((lsb, msb - lsb + 1))
+}
+
+/// Removes all `<!-- .. -->` comments from the given XML.
+fn strip_comments(xml: &str) -> String {
+    let mut stripped = String::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find("<!--") {
+        stripped.push_str(&rest[..start]);
+        rest = match rest[start..].find("-->") {
+            Some(end) => &rest[start + end + 3..],
+            None => "",
+        };
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+/// Returns the contents of all top-most elements with the given tag.
+fn elements<'a>(xml: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut found = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+        let is_tag = after.starts_with(|c: char| c == '>' || c.is_whitespace());
+        let content_start = match (is_tag, after.find('>')) {
+            (true, Some(end)) => end + 1,
+            _ => {
+                rest = after;
+                continue
+            }
+        };
+        let content = &after[content_start..];
+        match content.find(&close) {
+            Some(end) => {
+                found.push(&content[..end]);
+                rest = &content[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+    found
+}
+
+/// Returns the given XML with the contents of all top-most elements with the given tag removed.
+fn without_elements(xml: &str, tag: &str) -> String {
+    elements(xml, tag)
+        .into_iter()
+        .fold(xml.to_string(), |xml, content| xml.replacen(content, "", 1))
+}
+
+/// Returns the trimmed and unescaped text of the first child element with the given tag.
+fn child_text(xml: &str, tag: &str) -> Option<String> {
+    let text = elements(xml, tag).into_iter().next()?;
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    Some(
+        text.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&"),
+    )
+}
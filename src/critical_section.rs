@@ -0,0 +1,84 @@
+//! Helpers to modify bitfields shared with interrupt handlers within critical sections.
+//!
+//! Requires the `critical-section` crate feature.
+
+use crate::VolatileAccess;
+use ::critical_section::Mutex;
+use core::cell::RefCell;
+
+/// Extension trait to modify bitfields within a critical section.
+///
+/// Implemented for all in place accessors of bitfields, such as the `FooPtr` types
+/// generated with `ptr_view = true`, as well as for bitfields stored in a
+/// `critical_section::Mutex<RefCell<_>>`.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use core::cell::RefCell;
+/// use critical_section::Mutex;
+/// use modular_bitfield::critical_section::ModifyCs as _;
+///
+/// #[bitfield(ptr_view = true)]
+/// pub struct Status {
+///     ready: bool,
+///     counter: B7,
+/// }
+///
+/// let mut register = [0x00];
+/// let status = unsafe { StatusPtr::from_ptr(register.as_mut_ptr()) };
+/// status.modify_cs(|status| {
+///     status.set_ready(true);
+///     status.set_counter(status.counter() + 1);
+/// });
+/// assert_eq!(register, [0b0000_0011]);
+///
+/// static SHARED: Mutex<RefCell<Status>> = Mutex::new(RefCell::new(Status::new()));
+/// let counter = SHARED.modify_cs(|status| {
+///     status.set_counter(42);
+///     status.counter()
+/// });
+/// assert_eq!(counter, 42);
+/// ```
+pub trait ModifyCs {
+    /// The modified bitfield type.
+    type Bitfield;
+
+    /// Reads the bitfield, modifies it with `f` and writes it back within a critical section.
+    ///
+    /// Returns the value returned by `f`.
+    fn modify_cs<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut Self::Bitfield) -> R;
+}
+
+impl<T> ModifyCs for T
+where
+    T: VolatileAccess,
+{
+    type Bitfield = T::Bitfield;
+
+    fn modify_cs<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut Self::Bitfield) -> R,
+    {
+        ::critical_section::with(|_| {
+            let mut bitfield = self.read();
+            let result = f(&mut bitfield);
+            self.write(bitfield);
+            result
+        })
+    }
+}
+
+impl<T> ModifyCs for Mutex<RefCell<T>> {
+    type Bitfield = T;
+
+    fn modify_cs<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut Self::Bitfield) -> R,
+    {
+        ::critical_section::with(|cs| f(&mut self.borrow(cs).borrow_mut()))
+    }
+}
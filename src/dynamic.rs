@@ -0,0 +1,182 @@
+//! Bitfield layouts that are defined at runtime.
+//!
+//! A [`DynBitfield`] is built from a list of `(name, bits)` pairs, for example loaded from a
+//! configuration file, and reads and writes its fields in caller-provided buffers.
+//! The fields are laid out in the same bit order as the fields of `#[bitfield]` structs.
+
+use crate::{
+    error::{
+        DynAccessError,
+        InvalidWidth,
+        OutOfBounds,
+    },
+    raw,
+};
+
+/// A single field of a [`DynBitfield`] layout.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DynField<'a> {
+    /// The name of the field.
+    pub name: &'a str,
+    /// The offset of the first bit of the field within the layout.
+    pub offset: usize,
+    /// The number of bits of the field.
+    pub bits: usize,
+}
+
+/// A bitfield layout defined at runtime from a list of `(name, bits)` pairs.
+///
+/// The first field occupies the least significant bits of the first byte.
+/// Values are read and written as `u128` and must fit into the bits of their field.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::dynamic::DynBitfield;
+///
+/// let layout = DynBitfield::new(&[("flag", 1), ("kind", 3), ("length", 12)]).unwrap();
+/// assert_eq!(layout.bytes(), 2);
+///
+/// let mut buffer = [0x00; 2];
+/// layout.set_by_name(&mut buffer, "kind", 0b101).unwrap();
+/// layout.set(&mut buffer, 2, 0xABC).unwrap();
+/// assert_eq!(buffer, [0b1100_1010, 0xAB]);
+/// assert_eq!(layout.get_by_name(&buffer, "length"), Ok(0xABC));
+/// assert!(layout.set_by_name(&mut buffer, "flag", 2).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DynBitfield<'a> {
+    fields: &'a [(&'a str, usize)],
+    bits: usize,
+}
+
+impl<'a> DynBitfield<'a> {
+    /// Creates a new layout from the given `(name, bits)` pairs.
+    ///
+    /// # Errors
+    ///
+    /// If any field has zero or more than 128 bits.
+    pub fn new(fields: &'a [(&'a str, usize)]) -> Result<Self, InvalidWidth> {
+        let mut bits = 0;
+        for (index, &(_, width)) in fields.iter().enumerate() {
+            if width == 0 || width > 128 {
+                return Err(InvalidWidth { index, bits: width })
+            }
+            bits += width;
+        }
+        Ok(Self { fields, bits })
+    }
+
+    /// Returns the total number of bits of all fields.
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the number of bytes required by buffers of the layout.
+    #[inline]
+    pub fn bytes(&self) -> usize {
+        (self.bits + 7) / 8
+    }
+
+    /// Returns the number of fields of the layout.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if the layout has no fields.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns an iterator over all fields of the layout in order.
+    pub fn fields(&self) -> impl Iterator<Item = DynField<'a>> + 'a {
+        self.fields.iter().scan(0, |offset, &(name, bits)| {
+            let field = DynField {
+                name,
+                offset: *offset,
+                bits,
+            };
+            *offset += bits;
+            Some(field)
+        })
+    }
+
+    /// Returns the field at the given index if any.
+    pub fn field(&self, index: usize) -> Option<DynField<'a>> {
+        self.fields().nth(index)
+    }
+
+    /// Returns the index of the first field with the given name if any.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|&(field, _)| field == name)
+    }
+
+    /// Returns the field at the given index after checking the buffer length.
+    fn checked_field(&self, len: usize, index: usize) -> Result<DynField<'a>, DynAccessError> {
+        if len < self.bytes() {
+            return Err(DynAccessError::TooShort {
+                expected: self.bytes(),
+                found: len,
+            })
+        }
+        self.field(index).ok_or(DynAccessError::IndexOutOfRange {
+            index,
+            len: self.len(),
+        })
+    }
+
+    /// Returns the value of the field at the given index within the given buffer.
+    ///
+    /// # Errors
+    ///
+    /// - If the layout has no field at the given index.
+    /// - If the buffer is shorter than [`DynBitfield::bytes`].
+    pub fn get(&self, bytes: &[u8], index: usize) -> Result<u128, DynAccessError> {
+        let field = self.checked_field(bytes.len(), index)?;
+        Ok(raw::read(bytes, field.offset, field.bits))
+    }
+
+    /// Sets the value of the field at the given index within the given buffer.
+    ///
+    /// # Errors
+    ///
+    /// - If the layout has no field at the given index.
+    /// - If the buffer is shorter than [`DynBitfield::bytes`].
+    /// - If the value does not fit into the bits of the field.
+    pub fn set(&self, bytes: &mut [u8], index: usize, value: u128) -> Result<(), DynAccessError> {
+        let field = self.checked_field(bytes.len(), index)?;
+        if field.bits < 128 && value >> field.bits != 0 {
+            return Err(DynAccessError::OutOfBounds(OutOfBounds::with_value(
+                value, field.bits,
+            )))
+        }
+        raw::write(bytes, field.offset, field.bits, value);
+        Ok(())
+    }
+
+    /// Returns the value of the field with the given name within the given buffer.
+    ///
+    /// # Errors
+    ///
+    /// - If the layout has no field with the given name.
+    /// - If the buffer is shorter than [`DynBitfield::bytes`].
+    pub fn get_by_name(&self, bytes: &[u8], name: &str) -> Result<u128, DynAccessError> {
+        let index = self.index_of(name).ok_or(DynAccessError::UnknownField)?;
+        self.get(bytes, index)
+    }
+
+    /// Sets the value of the field with the given name within the given buffer.
+    ///
+    /// # Errors
+    ///
+    /// - If the layout has no field with the given name.
+    /// - If the buffer is shorter than [`DynBitfield::bytes`].
+    /// - If the value does not fit into the bits of the field.
+    pub fn set_by_name(&self, bytes: &mut [u8], name: &str, value: u128) -> Result<(), DynAccessError> {
+        let index = self.index_of(name).ok_or(DynAccessError::UnknownField)?;
+        self.set(bytes, index, value)
+    }
+}
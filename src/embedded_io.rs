@@ -0,0 +1,95 @@
+//! Helpers to read and write bitfields from and to `embedded-io` streams.
+//!
+//! Requires the `embedded-io` crate feature.
+
+use crate::Bitfield;
+use ::embedded_io::{
+    Read,
+    ReadExactError,
+    Write,
+};
+use core::fmt::Debug;
+
+/// Errors that can occure while reading a bitfield from an `embedded-io` reader.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReadError<E> {
+    /// The reader ran out of bytes before the bitfield was read completely.
+    UnexpectedEof,
+    /// The reader encountered an error.
+    Other(E),
+    /// The read bytes contain bits at positions that are undefined for the bitfield.
+    OutOfBounds,
+}
+
+impl<E> From<ReadExactError<E>> for ReadError<E> {
+    fn from(error: ReadExactError<E>) -> Self {
+        match error {
+            ReadExactError::UnexpectedEof => Self::UnexpectedEof,
+            ReadExactError::Other(error) => Self::Other(error),
+        }
+    }
+}
+
+impl<E> core::fmt::Display for ReadError<E>
+where
+    E: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::UnexpectedEof => write!(f, "encountered an unexpected end of input"),
+            Self::Other(error) => write!(f, "encountered a read error: {:?}", error),
+            Self::OutOfBounds => write!(f, "encountered an out of bounds value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for ReadError<E> where E: Debug {}
+
+/// Extension trait to read and write bitfields from and to `embedded-io` streams.
+///
+/// Implemented for all `#[bitfield]` structs with public byte conversions.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::embedded_io::BitfieldEmbeddedIo as _;
+///
+/// #[bitfield]
+/// pub struct Header {
+///     version: B4,
+///     length: B12,
+/// }
+///
+/// let mut buffer = [0x00; 2];
+/// Header::new().with_version(2).with_length(42).write_to(&mut &mut buffer[..]).unwrap();
+///
+/// let header = Header::read_from(&mut &buffer[..]).unwrap();
+/// assert_eq!(header.version(), 2);
+/// assert_eq!(header.length(), 42);
+/// ```
+pub trait BitfieldEmbeddedIo: Bitfield {
+    /// Reads the bitfield from the given reader.
+    ///
+    /// # Errors
+    ///
+    /// - If reading from the reader fails or the reader runs out of bytes.
+    /// - If the read bytes contain bits at positions that are undefined for the bitfield.
+    fn read_from<R: Read>(reader: &mut R) -> Result<Self, ReadError<R::Error>> {
+        let mut bytes = Self::zeroed_bytes();
+        reader.read_exact(bytes.as_mut())?;
+        Self::from_bytes(bytes).map_err(|_| ReadError::OutOfBounds)
+    }
+
+    /// Writes the bytes of the bitfield to the given writer.
+    ///
+    /// # Errors
+    ///
+    /// If writing to the writer fails.
+    fn write_to<W: Write>(&self, writer: &mut W) -> Result<(), W::Error> {
+        writer.write_all(self.as_bytes().as_ref())
+    }
+}
+
+impl<T> BitfieldEmbeddedIo for T where T: Bitfield {}
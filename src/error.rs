@@ -3,19 +3,372 @@
 use core::fmt::Debug;
 
 /// The given value was out of range for the bitfield.
-#[derive(Debug, PartialEq, Eq)]
-pub struct OutOfBounds;
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// The offending value and the bounds it violated if known.
+    ///
+    /// This is set by the setters of bit specifiers and `#[bitfield]` structs.
+    pub details: Option<OutOfBoundsValue>,
+}
+
+/// An out of bounds value together with the bounds it violated.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OutOfBoundsValue {
+    /// The offending value.
+    pub value: u128,
+    /// The bit width of the field.
+    pub bits: usize,
+    /// The maximum value that fits into the field.
+    pub max_value: u128,
+}
+
+impl OutOfBounds {
+    /// Creates a new out of bounds error without further details.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { details: None }
+    }
+
+    /// Creates a new out of bounds error for the given value exceeding the given bit width.
+    #[inline]
+    pub fn with_value(value: u128, bits: usize) -> Self {
+        let max_value = match bits >= 128 {
+            true => u128::MAX,
+            false => (1 << bits) - 1,
+        };
+        Self {
+            details: Some(OutOfBoundsValue {
+                value,
+                bits,
+                max_value,
+            }),
+        }
+    }
+
+    /// Returns the offending value and the bounds it violated if known.
+    #[inline]
+    pub fn details(&self) -> Option<OutOfBoundsValue> {
+        self.details
+    }
+}
 
 impl core::fmt::Display for OutOfBounds {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "encountered an out of bounds value")
+        match self.details {
+            Some(details) => {
+                write!(
+                    f,
+                    "encountered an out of bounds value: {} does not fit into {} bits (max: {})",
+                    details.value, details.bits, details.max_value
+                )
+            }
+            None => write!(f, "encountered an out of bounds value"),
+        }
     }
 }
 
-/// The bitfield contained an invalid bit pattern.
+/// The given byte slice could not be converted into the bitfield.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FromSliceError {
+    /// The byte slice contains fewer bytes than the bitfield requires.
+    TooShort {
+        /// The amount of bytes required by the bitfield.
+        expected: usize,
+        /// The amount of bytes of the given byte slice.
+        found: usize,
+    },
+    /// The byte slice contains bits at positions that are undefined for the bitfield.
+    InvalidPadding,
+}
+
+impl core::fmt::Display for FromSliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::TooShort { expected, found } => {
+                write!(
+                    f,
+                    "encountered a byte slice that is too short: expected {} bytes but found {}",
+                    expected, found
+                )
+            }
+            Self::InvalidPadding => {
+                write!(f, "encountered a byte slice with invalid padding bits")
+            }
+        }
+    }
+}
+
+/// A field of the bitfield could not be accessed by its name.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FieldAccessError {
+    /// The bitfield has no field with the given name.
+    UnknownField,
+    /// The given value does not fit into the bits of the field.
+    OutOfBounds(OutOfBounds),
+    /// The given value is an invalid bit pattern of the field.
+    InvalidBitPattern,
+}
+
+impl core::fmt::Display for FieldAccessError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::UnknownField => write!(f, "encountered an unknown field name"),
+            Self::OutOfBounds(error) => core::fmt::Display::fmt(error, f),
+            Self::InvalidBitPattern => write!(f, "encountered an invalid bit pattern of the field"),
+        }
+    }
+}
+
+/// A field of a `DynBitfield` layout has an unsupported bit width.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidWidth {
+    /// The index of the offending field.
+    pub index: usize,
+    /// The bit width of the offending field.
+    pub bits: usize,
+}
+
+impl core::fmt::Display for InvalidWidth {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "encountered field {} with {} bits but fields must have 1 to 128 bits",
+            self.index, self.bits
+        )
+    }
+}
+
+/// A field of a `DynBitfield` could not be accessed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DynAccessError {
+    /// The layout has no field with the given name.
+    UnknownField,
+    /// The layout has no field with the given index.
+    IndexOutOfRange {
+        /// The requested index.
+        index: usize,
+        /// The number of fields of the layout.
+        len: usize,
+    },
+    /// The buffer contains fewer bytes than the layout requires.
+    TooShort {
+        /// The amount of bytes required by the layout.
+        expected: usize,
+        /// The amount of bytes of the given buffer.
+        found: usize,
+    },
+    /// The given value does not fit into the bits of the field.
+    OutOfBounds(OutOfBounds),
+}
+
+impl core::fmt::Display for DynAccessError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::UnknownField => write!(f, "encountered an unknown field name"),
+            Self::IndexOutOfRange { index, len } => {
+                write!(
+                    f,
+                    "encountered field index {} but the layout has only {} fields",
+                    index, len
+                )
+            }
+            Self::TooShort { expected, found } => {
+                write!(
+                    f,
+                    "encountered a buffer that is too short: expected {} bytes but found {}",
+                    expected, found
+                )
+            }
+            Self::OutOfBounds(error) => core::fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+/// The textual representation of a bitfield could not be parsed.
+///
+/// Returned by the `FromStr` impl of `#[bitfield(text = true)]` structs.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseTextError {
+    /// A `name=value` pair is missing its `=value` part.
+    MissingValue,
+    /// The bitfield has no field with the given name.
+    UnknownField,
+    /// The value is neither a decimal nor a `0x` prefixed hexadecimal integer,
+    /// or neither `true` nor `false` for a `bool` field.
+    InvalidValue,
+    /// The given value does not fit into the bits of the field.
+    OutOfBounds(OutOfBounds),
+}
+
+impl core::fmt::Display for ParseTextError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::MissingValue => write!(f, "encountered a field without `=value`"),
+            Self::UnknownField => write!(f, "encountered an unknown field name"),
+            Self::InvalidValue => write!(f, "encountered an invalid field value"),
+            Self::OutOfBounds(error) => core::fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+/// A `BitReader` or `BitWriter` could not read or write a value.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CursorError {
+    /// Fewer bits are left than requested.
+    UnexpectedEnd {
+        /// The amount of bits requested.
+        requested: usize,
+        /// The amount of bits left.
+        remaining: usize,
+    },
+    /// The read bits are an invalid bit pattern for the specifier.
+    InvalidBitPattern {
+        /// The invalid bit pattern.
+        invalid_bits: u128,
+    },
+    /// The written value is out of bounds for the specifier.
+    OutOfBounds(OutOfBounds),
+}
+
+impl core::fmt::Display for CursorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::UnexpectedEnd {
+                requested,
+                remaining,
+            } => {
+                write!(
+                    f,
+                    "encountered an unexpected end: requested {} bits but only {} are left",
+                    requested, remaining
+                )
+            }
+            Self::InvalidBitPattern { invalid_bits } => {
+                write!(f, "encountered an invalid bit pattern: {:#X}", invalid_bits)
+            }
+            Self::OutOfBounds(error) => core::fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+/// The given bytes could not be decoded into the bitfield.
 #[derive(Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The bytes contain bits at positions that are undefined for the bitfield.
+    InvalidPadding,
+    /// The bytes contain an invalid bit pattern for a field of the bitfield.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The invalid bit pattern of the field.
+        invalid_bits: u128,
+    },
+    /// The value of a field was rejected by its `#[validate(..)]` function.
+    InvalidValue {
+        /// The name of the field.
+        field: &'static str,
+    },
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::InvalidPadding => {
+                write!(f, "encountered bytes with invalid padding bits")
+            }
+            Self::InvalidField {
+                field,
+                invalid_bits,
+            } => {
+                write!(
+                    f,
+                    "encountered an invalid bit pattern for field {}: {:#X}",
+                    field, invalid_bits
+                )
+            }
+            Self::InvalidValue { field } => {
+                write!(f, "encountered a value rejected by the validator of field {}", field)
+            }
+        }
+    }
+}
+
+/// The checked setter of a field with a `#[validate(..)]` attribute rejected the given value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ValidateError<E> {
+    /// The value does not fit into the bits of the field.
+    OutOfBounds(OutOfBounds),
+    /// The validation function of the field rejected the value with the contained error.
+    Invalid(E),
+}
+
+impl<E> From<OutOfBounds> for ValidateError<E> {
+    #[inline]
+    fn from(error: OutOfBounds) -> Self {
+        Self::OutOfBounds(error)
+    }
+}
+
+impl<E> core::fmt::Display for ValidateError<E>
+where
+    E: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::OutOfBounds(error) => core::fmt::Display::fmt(error, f),
+            Self::Invalid(error) => {
+                write!(f, "encountered a value rejected by the validation function: {:?}", error)
+            }
+        }
+    }
+}
+
+/// The location of a field within its bitfield.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FieldContext {
+    /// The name of the bitfield struct.
+    pub struct_name: &'static str,
+    /// The name of the field.
+    pub field_name: &'static str,
+    /// The bit offset of the field within the bitfield.
+    pub offset: usize,
+    /// The number of bits of the field.
+    pub bits: usize,
+}
+
+impl FieldContext {
+    /// Returns the range of bits occupied by the field.
+    #[inline]
+    pub fn bit_range(&self) -> core::ops::Range<usize> {
+        self.offset..(self.offset + self.bits)
+    }
+}
+
+/// The bitfield contained an invalid bit pattern.
+#[derive(PartialEq, Eq)]
 pub struct InvalidBitPattern<Bytes> {
     pub invalid_bytes: Bytes,
+    /// The field that contained the invalid bit pattern if known.
+    ///
+    /// This is set by the checked getters generated by `#[bitfield]`.
+    pub context: Option<FieldContext>,
+}
+
+impl<Bytes> Debug for InvalidBitPattern<Bytes>
+where
+    Bytes: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let mut debug = f.debug_struct("InvalidBitPattern");
+        debug.field("invalid_bytes", &self.invalid_bytes);
+        if let Some(context) = &self.context {
+            debug
+                .field("struct_name", &context.struct_name)
+                .field("field_name", &context.field_name)
+                .field("bits", &context.bit_range());
+        }
+        debug.finish()
+    }
 }
 
 impl<Bytes> core::fmt::Display for InvalidBitPattern<Bytes>
@@ -27,7 +380,16 @@ where
             f,
             "encountered an invalid bit pattern: {:X?}",
             self.invalid_bytes
-        )
+        )?;
+        if let Some(context) = &self.context {
+            let bits = context.bit_range();
+            write!(
+                f,
+                " for field {}.{} at bits {}..{}",
+                context.struct_name, context.field_name, bits.start, bits.end
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -35,7 +397,17 @@ impl<Bytes> InvalidBitPattern<Bytes> {
     /// Creates a new invalid bit pattern error.
     #[inline]
     pub fn new(invalid_bytes: Bytes) -> Self {
-        Self { invalid_bytes }
+        Self {
+            invalid_bytes,
+            context: None,
+        }
+    }
+
+    /// Attaches the location of the field that contained the invalid bit pattern.
+    #[inline]
+    pub fn with_context(mut self, context: FieldContext) -> Self {
+        self.context = Some(context);
+        self
     }
 
     /// Returns the invalid bit pattern.
@@ -43,4 +415,40 @@ impl<Bytes> InvalidBitPattern<Bytes> {
     pub fn invalid_bytes(self) -> Bytes {
         self.invalid_bytes
     }
+
+    /// Returns the location of the field that contained the invalid bit pattern if known.
+    #[inline]
+    pub fn context(&self) -> Option<FieldContext> {
+        self.context
+    }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBounds {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromSliceError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldAccessError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidWidth {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DynAccessError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTextError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CursorError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for ValidateError<E> where E: Debug {}
+
+#[cfg(feature = "std")]
+impl<Bytes> std::error::Error for InvalidBitPattern<Bytes> where Bytes: Debug {}
@@ -0,0 +1,289 @@
+//! Bitmasks over the variants of an enum specifier.
+//!
+//! Useful for "one bit per capability" fields where every variant of an enum owns a single bit.
+
+use crate::{
+    error::{
+        InvalidBitPattern,
+        OutOfBounds,
+    },
+    private::WordAccess,
+    Specifier,
+};
+use core::marker::PhantomData;
+
+/// A set of variants of the enum specifier `E` stored as a bitmask.
+///
+/// The variant with the discriminant `n` owns the `n`-th least significant bit, so the
+/// set occupies `2^E::BITS` bits. Just like every other specifier a set of flags can be
+/// at most 128 bits wide and can be used as the type of a `#[bitfield]` field.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{
+///     flags::Flags,
+///     prelude::*,
+/// };
+///
+/// #[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq)]
+/// pub enum Capability {
+///     Read,
+///     Write,
+///     Execute,
+///     Share,
+/// }
+///
+/// #[bitfield]
+/// pub struct Permissions {
+///     owner: Flags<Capability>,
+///     group: Flags<Capability>,
+/// }
+///
+/// let mut owner = Flags::<Capability>::new();
+/// owner.insert(Capability::Read);
+/// owner.insert(Capability::Write);
+/// let permissions = Permissions::new().with_owner(owner);
+/// assert!(permissions.owner().contains(Capability::Write));
+/// assert!(!permissions.owner().contains(Capability::Execute));
+/// assert_eq!(
+///     permissions.owner().iter().collect::<Vec<_>>(),
+///     [Capability::Read, Capability::Write]
+/// );
+/// ```
+pub struct Flags<E> {
+    bits: u128,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl<E> Flags<E>
+where
+    E: Specifier,
+    E::Bytes: WordAccess,
+{
+    /// Creates a new set of flags with no variant set.
+    ///
+    /// # Panics
+    ///
+    /// If the flags occupy more than 128 bits.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_bits(0)
+    }
+
+    /// Creates a new set of flags from the given raw bits.
+    ///
+    /// Bits above the flags are ignored.
+    ///
+    /// # Panics
+    ///
+    /// If the flags occupy more than 128 bits.
+    #[inline]
+    pub fn from_bits(bits: u128) -> Self {
+        assert!(
+            <Self as Specifier>::BITS <= 128,
+            "flags cannot occupy more than 128 bits"
+        );
+        let mask = match <Self as Specifier>::BITS >= 128 {
+            true => u128::MAX,
+            false => (1 << <Self as Specifier>::BITS) - 1,
+        };
+        Self {
+            bits: bits & mask,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the raw bits of the flags.
+    #[inline]
+    pub fn into_bits(self) -> u128 {
+        self.bits
+    }
+
+    /// Returns the number of set variants.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns `true` if no variant is set.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns the bit owned by the given variant.
+    fn bit(value: E::InOut) -> u128 {
+        let index = <E::Bytes as WordAccess>::into_u128(
+            <E as Specifier>::into_bytes(value).expect("value out of bounds for flags variant"),
+        );
+        1 << index
+    }
+
+    /// Returns `true` if the given variant is set.
+    #[inline]
+    pub fn contains(&self, value: E::InOut) -> bool {
+        self.bits & Self::bit(value) != 0
+    }
+
+    /// Sets the given variant.
+    #[inline]
+    pub fn insert(&mut self, value: E::InOut) {
+        self.bits |= Self::bit(value);
+    }
+
+    /// Unsets the given variant.
+    #[inline]
+    pub fn remove(&mut self, value: E::InOut) {
+        self.bits &= !Self::bit(value);
+    }
+
+    /// Returns an iterator over the set variants in order of their discriminants.
+    ///
+    /// # Note
+    ///
+    /// The iterator panics upon set bits that do not belong to a variant of `E`.
+    #[inline]
+    pub fn iter(&self) -> Iter<E> {
+        Iter {
+            bits: self.bits,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<E> Clone for Flags<E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for Flags<E> {}
+
+impl<E> PartialEq for Flags<E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<E> Eq for Flags<E> {}
+
+impl<E> Default for Flags<E>
+where
+    E: Specifier,
+    E::Bytes: WordAccess,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> core::fmt::Debug for Flags<E> {
+    /// Prints the discriminants of all set variants.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_set()
+            .entries((0..128).filter(|index| self.bits & (1 << index) != 0))
+            .finish()
+    }
+}
+
+impl<E> Specifier for Flags<E>
+where
+    E: Specifier,
+    E::Bytes: WordAccess,
+{
+    const BITS: usize = 1 << <E as Specifier>::BITS;
+    type Bytes = u128;
+    type InOut = Self;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        Ok(input.bits)
+    }
+
+    /// Converts the bits into flags if every set bit belongs to a variant of `E`.
+    #[inline]
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        let flags = Self::from_bits(bytes);
+        let valid = (0..<Self as Specifier>::BITS)
+            .filter(|index| flags.bits & (1 << index) != 0)
+            .all(|index| {
+                let bytes = <E::Bytes as WordAccess>::from_u128(index as u128);
+                <E as Specifier>::from_bytes(bytes).is_ok()
+            });
+        match valid {
+            true => Ok(flags),
+            false => Err(InvalidBitPattern::new(bytes)),
+        }
+    }
+}
+
+/// Iterator over the set variants of [`Flags`].
+pub struct Iter<E> {
+    bits: u128,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl<E> Clone for Iter<E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            bits: self.bits,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<E> Iterator for Iter<E>
+where
+    E: Specifier,
+    E::Bytes: WordAccess,
+{
+    type Item = E::InOut;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None
+        }
+        let index = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        let bytes = <E::Bytes as WordAccess>::from_u128(u128::from(index));
+        match <E as Specifier>::from_bytes(bytes) {
+            Ok(value) => Some(value),
+            Err(_) => panic!("flags contain a bit that does not belong to a variant"),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bits.count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<E> ExactSizeIterator for Iter<E>
+where
+    E: Specifier,
+    E::Bytes: WordAccess,
+{
+}
+
+impl<E> IntoIterator for Flags<E>
+where
+    E: Specifier,
+    E::Bytes: WordAccess,
+{
+    type Item = E::InOut;
+    type IntoIter = Iter<E>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
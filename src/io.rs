@@ -0,0 +1,239 @@
+//! Cursors to read and write bit specifiers sequentially from and to byte slices.
+//!
+//! Useful for protocols whose field layout is not known statically.
+//! Bits are read and written in the same order as the fields of `#[bitfield]` structs,
+//! starting with the least significant bit of the first byte.
+
+use crate::{
+    error::CursorError,
+    private::WordAccess,
+    Specifier,
+};
+
+/// Reads bit specifiers sequentially from a byte slice.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{
+///     io::BitReader,
+///     specifiers::*,
+/// };
+///
+/// let mut reader = BitReader::new(&[0b1010_0101, 0x0F]);
+/// assert_eq!(reader.read::<bool>(), Ok(true));
+/// assert_eq!(reader.read::<B3>(), Ok(0b010));
+/// assert_eq!(reader.read::<B12>(), Ok(0x0FA));
+/// assert_eq!(reader.remaining_bits(), 0);
+/// assert!(reader.read::<bool>().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a new reader starting at the first bit of the given bytes.
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Returns the number of bits read or skipped so far.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bits that are left to be read.
+    #[inline]
+    pub fn remaining_bits(&self) -> usize {
+        self.bytes.len() * 8 - self.position
+    }
+
+    /// Ensures that at least `bits` bits are left to be read.
+    fn ensure_remaining(&self, bits: usize) -> Result<(), CursorError> {
+        let remaining = self.remaining_bits();
+        if bits > remaining {
+            return Err(CursorError::UnexpectedEnd {
+                requested: bits,
+                remaining,
+            })
+        }
+        Ok(())
+    }
+
+    /// Reads the next value of the given specifier.
+    ///
+    /// The reader only advances if the value has been read successfully.
+    ///
+    /// # Errors
+    ///
+    /// - If fewer bits are left than the specifier requires.
+    /// - If the read bits are an invalid bit pattern for the specifier.
+    #[inline]
+    pub fn read<T>(&mut self) -> Result<T::InOut, CursorError>
+    where
+        T: Specifier,
+        T::Bytes: WordAccess,
+    {
+        self.ensure_remaining(<T as Specifier>::BITS)?;
+        let raw = <T::Bytes as WordAccess>::read_bits(
+            self.bytes,
+            self.position,
+            <T as Specifier>::BITS,
+        );
+        let value = <T as Specifier>::from_bytes(raw).map_err(|error| {
+            CursorError::InvalidBitPattern {
+                invalid_bits: <T::Bytes as WordAccess>::into_u128(error.invalid_bytes),
+            }
+        })?;
+        self.position += <T as Specifier>::BITS;
+        Ok(value)
+    }
+
+    /// Reads the next `bits` bits as an unsigned integer.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `bits` bits are left.
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is greater than 128.
+    #[inline]
+    pub fn read_bits(&mut self, bits: usize) -> Result<u128, CursorError> {
+        assert!(bits <= 128, "cannot read more than 128 bits at once");
+        self.ensure_remaining(bits)?;
+        let value = <u128 as WordAccess>::read_bits(self.bytes, self.position, bits);
+        self.position += bits;
+        Ok(value)
+    }
+
+    /// Skips the next `bits` bits.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `bits` bits are left.
+    #[inline]
+    pub fn skip(&mut self, bits: usize) -> Result<(), CursorError> {
+        self.ensure_remaining(bits)?;
+        self.position += bits;
+        Ok(())
+    }
+}
+
+/// Writes bit specifiers sequentially into a byte slice.
+///
+/// Bits of the byte slice that are not written keep their values.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{
+///     io::BitWriter,
+///     specifiers::*,
+/// };
+///
+/// let mut bytes = [0x00; 2];
+/// let mut writer = BitWriter::new(&mut bytes);
+/// writer.write::<bool>(true).unwrap();
+/// writer.write::<B3>(0b010).unwrap();
+/// writer.write::<B12>(0x0FA).unwrap();
+/// assert!(writer.write::<bool>(true).is_err());
+/// assert_eq!(bytes, [0b1010_0101, 0x0F]);
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    bytes: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Creates a new writer starting at the first bit of the given bytes.
+    #[inline]
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Returns the number of bits written or skipped so far.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bits that are left to be written.
+    #[inline]
+    pub fn remaining_bits(&self) -> usize {
+        self.bytes.len() * 8 - self.position
+    }
+
+    /// Ensures that at least `bits` bits are left to be written.
+    fn ensure_remaining(&self, bits: usize) -> Result<(), CursorError> {
+        let remaining = self.remaining_bits();
+        if bits > remaining {
+            return Err(CursorError::UnexpectedEnd {
+                requested: bits,
+                remaining,
+            })
+        }
+        Ok(())
+    }
+
+    /// Writes the given value of the given specifier.
+    ///
+    /// The writer only advances if the value has been written successfully.
+    ///
+    /// # Errors
+    ///
+    /// - If fewer bits are left than the specifier requires.
+    /// - If the value is out of bounds for the specifier.
+    #[inline]
+    pub fn write<T>(&mut self, value: T::InOut) -> Result<(), CursorError>
+    where
+        T: Specifier,
+        T::Bytes: WordAccess,
+    {
+        self.ensure_remaining(<T as Specifier>::BITS)?;
+        let raw = <T as Specifier>::into_bytes(value).map_err(CursorError::OutOfBounds)?;
+        <T::Bytes as WordAccess>::write_bits(
+            self.bytes,
+            self.position,
+            <T as Specifier>::BITS,
+            raw,
+        );
+        self.position += <T as Specifier>::BITS;
+        Ok(())
+    }
+
+    /// Writes the `bits` least significant bits of the given value.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `bits` bits are left.
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is greater than 128.
+    #[inline]
+    pub fn write_bits(&mut self, bits: usize, value: u128) -> Result<(), CursorError> {
+        assert!(bits <= 128, "cannot write more than 128 bits at once");
+        self.ensure_remaining(bits)?;
+        <u128 as WordAccess>::write_bits(self.bytes, self.position, bits, value);
+        self.position += bits;
+        Ok(())
+    }
+
+    /// Skips the next `bits` bits leaving them unchanged.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `bits` bits are left.
+    #[inline]
+    pub fn skip(&mut self, bits: usize) -> Result<(), CursorError> {
+        self.ensure_remaining(bits)?;
+        self.position += bits;
+        Ok(())
+    }
+}
@@ -0,0 +1,380 @@
+//! Compile-time reflection of the layout of `#[bitfield]` structs.
+//!
+//! Every `#[bitfield]` struct `Foo` provides a `Foo::LAYOUT` constant describing its fields.
+//! Additionally the [`Reflect`] trait provides access to the fields by their names at runtime
+//! and [`CHeader`] renders a layout as C preprocessor definitions.
+
+use crate::{
+    error::FieldAccessError,
+    private::{
+        self,
+        read_bits_shared,
+        RawFields,
+    },
+};
+
+/// The kind of the values stored in a field.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FieldKind {
+    /// A `bool` field.
+    Bool,
+    /// An unsigned integer field such as `B5` or `u16`.
+    Unsigned,
+    /// A field of any other bitfield specifier such as an enum or a nested bitfield.
+    Specifier,
+}
+
+/// Describes a single field of a `#[bitfield]` struct.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FieldDescriptor {
+    /// The name of the field, or its index for tuple structs.
+    pub name: &'static str,
+    /// The offset of the first bit of the field within the bitfield.
+    pub offset: usize,
+    /// The number of bits of the field.
+    pub bits: usize,
+    /// The kind of the values stored in the field.
+    pub kind: FieldKind,
+    /// `true` if the field is reserved via `#[skip]`.
+    pub skipped: bool,
+}
+
+impl FieldDescriptor {
+    /// Returns the range of bits occupied by the field.
+    #[inline]
+    pub fn bit_range(&self) -> core::ops::Range<usize> {
+        self.offset..(self.offset + self.bits)
+    }
+}
+
+/// Feeds the given bytes into the FNV-1a hash state.
+const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes a fingerprint of the given layout from the names, order and widths of its fields.
+///
+/// Every `#[bitfield]` struct `Foo` provides this as its `Foo::LAYOUT_HASH` constant.
+/// Two programs can compare their fingerprints to cheaply verify that they agree on a wire format.
+/// The fingerprint is stable across compilations and platforms.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{
+///     layout::layout_hash,
+///     prelude::*,
+/// };
+///
+/// #[bitfield]
+/// pub struct Header {
+///     is_compact: bool,
+///     length: B7,
+/// }
+///
+/// assert_eq!(Header::LAYOUT_HASH, layout_hash(Header::LAYOUT));
+/// ```
+pub const fn layout_hash(layout: &[FieldDescriptor]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < layout.len() {
+        let field = &layout[i];
+        hash = fnv1a(hash, &(field.name.len() as u64).to_le_bytes());
+        hash = fnv1a(hash, field.name.as_bytes());
+        hash = fnv1a(hash, &(field.bits as u64).to_le_bytes());
+        i += 1;
+    }
+    hash
+}
+
+/// Access to the fields of a `#[bitfield]` struct by their names at runtime.
+///
+/// Implemented for `#[bitfield]` structs with public byte conversions or `reflect = true`
+/// except for those with `cell = "volatile"`. The fields are read and written as their raw bits.
+/// Fields reserved via `#[skip]` can neither be read nor written and writes of invalid bit
+/// patterns to enum or nested bitfield fields are rejected.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{
+///     layout::Reflect,
+///     prelude::*,
+/// };
+///
+/// #[bitfield]
+/// pub struct Header {
+///     is_compact: bool,
+///     length: B7,
+/// }
+///
+/// let mut header = Header::new();
+/// header.set_field("length", 42).unwrap();
+/// assert_eq!(header.length(), 42);
+/// assert_eq!(header.get_field("length"), Some(42));
+/// assert_eq!(header.get_field("unknown"), None);
+/// assert!(header.set_field("length", 128).is_err());
+/// ```
+pub trait Reflect: RawFields {
+    /// The layout of the fields of the bitfield in declaration order.
+    const LAYOUT: &'static [FieldDescriptor] = <Self as RawFields>::LAYOUT;
+
+    /// Returns the descriptor of the field with the given name if any.
+    #[inline]
+    fn field_descriptor(name: &str) -> Option<&'static FieldDescriptor> {
+        <Self as Reflect>::LAYOUT.iter().find(|field| field.name == name)
+    }
+
+    /// Returns the raw bits of the field with the given name or `None` if there is no such field
+    /// or the field is skipped.
+    #[inline]
+    fn get_field(&self, name: &str) -> Option<u128> {
+        private::get_field(self, name)
+    }
+
+    /// Returns an iterator over the names and raw bits of all fields that are not skipped.
+    #[inline]
+    fn fields(&self) -> Fields<'_, Self>
+    where
+        Self: Sized,
+    {
+        Fields {
+            bitfield: self,
+            layout: <Self as Reflect>::LAYOUT.iter(),
+        }
+    }
+
+    /// Returns an iterator over the fields that are not skipped and whose raw bits differ
+    /// between `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use modular_bitfield::{
+    ///     layout::Reflect,
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[bitfield]
+    /// pub struct Header {
+    ///     is_compact: bool,
+    ///     length: B7,
+    /// }
+    ///
+    /// let before = Header::new().with_length(1);
+    /// let after = Header::new().with_length(2);
+    /// let changes = before.diff(&after).collect::<Vec<_>>();
+    /// assert_eq!(changes.len(), 1);
+    /// assert_eq!(changes[0].field.name, "length");
+    /// assert_eq!((changes[0].old, changes[0].new), (1, 2));
+    /// ```
+    #[inline]
+    fn diff<'a>(&'a self, other: &'a Self) -> Diff<'a, Self>
+    where
+        Self: Sized,
+    {
+        Diff {
+            old: self,
+            new: other,
+            layout: <Self as Reflect>::LAYOUT.iter(),
+        }
+    }
+
+    /// Sets the raw bits of the field with the given name.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no field with the given name or the field is skipped.
+    /// - If the given value does not fit into the bits of the field.
+    /// - If the given value is an invalid bit pattern of an enum or nested bitfield field.
+    #[inline]
+    fn set_field(&mut self, name: &str, value: u128) -> Result<(), FieldAccessError> {
+        private::set_field(self, name, value)
+    }
+
+    /// Copies the raw bits of all fields of `other` that are not skipped into the fields
+    /// of `self` with the same names.
+    ///
+    /// Fields of `self` without a counterpart in `other` are left untouched and fields of `other`
+    /// without a counterpart in `self` are dropped. Values that do not fit into the bits of the
+    /// field of `self` are truncated to its bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use modular_bitfield::{
+    ///     layout::Reflect,
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[bitfield]
+    /// pub struct HeaderV1 {
+    ///     length: B7,
+    ///     is_compact: bool,
+    /// }
+    ///
+    /// #[bitfield]
+    /// pub struct HeaderV2 {
+    ///     is_compact: bool,
+    ///     checksum: B3,
+    ///     length: B12,
+    /// }
+    ///
+    /// let mut header = HeaderV2::new();
+    /// header.copy_fields_from(&HeaderV1::new().with_length(100).with_is_compact(true));
+    /// assert!(header.is_compact());
+    /// assert_eq!(header.checksum(), 0);
+    /// assert_eq!(header.length(), 100);
+    /// ```
+    #[inline]
+    fn copy_fields_from<T>(&mut self, other: &T)
+    where
+        T: Reflect,
+    {
+        private::copy_fields(self, other)
+    }
+}
+
+/// Iterator over the names and raw bits of the fields of a `#[bitfield]` struct.
+///
+/// Returned by [`Reflect::fields`]. Fields reserved via `#[skip]` are not yielded.
+pub struct Fields<'a, T> {
+    bitfield: &'a T,
+    layout: core::slice::Iter<'static, FieldDescriptor>,
+}
+
+impl<'a, T> Iterator for Fields<'a, T>
+where
+    T: Reflect,
+{
+    type Item = (&'static str, u128);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = self.layout.find(|field| !field.skipped)?;
+        let value = read_bits_shared(self.bitfield.__bf_bytes(), field.offset, field.bits);
+        Some((field.name, value))
+    }
+}
+
+/// A field whose raw bits differ between two bitfields.
+///
+/// Yielded by the iterator returned by [`Reflect::diff`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// The descriptor of the changed field.
+    pub field: &'static FieldDescriptor,
+    /// The raw bits of the field in the first bitfield.
+    pub old: u128,
+    /// The raw bits of the field in the second bitfield.
+    pub new: u128,
+}
+
+/// Iterator over the fields whose raw bits differ between two `#[bitfield]` structs.
+///
+/// Returned by [`Reflect::diff`]. Fields reserved via `#[skip]` are not yielded.
+pub struct Diff<'a, T> {
+    old: &'a T,
+    new: &'a T,
+    layout: core::slice::Iter<'static, FieldDescriptor>,
+}
+
+impl<'a, T> Iterator for Diff<'a, T>
+where
+    T: Reflect,
+{
+    type Item = FieldChange;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (old, new) = (self.old.__bf_bytes(), self.new.__bf_bytes());
+        self.layout
+            .by_ref()
+            .filter(|field| !field.skipped)
+            .map(|field| {
+                FieldChange {
+                    field,
+                    old: read_bits_shared(old, field.offset, field.bits),
+                    new: read_bits_shared(new, field.offset, field.bits),
+                }
+            })
+            .find(|change| change.old != change.new)
+    }
+}
+
+/// Renders a bitfield layout as C preprocessor definitions.
+///
+/// For every field `f` that is not skipped this defines `PREFIX_F_OFFSET` and `PREFIX_F_BITS`
+/// as well as `PREFIX_F_MASK` for fields of at most 64 bits. The mask is not shifted by the offset.
+/// Shared layouts between C and Rust code can be kept in sync by generating headers with this.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{
+///     layout::CHeader,
+///     prelude::*,
+/// };
+///
+/// #[bitfield]
+/// pub struct Header {
+///     is_compact: bool,
+///     length: B7,
+/// }
+///
+/// let header = CHeader::new("HEADER", Header::LAYOUT).to_string();
+/// assert!(header.contains("#define HEADER_LENGTH_OFFSET 1\n"));
+/// assert!(header.contains("#define HEADER_LENGTH_BITS 7\n"));
+/// assert!(header.contains("#define HEADER_LENGTH_MASK 0x7FULL\n"));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct CHeader<'a> {
+    prefix: &'a str,
+    layout: &'a [FieldDescriptor],
+}
+
+impl<'a> CHeader<'a> {
+    /// Creates a new renderer for the given layout prefixing all definitions with `prefix`.
+    #[inline]
+    pub fn new(prefix: &'a str, layout: &'a [FieldDescriptor]) -> Self {
+        Self { prefix, layout }
+    }
+}
+
+impl core::fmt::Display for CHeader<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        /// Writes a single `#define PREFIX_FIELD_SUFFIX value` line.
+        fn define(
+            f: &mut core::fmt::Formatter,
+            prefix: &str,
+            field: &FieldDescriptor,
+            suffix: &str,
+            value: &dyn core::fmt::Display,
+        ) -> core::fmt::Result {
+            write!(f, "#define {}_", prefix)?;
+            for c in field.name.chars() {
+                let c = match c.is_ascii_alphanumeric() {
+                    true => c.to_ascii_uppercase(),
+                    false => '_',
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f, "_{} {}", suffix, value)
+        }
+        for field in self.layout.iter().filter(|field| !field.skipped) {
+            define(f, self.prefix, field, "OFFSET", &field.offset)?;
+            define(f, self.prefix, field, "BITS", &field.bits)?;
+            if field.bits <= 64 {
+                let mask = (1u128 << field.bits) - 1;
+                define(f, self.prefix, field, "MASK", &format_args!("{:#X}ULL", mask))?;
+            }
+        }
+        Ok(())
+    }
+}
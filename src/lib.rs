@@ -61,6 +61,11 @@
 //! Any type that implements the `Specifier` trait can be used as a bitfield field.
 //! Besides the already mentioned `B1`, .. `B128` also the `bool`, `u8, `u16, `u32,
 //! `u64` or `u128` primitive types can be used from prelude.
+//! The zero-width `B0` specifier has getters and setters operating on `()` and can be used
+//! for marker fields that do not affect the layout.
+//! Byte arrays `[u8; N]` of up to 16 bytes can be used for opaque byte blobs at any bit offset.
+//! With the `ux` crate feature enabled the exact-width integers `ux::u1`, .. `ux::u63` and
+//! `ux::i1`, .. `ux::i63` of the `ux` crate can be used as specifiers as well.
 //!
 //! We can use this knowledge to encode our `is_alive` as `bool` type instead of `B1`:
 //!
@@ -303,7 +308,6 @@
 //!
 //! ```
 //! # use modular_bitfield::prelude::*;
-//! # use modular_bitfield::error::InvalidBitPattern;
 //! #
 //! # #[derive(BitfieldSpecifier)]
 //! # #[derive(Debug, PartialEq, Eq)]
@@ -331,7 +335,12 @@
 //! let mut data = PackedData::from_bytes([0b0000_0000, 0b1100_0000]);
 //! //           The 2 status field bits are invalid -----^^
 //! //           as Red = 0x00, Green = 0x01 and Yellow = 0x10
-//! assert_eq!(data.status_or_err(), Err(InvalidBitPattern { invalid_bytes: 0b11 }));
+//! let error = data.status_or_err().unwrap_err();
+//! assert_eq!(error.invalid_bytes, 0b11);
+//! // The error also tells us where the invalid bits are located.
+//! let context = error.context().unwrap();
+//! assert_eq!((context.struct_name, context.field_name), ("PackedData", "status"));
+//! assert_eq!(context.bit_range(), 14..16);
 //! data.set_status(Status::Green);
 //! assert_eq!(data.status_or_err(), Ok(Status::Green));
 //! ```
@@ -354,7 +363,33 @@
 //! |:--|:--|
 //! | `fn new() -> Self` | Creates a new instance of the bitfield with all bits initialized to 0. |
 //! | `fn from_bytes([u8; 1]) -> Self` | Creates a new instance of the bitfield from the given raw bytes. |
+//! | `fn try_from_bytes([u8; 1]) -> Result<Self, DecodeError>` | Creates a new instance of the bitfield from the given raw bytes after validating the bit patterns of all fields. |
+//! | `fn from_bytes_slice(&[u8]) -> Result<Self, FromSliceError>` | Creates a new instance of the bitfield from the prefix of the given byte slice. |
 //! | `fn into_bytes(self) -> [u8; 1]` | Returns the underlying bytes of the bitfield. |
+//! | `fn to_le_bytes(self) -> [u8; 1]` | Returns the underlying bytes of the bitfield in little-endian byte order. Also available as `to_be_bytes` and `to_ne_bytes`. |
+//! | `fn from_le_bytes([u8; 1]) -> Self` | Creates a new instance of the bitfield from bytes in little-endian byte order. Also available as `from_be_bytes` and `from_ne_bytes`. |
+//! | `fn as_bytes(&self) -> &[u8; 1]` | Returns a reference to the underlying bytes of the bitfield. |
+//! | `fn as_bytes_mut(&mut self) -> &mut [u8; 1]` | Returns a mutable reference to the underlying bytes of the bitfield. |
+//! | `fn clear(&mut self)` | Resets all bits of the bitfield to 0. |
+//! | `fn bit(&self, index: usize) -> bool` | Returns the bit at `index` or panics if `index` is out of bounds. |
+//! | `fn set_bit(&mut self, index: usize, value: bool)` | Sets the bit at `index` or panics if `index` is out of bounds. |
+//! | `fn set_flags(&self) -> impl Iterator<Item = &'static str>` | Iterates over the names of all `bool` fields that are set. Not generated if a field named `flags` has a setter. |
+//! | `fn is_zero(&self) -> bool` | Returns `true` if all bits of fields that are not reserved by `#[skip]` are clear. |
+//! | `fn any(&self) -> bool` | Returns `true` if any bit of a field that is not reserved by `#[skip]` is set. |
+//! | `fn swap_bytes(self) -> Self` | Returns the bitfield with the order of its bytes reversed. Only for bitfields with `filled = true`. |
+//! | `fn reverse_bits(self) -> Self` | Returns the bitfield with the order of all of its bits reversed. Only for bitfields with `filled = true`. |
+//! | `fn write_if_changed<E, F>(&self, current: &Self, sink: F) -> Result<bool, E>` | Hands the bytes to `sink` only if they differ from `current` and returns whether they were written. |
+//! | `const BITS: usize` | The number of bits of the bitfield, here `8`. |
+//! | `const BYTES: usize` | The number of bytes storing the bitfield, here `1`. |
+//! | `const LAYOUT: &[FieldDescriptor]` | Describes the name, bit offset, width and kind of all fields. See the `layout` module. |
+//! | `const LAYOUT_HASH: u64` | A fingerprint of the names, order and widths of all fields to detect layout drift. |
+//! | `impl Reflect` | Reads and writes the raw bits of fields by their names via `get_field` and `set_field` iterates over them via `fields` and lists changed fields via `diff`. Only generated for public byte conversions or `reflect = true` and not for `cell = "volatile"`. |
+//! | `fn sample<R: Rng>(&mut R) -> Self` | Returns the bitfield with uniformly random valid values for all fields that are not reserved by `#[skip]`. Requires the `rand` crate feature. Not generated if a field named `sample` has a getter. |
+//! | `fn read_from<R: io::Read>(&mut R) -> io::Result<Self>` | Reads the bitfield from the given reader. Requires the `std` crate feature and the `std_io::BitfieldIo` trait in scope. |
+//! | `fn write_to<W: io::Write>(&self, &mut W) -> io::Result<()>` | Writes the bitfield to the given writer. Requires the `std` crate feature and the `std_io::BitfieldIo` trait in scope. |
+//! | `fn to_vec(&self) -> Vec<u8>` | Returns the bytes of the bitfield as a `Vec<u8>`. Requires the `alloc` crate feature and the `vec::BitfieldVec` trait in scope. |
+//! | `fn extend_bytes(&self, &mut Vec<u8>)` | Appends the bytes of the bitfield to the given buffer. Requires the `alloc` crate feature and the `vec::BitfieldVec` trait in scope. |
+//! | `fn from_slice(&[u8]) -> Result<Self, FromSliceError>` | Converts the prefix of the byte slice into the bitfield. Requires the `alloc` crate feature and the `vec::BitfieldVec` trait in scope. |
 //!
 //! And below the generated signatures for field `a`:
 //!
@@ -366,6 +401,11 @@
 //! | `fn set_a_checked(&mut self, new_value: bool) -> Result<(), OutOfBounds>` | Sets `a` to the new value of returns an out of bounds error. |
 //! | `fn with_a(self, new_value: bool) -> Self` | Similar to `set_a` but useful for method chaining. |
 //! | `fn with_a_checked(self, new_value: bool) -> Result<Self, OutOfBounds>` | Similar to `set_a_checked` but useful for method chaining. |
+//! | `fn clear_a(&mut self)` | Resets all bits of `a` to 0. |
+//! | `fn replace_a(&mut self, new_value: bool) -> bool` | Sets `a` to the new value and returns its previous value. |
+//! | `fn take_a(&mut self) -> bool` | Returns the value of `a` and resets its bits to 0 or to its bits of the `default` parameter. |
+//! | `fn set_a_from(&mut self, new_value: impl Into<bool>)` | Similar to `set_a` but converts the given value first. |
+//! | `fn with_a_from(self, new_value: impl Into<bool>) -> Self` | Similar to `set_a_from` but useful for method chaining. |
 //!
 //! ## Generated Structure
 //!
@@ -415,11 +455,32 @@
 #![no_std]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 extern crate static_assertions;
 
+#[cfg(feature = "critical-section")]
+pub mod critical_section;
+pub mod dynamic;
+#[cfg(feature = "embedded-io")]
+pub mod embedded_io;
 pub mod error;
+pub mod flags;
+pub mod io;
+pub mod layout;
+pub mod packed;
 #[doc(hidden)]
 pub mod private;
+pub mod raw;
+pub mod uint;
+#[cfg(feature = "std")]
+pub mod std_io;
+#[cfg(feature = "alloc")]
+pub mod vec;
 
 use self::error::{
     InvalidBitPattern,
@@ -427,6 +488,9 @@ use self::error::{
 };
 pub use modular_bitfield_impl::{
     bitfield,
+    bitfield_layout,
+    bitfield_trait,
+    svd_registers,
     BitfieldSpecifier,
 };
 
@@ -434,7 +498,10 @@ pub use modular_bitfield_impl::{
 pub mod prelude {
     pub use super::{
         bitfield,
+        bitfield_layout,
+        bitfield_trait,
         specifiers::*,
+        svd_registers,
         BitfieldSpecifier,
         Specifier,
     };
@@ -490,6 +557,52 @@ pub trait Specifier {
     ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>>;
 }
 
+/// Trait implemented by all `#[bitfield]` structs with public byte conversions.
+///
+/// Should generally not be implemented directly by users
+/// but through the `#[bitfield]` macro provided by the crate.
+///
+/// # Note
+///
+/// Allows to generically operate on the underlying bytes of bitfields,
+/// e.g. in order to read or write them from and to I/O streams.
+pub trait Bitfield: Sized {
+    /// The byte array storing the bitfield, e.g. `[u8; 4]`.
+    type Bytes: AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Returns a byte array with all bits set to zero.
+    fn zeroed_bytes() -> Self::Bytes;
+
+    /// Returns a shared reference to the underlying bytes of the bitfield.
+    fn as_bytes(&self) -> &Self::Bytes;
+
+    /// Converts the bitfield into its underlying bytes.
+    fn into_bytes(self) -> Self::Bytes;
+
+    /// Converts the given bytes into the bitfield.
+    ///
+    /// # Errors
+    ///
+    /// If the given bytes contain bits at positions that are undefined for the bitfield.
+    fn from_bytes(bytes: Self::Bytes) -> Result<Self, OutOfBounds>;
+}
+
+/// Trait implemented by types that access a `#[bitfield]` in place,
+/// such as the `FooPtr` types generated with `ptr_view = true`.
+///
+/// Should generally not be implemented directly by users
+/// but through the `#[bitfield]` macro provided by the crate.
+pub trait VolatileAccess {
+    /// The accessed bitfield type.
+    type Bitfield;
+
+    /// Reads the entire bitfield.
+    fn read(&self) -> Self::Bitfield;
+
+    /// Writes the entire bitfield.
+    fn write(&self, value: Self::Bitfield);
+}
+
 /// The default set of predefined specifiers.
 pub mod specifiers {
     ::modular_bitfield_impl::define_specifiers!();
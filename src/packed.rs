@@ -0,0 +1,353 @@
+//! Densely packed arrays of bit specifiers.
+//!
+//! Useful for fields holding several small values back-to-back, e.g. buffers of 12-bit samples.
+
+use crate::{
+    error::{
+        InvalidBitPattern,
+        OutOfBounds,
+    },
+    private::WordAccess,
+    Specifier,
+};
+use core::marker::PhantomData;
+
+/// An array of `N` elements of specifier `T` stored back-to-back without padding.
+///
+/// The first element occupies the least significant bits. Just like every other
+/// specifier a packed array can be at most 128 bits wide and can be used as the type
+/// of a `#[bitfield]` field. Wider packed arrays are rejected at compile time.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{
+///     packed::PackedArray,
+///     prelude::*,
+/// };
+///
+/// #[bitfield]
+/// pub struct Samples {
+///     channel: B4,
+///     values: PackedArray<B12, 5>,
+/// }
+///
+/// let mut values = PackedArray::<B12, 5>::new();
+/// values.set(0, 0xABC);
+/// values.set(4, 0xFFF);
+/// let samples = Samples::new().with_channel(3).with_values(values);
+/// assert_eq!(samples.values().get(0), 0xABC);
+/// assert_eq!(samples.values().iter().collect::<Vec<_>>(), [0xABC, 0, 0, 0, 0xFFF]);
+/// ```
+pub struct PackedArray<T, const N: usize> {
+    bits: u128,
+    marker: PhantomData<fn() -> T>,
+}
+
+/// Rejects packed arrays whose elements occupy more than 128 bits at compile time.
+///
+/// Their bits would not fit into the `u128` storing the elements.
+struct PackedArrayCheck<T, const N: usize>(PhantomData<fn() -> T>);
+
+impl<T, const N: usize> PackedArrayCheck<T, N>
+where
+    T: Specifier,
+{
+    const ASSERT: () = assert!(
+        <T as Specifier>::BITS * N <= 128,
+        "packed arrays cannot occupy more than 128 bits"
+    );
+}
+
+impl<T, const N: usize> PackedArray<T, N>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    /// Creates a new packed array with all bits set to zero.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_bits(0)
+    }
+
+    /// Creates a new packed array from the given raw bits.
+    ///
+    /// Bits above the elements of the array are ignored.
+    #[inline]
+    pub fn from_bits(bits: u128) -> Self {
+        let mask = match <Self as Specifier>::BITS >= 128 {
+            true => u128::MAX,
+            false => (1 << <Self as Specifier>::BITS) - 1,
+        };
+        Self {
+            bits: bits & mask,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the raw bits of all elements of the array.
+    #[inline]
+    pub fn into_bits(self) -> u128 {
+        self.bits
+    }
+
+    /// Returns the number of elements of the array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the array has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the raw bits of the element at the given index.
+    fn raw(&self, index: usize) -> T::Bytes {
+        assert!(
+            index < N,
+            "index {} is out of bounds for packed array of length {}",
+            index,
+            N
+        );
+        let bits = <T as Specifier>::BITS;
+        let mask = match bits >= 128 {
+            true => u128::MAX,
+            false => (1 << bits) - 1,
+        };
+        <T::Bytes as WordAccess>::from_u128((self.bits >> (index * bits)) & mask)
+    }
+
+    /// Returns the element at the given index.
+    ///
+    /// # Panics
+    ///
+    /// - If the index is out of bounds.
+    /// - If the element holds an invalid bit pattern for `T`.
+    #[inline]
+    pub fn get(&self, index: usize) -> T::InOut {
+        match self.get_or_err(index) {
+            Ok(value) => value,
+            Err(_) => {
+                panic!("value contains invalid bit pattern for packed array element")
+            }
+        }
+    }
+
+    /// Returns the element at the given index.
+    ///
+    /// # Errors
+    ///
+    /// If the element holds an invalid bit pattern for `T`.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    #[inline]
+    pub fn get_or_err(
+        &self,
+        index: usize,
+    ) -> Result<T::InOut, InvalidBitPattern<T::Bytes>> {
+        <T as Specifier>::from_bytes(self.raw(index))
+    }
+
+    /// Sets the element at the given index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// - If the index is out of bounds.
+    /// - If the value is out of bounds for `T`.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: T::InOut) {
+        self.set_checked(index, value)
+            .expect("value out of bounds for packed array element")
+    }
+
+    /// Sets the element at the given index to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the value is out of bounds for `T`.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    #[inline]
+    pub fn set_checked(
+        &mut self,
+        index: usize,
+        value: T::InOut,
+    ) -> Result<(), OutOfBounds> {
+        assert!(
+            index < N,
+            "index {} is out of bounds for packed array of length {}",
+            index,
+            N
+        );
+        let raw =
+            <T::Bytes as WordAccess>::into_u128(<T as Specifier>::into_bytes(value)?);
+        let bits = <T as Specifier>::BITS;
+        let mask = match bits >= 128 {
+            true => u128::MAX,
+            false => (1 << bits) - 1,
+        };
+        if raw & !mask != 0 {
+            return Err(OutOfBounds::with_value(raw, bits))
+        }
+        let shift = index * bits;
+        self.bits = (self.bits & !(mask << shift)) | (raw << shift);
+        Ok(())
+    }
+
+    /// Returns an iterator over all elements of the array.
+    ///
+    /// # Note
+    ///
+    /// The iterator panics upon elements holding an invalid bit pattern for `T`.
+    #[inline]
+    pub fn iter(&self) -> Iter<T, N> {
+        Iter {
+            array: *self,
+            index: 0,
+        }
+    }
+}
+
+impl<T, const N: usize> Clone for PackedArray<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for PackedArray<T, N> {}
+
+impl<T, const N: usize> PartialEq for PackedArray<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<T, const N: usize> Eq for PackedArray<T, N> {}
+
+impl<T, const N: usize> Default for PackedArray<T, N>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for PackedArray<T, N>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    /// Prints the raw bits of every element of the array.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list()
+            .entries(
+                (0..N).map(|index| <T::Bytes as WordAccess>::into_u128(self.raw(index))),
+            )
+            .finish()
+    }
+}
+
+impl<T, const N: usize> Specifier for PackedArray<T, N>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    const BITS: usize = {
+        let () = PackedArrayCheck::<T, N>::ASSERT;
+        <T as Specifier>::BITS * N
+    };
+    type Bytes = u128;
+    type InOut = Self;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        Ok(input.bits)
+    }
+
+    #[inline]
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        Ok(Self::from_bits(bytes))
+    }
+}
+
+/// Iterator over the elements of a [`PackedArray`].
+pub struct Iter<T, const N: usize>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    array: PackedArray<T, N>,
+    index: usize,
+}
+
+impl<T, const N: usize> Clone for Iter<T, N>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array,
+            index: self.index,
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for Iter<T, N>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    type Item = T::InOut;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= N {
+            return None
+        }
+        let value = self.array.get(self.index);
+        self.index += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = N - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Iter<T, N>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+}
+
+impl<T, const N: usize> IntoIterator for PackedArray<T, N>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    type Item = T::InOut;
+    type IntoIter = Iter<T, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
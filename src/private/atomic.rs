@@ -0,0 +1,138 @@
+use core::sync::atomic::Ordering;
+
+/// Maps the byte array of a `#[bitfield]` struct to the atomic integer type of the same size.
+///
+/// The bytes are stored in little endian order within the atomic integer so that
+/// their bit positions agree with the bit positions of the bitfield.
+pub trait AtomicBytes: Sized {
+    /// The atomic integer type that stores the bytes.
+    type Atomic;
+
+    /// The zero initialized atomic integer.
+    #[allow(clippy::declare_interior_mutable_const)]
+    const ZERO: Self::Atomic;
+
+    /// Creates a new atomic integer from the given bytes.
+    fn new(bytes: Self) -> Self::Atomic;
+
+    /// Returns the bytes of the consumed atomic integer.
+    fn into_inner(atomic: Self::Atomic) -> Self;
+
+    /// Loads the bytes from the atomic integer.
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+
+    /// Stores the bytes into the atomic integer.
+    fn store(atomic: &Self::Atomic, bytes: Self, order: Ordering);
+
+    /// Atomically updates the bytes of the atomic integer with the given closure.
+    ///
+    /// Returns the previous bytes if `f` returned `Some` and otherwise `Err` with the current bytes.
+    fn fetch_update<F>(
+        atomic: &Self::Atomic,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        f: F,
+    ) -> Result<Self, Self>
+    where
+        F: FnMut(Self) -> Option<Self>;
+
+    /// Stores `new` into the atomic integer if it currently holds `current`.
+    ///
+    /// Returns the previous bytes on success and otherwise `Err` with the current bytes.
+    fn compare_exchange(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+}
+
+macro_rules! impl_atomic_bytes {
+    ( $( ($width:literal, $atomic:ident, $prim:ty) ),* $(,)? ) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicBytes for [u8; core::mem::size_of::<$prim>()] {
+                type Atomic = core::sync::atomic::$atomic;
+
+                #[allow(clippy::declare_interior_mutable_const)]
+                const ZERO: Self::Atomic = core::sync::atomic::$atomic::new(0);
+
+                #[inline]
+                fn new(bytes: Self) -> Self::Atomic {
+                    core::sync::atomic::$atomic::new(<$prim>::from_le_bytes(bytes))
+                }
+
+                #[inline]
+                fn into_inner(atomic: Self::Atomic) -> Self {
+                    atomic.into_inner().to_le_bytes()
+                }
+
+                #[inline]
+                fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                    atomic.load(order).to_le_bytes()
+                }
+
+                #[inline]
+                fn store(atomic: &Self::Atomic, bytes: Self, order: Ordering) {
+                    atomic.store(<$prim>::from_le_bytes(bytes), order)
+                }
+
+                #[inline]
+                fn fetch_update<F>(
+                    atomic: &Self::Atomic,
+                    set_order: Ordering,
+                    fetch_order: Ordering,
+                    mut f: F,
+                ) -> Result<Self, Self>
+                where
+                    F: FnMut(Self) -> Option<Self>,
+                {
+                    atomic
+                        .fetch_update(set_order, fetch_order, |prim| {
+                            f(prim.to_le_bytes()).map(<$prim>::from_le_bytes)
+                        })
+                        .map(<$prim>::to_le_bytes)
+                        .map_err(<$prim>::to_le_bytes)
+                }
+
+                #[inline]
+                fn compare_exchange(
+                    atomic: &Self::Atomic,
+                    current: Self,
+                    new: Self,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<Self, Self> {
+                    atomic
+                        .compare_exchange(
+                            <$prim>::from_le_bytes(current),
+                            <$prim>::from_le_bytes(new),
+                            success,
+                            failure,
+                        )
+                        .map(<$prim>::to_le_bytes)
+                        .map_err(<$prim>::to_le_bytes)
+                }
+            }
+        )*
+    };
+}
+impl_atomic_bytes!(
+    ("8", AtomicU8, u8),
+    ("16", AtomicU16, u16),
+    ("32", AtomicU32, u32),
+    ("64", AtomicU64, u64),
+);
+
+/// Returns the strongest ordering that is allowed as fetch ordering for the given set ordering.
+///
+/// Used by setters of atomic bitfields that only take a single ordering.
+#[inline]
+pub fn fetch_order(set_order: Ordering) -> Ordering {
+    match set_order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
@@ -12,74 +12,6 @@ macro_rules! impl_sealed_for {
 }
 impl_sealed_for!(bool, u8, u16, u32, u64, u128);
 
-/// Helper trait to check whether the size of bitfield structs
-/// is a multiple of 8 to form complete bytes.
-pub trait TotalSizeIsMultipleOfEightBits: private::Sealed {}
-
-/// Helper trait used to check whether a bitfield struct does not
-/// fill its entire value space, e.g. has undefined bits.
-pub trait TotalSizeIsNotMultipleOfEightBits: private::Sealed {}
-
-/// Helper trait to improve compile error messages.
-pub trait RenameSizeType: private::Sealed {
-    type CheckType;
-}
-
-/// Helper type to sum up bit size of a bitfield at compile time.
-pub struct TotalSize<T>(::core::marker::PhantomData<T>);
-
-macro_rules! impl_total_size_for {
-    ( $(($n:expr, $name:ident)),* ) => {
-        $(
-            pub enum $name {}
-            impl private::Sealed for TotalSize<[(); $n]> {}
-            impl private::Sealed for $name {}
-            impl RenameSizeType for TotalSize<[(); $n]> {
-                type CheckType = $name;
-            }
-        )*
-    }
-}
-
-impl_total_size_for!(
-    (0, ZeroMod8),
-    (1, OneMod8),
-    (2, TwoMod8),
-    (3, ThreeMod8),
-    (4, FourMod8),
-    (5, FiveMod8),
-    (6, SixMod8),
-    (7, SevenMod8)
-);
-
-impl TotalSizeIsMultipleOfEightBits for ZeroMod8 {}
-
-impl TotalSizeIsNotMultipleOfEightBits for OneMod8 {}
-impl TotalSizeIsNotMultipleOfEightBits for TwoMod8 {}
-impl TotalSizeIsNotMultipleOfEightBits for ThreeMod8 {}
-impl TotalSizeIsNotMultipleOfEightBits for FourMod8 {}
-impl TotalSizeIsNotMultipleOfEightBits for FiveMod8 {}
-impl TotalSizeIsNotMultipleOfEightBits for SixMod8 {}
-impl TotalSizeIsNotMultipleOfEightBits for SevenMod8 {}
-
-/// Public facing trait implemented by bitfield structs in order to let the compiler
-/// check if their sizes match a multiple of 8.
-pub trait CheckTotalSizeMultipleOf8
-where
-    <Self::Size as RenameSizeType>::CheckType: TotalSizeIsMultipleOfEightBits,
-{
-    type Size: RenameSizeType;
-}
-
-/// Public facing trait implemented by bitfield structs in order to let the compiler
-/// check if their sizes does not match a multiple of 8.
-pub trait CheckTotalSizeIsNotMultipleOf8
-where
-    <Self::Size as RenameSizeType>::CheckType: TotalSizeIsNotMultipleOfEightBits,
-{
-    type Size: RenameSizeType;
-}
-
 /// Helper trait to check if an enum discriminant of a bitfield specifier
 /// is within valid bounds.
 pub trait DiscriminantInRange: private::Sealed {}
@@ -106,8 +38,6 @@ pub enum False {}
 impl private::Sealed for True {}
 impl DiscriminantInRange for True {}
 impl SpecifierHasAtMost128Bits for True {}
-impl FillsUnalignedBits for True {}
-impl DoesNotFillUnalignedBits for True {}
 
 /// Helper trait to improve compile time error messages.
 pub trait DispatchTrueFalse: private::Sealed {
@@ -149,20 +79,141 @@ pub struct BitsCheck<A> {
     pub arr: A,
 }
 
-pub trait CheckFillsUnalignedBits
-where
-    <Self::CheckType as DispatchTrueFalse>::Out: FillsUnalignedBits,
-{
-    type CheckType: DispatchTrueFalse;
+/// Compile time error message of a failed size check of a `#[bitfield]` struct.
+struct SizeMessage {
+    bytes: [u8; 192],
+    len: usize,
 }
 
-pub trait FillsUnalignedBits {}
+impl SizeMessage {
+    /// Creates an empty message.
+    const fn new() -> Self {
+        Self {
+            bytes: [0x00; 192],
+            len: 0,
+        }
+    }
 
-pub trait CheckDoesNotFillUnalignedBits
-where
-    <Self::CheckType as DispatchTrueFalse>::Out: DoesNotFillUnalignedBits,
-{
-    type CheckType: DispatchTrueFalse;
+    /// Appends the given string to the message.
+    const fn push_str(mut self, string: &str) -> Self {
+        let string = string.as_bytes();
+        let mut i = 0;
+        while i < string.len() && self.len < self.bytes.len() {
+            self.bytes[self.len] = string[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends the decimal representation of the given value to the message.
+    const fn push_usize(mut self, mut value: usize) -> Self {
+        let mut digits = [0x00; 20];
+        let mut n = 0;
+        loop {
+            digits[n] = b'0' + (value % 10) as u8;
+            n += 1;
+            value /= 10;
+            if value == 0 {
+                break
+            }
+        }
+        while n > 0 && self.len < self.bytes.len() {
+            n -= 1;
+            self.bytes[self.len] = digits[n];
+            self.len += 1;
+        }
+        self
+    }
+
+    /// Returns the message as string.
+    const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(message) => message,
+            Err(_) => "encountered invalid #[bitfield] size",
+        }
+    }
+}
+
+/// Checks at compile time that a `#[bitfield]` struct with `filled = true`
+/// has a total size of a multiple of 8 bits.
+///
+/// # Panics
+///
+/// If `bits` is not a multiple of 8 with a message stating the required padding.
+pub const fn check_multiple_of_8_bits(bits: usize) {
+    if bits % 8 != 0 {
+        let message = SizeMessage::new()
+            .push_str("#[bitfield] expected a multiple of 8 bits, found ")
+            .push_usize(bits)
+            .push_str(" bits; consider adding B")
+            .push_usize(8 - bits % 8)
+            .push_str(" padding or using `filled = false`");
+        panic!("{}", message.as_str())
+    }
+}
+
+/// Checks at compile time that a `#[bitfield]` struct with `filled = false`
+/// does not have a total size of a multiple of 8 bits.
+///
+/// # Panics
+///
+/// If `bits` is a multiple of 8.
+pub const fn check_not_multiple_of_8_bits(bits: usize) {
+    if bits % 8 == 0 {
+        let message = SizeMessage::new()
+            .push_str("#[bitfield(filled = false)] expected a size that is not a multiple of 8 bits, found ")
+            .push_usize(bits)
+            .push_str(" bits; consider removing `filled = false`");
+        panic!("{}", message.as_str())
+    }
 }
 
-pub trait DoesNotFillUnalignedBits {}
+/// Checks at compile time that a `#[bitfield(bits = N)]` struct with `filled = true`
+/// has a total size of exactly `N` bits.
+///
+/// # Panics
+///
+/// If `bits` is not equal to `expected`.
+pub const fn check_exact_bits(expected: usize, bits: usize) {
+    if bits != expected {
+        let message = SizeMessage::new()
+            .push_str("#[bitfield(bits = ")
+            .push_usize(expected)
+            .push_str(")] expected exactly ")
+            .push_usize(expected)
+            .push_str(" bits, found ")
+            .push_usize(bits)
+            .push_str(" bits");
+        let message = match bits < expected {
+            true => {
+                message
+                    .push_str("; consider adding B")
+                    .push_usize(expected - bits)
+                    .push_str(" padding")
+            }
+            false => message,
+        };
+        panic!("{}", message.as_str())
+    }
+}
+
+/// Checks at compile time that a `#[bitfield(bits = N)]` struct with `filled = false`
+/// has a total size of fewer than `N` bits.
+///
+/// # Panics
+///
+/// If `bits` is not less than `expected`.
+pub const fn check_fewer_bits(expected: usize, bits: usize) {
+    if bits >= expected {
+        let message = SizeMessage::new()
+            .push_str("#[bitfield(bits = ")
+            .push_usize(expected)
+            .push_str(", filled = false)] expected fewer than ")
+            .push_usize(expected)
+            .push_str(" bits, found ")
+            .push_usize(bits)
+            .push_str(" bits");
+        panic!("{}", message.as_str())
+    }
+}
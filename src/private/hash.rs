@@ -0,0 +1,30 @@
+//! Implements `hash32::Hash` for `#[bitfield]` structs if the `hash32` crate feature is enabled.
+//!
+//! The generated code always invokes `__bf_impl_hash32!` which only expands
+//! to an impl if the `hash32` crate feature is enabled.
+
+/// Implements `hash32::Hash` for the given bitfield type by hashing its underlying bytes.
+#[cfg(feature = "hash32")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bf_impl_hash32 {
+    ( $ty:ident, $storage:ident ) => {
+        impl $crate::private::hash32::Hash for $ty {
+            #[inline]
+            fn hash<H>(&self, state: &mut H)
+            where
+                H: $crate::private::hash32::Hasher,
+            {
+                $crate::private::hash32::Hasher::write(state, &self.$storage[..]);
+            }
+        }
+    };
+}
+
+/// Expands to nothing since the `hash32` crate feature is disabled.
+#[cfg(not(feature = "hash32"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bf_impl_hash32 {
+    ( $( $tt:tt )* ) => {};
+}
@@ -0,0 +1,21 @@
+/// Formats the wrapped bytes as a list of hexadecimal numbers, e.g. `[0x0F, 0xA0]`.
+///
+/// Used by the `Debug` implementation of bitfields with `debug = "raw"`.
+pub struct HexBytes<'a>(pub &'a [u8]);
+
+impl core::fmt::Debug for HexBytes<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().copied().map(HexByte))
+            .finish()
+    }
+}
+
+/// Formats the wrapped byte as a hexadecimal number, e.g. `0x0F`.
+struct HexByte(u8);
+
+impl core::fmt::Debug for HexByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:#04X}", self.0)
+    }
+}
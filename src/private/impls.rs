@@ -23,7 +23,7 @@ impl Specifier for bool {
         match bytes {
             0 => Ok(false),
             1 => Ok(true),
-            invalid_bytes => Err(InvalidBitPattern { invalid_bytes }),
+            invalid_bytes => Err(InvalidBitPattern::new(invalid_bytes)),
         }
     }
 }
@@ -56,3 +56,45 @@ impl_specifier_for_primitive!(
     (u64: 64),
     (u128: 128),
 );
+
+/// Rejects byte arrays with more than 16 bytes at compile time.
+///
+/// Their bits would not fit into the `u128` used as `Bytes` of the byte array specifier.
+struct ByteArrayCheck<const N: usize>;
+
+impl<const N: usize> ByteArrayCheck<N> {
+    const ASSERT: () = assert!(N <= 16, "byte array specifiers support at most 16 bytes");
+}
+
+/// Opaque byte blobs such as MAC addresses or UUIDs of up to 16 bytes.
+///
+/// The first byte of the array occupies the least significant bits of the field.
+impl<const N: usize> Specifier for [u8; N] {
+    const BITS: usize = {
+        let () = ByteArrayCheck::<N>::ASSERT;
+        N * 8
+    };
+    type Bytes = u128;
+    type InOut = [u8; N];
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        let () = ByteArrayCheck::<N>::ASSERT;
+        let mut buffer = [0x00; 16];
+        buffer[..N].copy_from_slice(&input);
+        Ok(u128::from_le_bytes(buffer))
+    }
+
+    #[inline]
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        let () = ByteArrayCheck::<N>::ASSERT;
+        if N < 16 && bytes >> (N * 8) != 0 {
+            return Err(InvalidBitPattern::new(bytes))
+        }
+        let mut array = [0x00; N];
+        array.copy_from_slice(&bytes.to_le_bytes()[..N]);
+        Ok(array)
+    }
+}
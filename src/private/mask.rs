@@ -0,0 +1,45 @@
+/// Returns the bytes of a bitfield with all bits of the given fields set.
+///
+/// Every field is given as a pair of its bit offset and its bit width.
+///
+/// Used by the mask constants of bitfields with `#[group = ".."]` fields.
+pub const fn mask_bytes<const N: usize>(fields: &[(usize, usize)]) -> [u8; N] {
+    let mut bytes = [0x00; N];
+    let mut i = 0;
+    while i < fields.len() {
+        let (offset, bits) = fields[i];
+        let mut bit = offset;
+        while bit < offset + bits {
+            bytes[bit / 8] |= 0x01 << (bit % 8);
+            bit += 1;
+        }
+        i += 1;
+    }
+    bytes
+}
+
+/// Returns the bytes of a bitfield initialized to the given little-endian reset value.
+///
+/// Used by the constructor of bitfields with the `default` parameter.
+///
+/// # Panics
+///
+/// If the reset value sets bits beyond the first `bits` bits of the bitfield.
+pub const fn reset_bytes<const N: usize>(value: &[u8], bits: usize) -> [u8; N] {
+    let mut bytes = [0x00; N];
+    let mut i = 0;
+    while i < value.len() {
+        let mut bit = 0;
+        while bit < 8 {
+            if value[i] & (0x01 << bit) != 0 && i * 8 + bit >= bits {
+                panic!("reset value of the `default` parameter is out of bounds for the bitfield")
+            }
+            bit += 1;
+        }
+        if i < N {
+            bytes[i] = value[i];
+        }
+        i += 1;
+    }
+    bytes
+}
@@ -0,0 +1,64 @@
+use crate::layout::{
+    FieldDescriptor,
+    FieldKind,
+};
+
+/// Returns `true` if the given names are equal.
+const fn names_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if the raw bits of a field of kind `from` with `from_bits` bits
+/// can be copied into a field of kind `into` with `into_bits` bits without losing data.
+///
+/// Unsigned integers may be widened while the bits of all other kinds must match exactly
+/// since their bit patterns are not validated when copied.
+const fn is_lossless_field(
+    from: FieldKind,
+    from_bits: usize,
+    into: FieldKind,
+    into_bits: usize,
+) -> bool {
+    match (from, into) {
+        (FieldKind::Unsigned, FieldKind::Unsigned) => from_bits <= into_bits,
+        (FieldKind::Bool, FieldKind::Bool) | (FieldKind::Specifier, FieldKind::Specifier) => {
+            from_bits == into_bits
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if all fields of the `from` layout that are not skipped can be copied
+/// into the fields of the `into` layout with the same names without losing data.
+///
+/// Used by the compile-time checks of the `From` impls generated for `migrate_from`.
+pub const fn is_lossless_migration(from: &[FieldDescriptor], into: &[FieldDescriptor]) -> bool {
+    let mut i = 0;
+    while i < from.len() {
+        let source = &from[i];
+        let mut j = 0;
+        while !source.skipped && j < into.len() {
+            let target = &into[j];
+            if !target.skipped
+                && names_eq(source.name, target.name)
+                && !is_lossless_field(source.kind, source.bits, target.kind, target.bits)
+            {
+                return false
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
@@ -1,22 +1,72 @@
 mod array_bytes_conv;
+mod atomic;
 pub mod checks;
+mod hash;
+mod hex;
 mod impls;
+mod mask;
+mod migrate;
+mod narrow;
+mod perm;
 mod proc;
-mod push_pop;
+mod reflect;
+mod sample;
+mod text;
 mod traits;
+mod unaligned;
+mod verbose;
+#[cfg(feature = "ux")]
+mod ux;
+mod word;
+
+#[cfg(feature = "vcell")]
+pub use vcell::VolatileCell;
+#[cfg(feature = "zerocopy")]
+pub use zerocopy::Unaligned;
+#[cfg(feature = "rand")]
+pub use self::sample::sample_specifier;
+#[cfg(feature = "rand")]
+pub use rand;
+#[cfg(feature = "hash32")]
+pub use hash32;
+
+pub(crate) use self::word::{
+    read_bits_shared,
+    write_bits_shared,
+};
 
 pub mod static_assertions {
     pub use static_assertions::*;
 }
 pub use self::{
     array_bytes_conv::ArrayBytesConversion,
+    atomic::{
+        fetch_order,
+        AtomicBytes,
+    },
+    hex::HexBytes,
+    mask::{
+        mask_bytes,
+        reset_bytes,
+    },
+    migrate::is_lossless_migration,
+    narrow::NarrowedPrimitive,
+    perm::reverse_words,
+    text::{
+        fmt_text,
+        parse_text,
+    },
     proc::{
         read_specifier,
+        read_specifier_shared,
         write_specifier,
+        write_specifier_shared,
     },
-    push_pop::{
-        PopBuffer,
-        PushBuffer,
+    reflect::{
+        copy_fields,
+        get_field,
+        set_field,
+        RawFields,
     },
     traits::{
         IsU128Compatible,
@@ -24,8 +74,8 @@ pub use self::{
         IsU32Compatible,
         IsU64Compatible,
         IsU8Compatible,
-        PopBits,
-        PushBits,
         SpecifierBytes,
+        WordAccess,
     },
+    verbose::VerboseField,
 };
@@ -0,0 +1,47 @@
+use crate::{
+    error::{
+        InvalidBitPattern,
+        OutOfBounds,
+    },
+    Specifier,
+};
+use core::marker::PhantomData;
+
+/// Specifier storing only the `N` least significant bits of the primitive `P`.
+///
+/// Used for fields of primitive integer types annotated with `#[bits = N]`
+/// so that their accessors keep operating on `P` while the layout stays packed.
+pub struct NarrowedPrimitive<P, const N: usize> {
+    marker: PhantomData<fn() -> P>,
+}
+
+macro_rules! impl_specifier_for_narrowed_primitive {
+    ( $( $prim:ty ),* $(,)? ) => {
+        $(
+            impl<const N: usize> Specifier for NarrowedPrimitive<$prim, N> {
+                const BITS: usize = N;
+                type Bytes = $prim;
+                type InOut = $prim;
+
+                #[inline]
+                fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+                    if N < ::core::mem::size_of::<$prim>() * 8 && input >> N != 0 {
+                        return Err(OutOfBounds::with_value(input as u128, N))
+                    }
+                    Ok(input)
+                }
+
+                #[inline]
+                fn from_bytes(
+                    bytes: Self::Bytes,
+                ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+                    if N < ::core::mem::size_of::<$prim>() * 8 && bytes >> N != 0 {
+                        return Err(InvalidBitPattern::new(bytes))
+                    }
+                    Ok(bytes)
+                }
+            }
+        )*
+    };
+}
+impl_specifier_for_narrowed_primitive!(u8, u16, u32, u64, u128);
@@ -0,0 +1,20 @@
+/// Reverses the order of the `word`-byte words of the given bytes while keeping
+/// the order of the bytes within every word.
+///
+/// Used by the byte permutation conversions of bitfields with the `byte_perm` parameter.
+/// Since the permutation is its own inverse it converts in both directions.
+///
+/// # Panics
+///
+/// If the number of bytes is not divisible by `word`.
+pub const fn reverse_words<const N: usize>(bytes: [u8; N], word: usize) -> [u8; N] {
+    assert!(N % word == 0, "number of bytes is not divisible by the word size");
+    let words = N / word;
+    let mut permuted = [0x00; N];
+    let mut i = 0;
+    while i < N {
+        permuted[(words - 1 - i / word) * word + i % word] = bytes[i];
+        i += 1;
+    }
+    permuted
+}
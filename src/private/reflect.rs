@@ -0,0 +1,98 @@
+use crate::{
+    error::{
+        FieldAccessError,
+        OutOfBounds,
+    },
+    layout::FieldDescriptor,
+    private::{
+        read_bits_shared,
+        write_bits_shared,
+    },
+};
+
+/// Raw access to the fields of a `#[bitfield]` struct by their names.
+///
+/// Implemented for all `#[bitfield]` structs except for those with `cell = "volatile"`.
+/// Backs the public `Reflect` trait without exposing the bytes of bitfields whose byte
+/// conversions are restricted by `bytes_vis`.
+pub trait RawFields {
+    /// The layout of the fields of the bitfield in declaration order.
+    const LAYOUT: &'static [FieldDescriptor];
+
+    /// Returns the bytes of the bitfield.
+    fn __bf_bytes(&self) -> &[u8];
+
+    /// Returns the bytes of the bitfield for writing.
+    fn __bf_bytes_mut(&mut self) -> &mut [u8];
+
+    /// Returns `true` if `value` is a valid bit pattern of the field with the given name.
+    fn __bf_is_valid(name: &str, value: u128) -> bool;
+}
+
+/// Returns the descriptor of the field with the given name unless it is skipped.
+#[inline]
+pub fn field_descriptor<T>(name: &str) -> Option<&'static FieldDescriptor>
+where
+    T: RawFields + ?Sized,
+{
+    T::LAYOUT
+        .iter()
+        .find(|field| field.name == name && !field.skipped)
+}
+
+/// Returns the raw bits of the field with the given name unless it is unknown or skipped.
+#[inline]
+pub fn get_field<T>(bitfield: &T, name: &str) -> Option<u128>
+where
+    T: RawFields + ?Sized,
+{
+    let field = field_descriptor::<T>(name)?;
+    Some(read_bits_shared(bitfield.__bf_bytes(), field.offset, field.bits))
+}
+
+/// Sets the raw bits of the field with the given name.
+///
+/// Fails for unknown or skipped fields, values that do not fit into the bits of the field
+/// and invalid bit patterns of enum or nested bitfield fields.
+#[inline]
+pub fn set_field<T>(bitfield: &mut T, name: &str, value: u128) -> Result<(), FieldAccessError>
+where
+    T: RawFields + ?Sized,
+{
+    let field = field_descriptor::<T>(name).ok_or(FieldAccessError::UnknownField)?;
+    if field.bits < 128 && value >> field.bits != 0 {
+        return Err(FieldAccessError::OutOfBounds(OutOfBounds::with_value(
+            value, field.bits,
+        )))
+    }
+    if !T::__bf_is_valid(field.name, value) {
+        return Err(FieldAccessError::InvalidBitPattern)
+    }
+    write_bits_shared(bitfield.__bf_bytes_mut(), field.offset, field.bits, value);
+    Ok(())
+}
+
+/// Copies the raw bits of all fields of `from` that are not skipped into the fields
+/// of `into` with the same names that are not skipped.
+///
+/// Values that do not fit into the bits of the field of `into` are truncated to its bits.
+/// Used by `Reflect::copy_fields_from` and the `From` impls generated for `migrate_from`.
+#[inline]
+pub fn copy_fields<T, U>(into: &mut T, from: &U)
+where
+    T: RawFields + ?Sized,
+    U: RawFields + ?Sized,
+{
+    for source in U::LAYOUT.iter().filter(|field| !field.skipped) {
+        let field = match field_descriptor::<T>(source.name) {
+            Some(field) => field,
+            None => continue,
+        };
+        let value = read_bits_shared(from.__bf_bytes(), source.offset, source.bits);
+        let value = match field.bits {
+            bits if bits < 128 => value & ((1u128 << bits) - 1),
+            _ => value,
+        };
+        write_bits_shared(into.__bf_bytes_mut(), field.offset, field.bits, value);
+    }
+}
@@ -0,0 +1,73 @@
+//! Generates `sample` for `#[bitfield]` structs if the `rand` crate feature is enabled.
+//!
+//! The generated code always invokes `__bf_impl_sample!` which only expands
+//! to an impl if the `rand` crate feature is enabled.
+
+#[cfg(feature = "rand")]
+use crate::{
+    private::{
+        write_specifier,
+        WordAccess,
+    },
+    Specifier,
+};
+
+/// Writes a uniformly random valid value of the specifier `T` starting at bit `offset`.
+///
+/// Invalid bit patterns, e.g. of enums with fewer variants than their bits allow,
+/// are rejected and sampled again so that all valid values are equally likely.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[inline]
+pub fn sample_specifier<T, R>(rng: &mut R, bytes: &mut [u8], offset: usize)
+where
+    T: Specifier,
+    T::Bytes: WordAccess + Copy,
+    R: rand::Rng + ?Sized,
+{
+    loop {
+        let raw = rng.gen::<u128>() >> (128 - <T as Specifier>::BITS);
+        let candidate = <T::Bytes as WordAccess>::from_u128(raw);
+        if <T as Specifier>::from_bytes(candidate).is_ok() {
+            write_specifier::<T>(bytes, offset, candidate);
+            return
+        }
+    }
+}
+
+/// Implements `sample` for the given bitfield type with the given fields and offsets.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bf_impl_sample {
+    (
+        $ty:ident { $( $init:tt )* } $storage:ident: [u8; $len:expr];
+        $( $( #[$cfg:meta] )* ($field_ty:ty, $offset:expr) )*
+    ) => {
+        impl $ty {
+            /// Returns the bitfield with uniformly random valid values for all of its fields.
+            ///
+            /// Bits of fields that are entirely `#[skip]`ped are zero.
+            #[allow(clippy::identity_op)]
+            pub fn sample<R>(rng: &mut R) -> Self
+            where
+                R: $crate::private::rand::Rng + ?::core::marker::Sized,
+            {
+                let mut bytes = [0u8; $len];
+                $(
+                    $( #[$cfg] )*
+                    $crate::private::sample_specifier::<$field_ty, R>(rng, &mut bytes[..], $offset);
+                )*
+                Self { $( $init )* $storage: bytes }
+            }
+        }
+    };
+}
+
+/// Expands to nothing since the `rand` crate feature is disabled.
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bf_impl_sample {
+    ( $( $tt:tt )* ) => {};
+}
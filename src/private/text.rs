@@ -0,0 +1,74 @@
+use crate::{
+    error::{
+        FieldAccessError,
+        ParseTextError,
+    },
+    layout::FieldKind,
+    private::{
+        read_bits_shared,
+        reflect::{
+            field_descriptor,
+            set_field,
+        },
+        RawFields,
+    },
+};
+use core::fmt;
+
+/// Writes the fields of the bitfield that are not skipped as `name=value` pairs separated by `,`.
+///
+/// Used by the `Display` impl of `#[bitfield(text = true)]` structs.
+pub fn fmt_text<T>(bitfield: &T, f: &mut fmt::Formatter) -> fmt::Result
+where
+    T: RawFields,
+{
+    let mut separator = "";
+    for field in T::LAYOUT.iter().filter(|field| !field.skipped) {
+        let value = read_bits_shared(bitfield.__bf_bytes(), field.offset, field.bits);
+        match field.kind {
+            FieldKind::Bool => write!(f, "{}{}={}", separator, field.name, value != 0)?,
+            _ => write!(f, "{}{}={}", separator, field.name, value)?,
+        }
+        separator = ",";
+    }
+    Ok(())
+}
+
+/// Parses a single value of a field of the given kind.
+fn parse_value(kind: FieldKind, text: &str) -> Option<u128> {
+    match (kind, text) {
+        (FieldKind::Bool, "true") => Some(1),
+        (FieldKind::Bool, "false") => Some(0),
+        (FieldKind::Bool, _) => None,
+        (_, text) => {
+            match text.strip_prefix("0x") {
+                Some(hex) => u128::from_str_radix(hex, 16).ok(),
+                None => text.parse::<u128>().ok(),
+            }
+        }
+    }
+}
+
+/// Sets the fields of the bitfield from `name=value` pairs separated by `,`.
+///
+/// Used by the `FromStr` impl of `#[bitfield(text = true)]` structs.
+/// Fields that are not mentioned are left untouched.
+pub fn parse_text<T>(bitfield: &mut T, text: &str) -> Result<(), ParseTextError>
+where
+    T: RawFields,
+{
+    for pair in text.split(',').map(str::trim).filter(|pair| !pair.is_empty()) {
+        let (name, value) = pair.split_once('=').ok_or(ParseTextError::MissingValue)?;
+        let field = field_descriptor::<T>(name.trim()).ok_or(ParseTextError::UnknownField)?;
+        let value =
+            parse_value(field.kind, value.trim()).ok_or(ParseTextError::InvalidValue)?;
+        set_field(bitfield, field.name, value).map_err(|error| {
+            match error {
+                FieldAccessError::UnknownField => ParseTextError::UnknownField,
+                FieldAccessError::OutOfBounds(error) => ParseTextError::OutOfBounds(error),
+                FieldAccessError::InvalidBitPattern => ParseTextError::InvalidValue,
+            }
+        })?;
+    }
+    Ok(())
+}
@@ -2,22 +2,29 @@ use super::checks;
 
 /// Helper trait for underlying primitives handling of bitfields.
 ///
-/// # Note
-///
-/// Must not and cannot be implemented by dependencies.
-#[doc(hidden)]
-pub trait PushBits: checks::private::Sealed {
-    fn push_bits(&mut self, amount: u32, bits: u8);
-}
-
-/// Helper trait for underlying primitives handling of bitfields.
+/// Reads and writes bits with a single word load and store of the covered
+/// bytes plus shifting and masking instead of operating on single bytes.
 ///
 /// # Note
 ///
 /// Must not and cannot be implemented by dependencies.
 #[doc(hidden)]
-pub trait PopBits: checks::private::Sealed {
-    fn pop_bits(&mut self, amount: u32) -> u8;
+pub trait WordAccess: checks::private::Sealed + Sized {
+    /// Reads `bits` bits starting at bit `offset` from the given bytes.
+    fn read_bits(bytes: &[u8], offset: usize, bits: usize) -> Self;
+
+    /// Writes the `bits` least significant bits of `value` starting at bit `offset` into the given bytes.
+    ///
+    /// All bits of the bytes outside of the written range are preserved.
+    /// The covered bytes are updated with a single masked read-modify-write so that
+    /// they never hold a partially written intermediate state of the field.
+    fn write_bits(bytes: &mut [u8], offset: usize, bits: usize, value: Self);
+
+    /// Converts the primitive into a `u128` without loss.
+    fn into_u128(self) -> u128;
+
+    /// Converts the `u128` into the primitive truncating all bits that do not fit.
+    fn from_u128(value: u128) -> Self;
 }
 
 /// Trait implemented by primitives that drive bitfield manipulations generically.
@@ -0,0 +1,26 @@
+//! Implements `zerocopy::Unaligned` for `#[bitfield(unaligned = true)]` structs.
+//!
+//! The generated code always invokes `__bf_impl_unaligned!` which only expands
+//! to an impl if the `zerocopy` crate feature is enabled.
+
+/// Implements `zerocopy::Unaligned` for the given align-1 bitfield type.
+///
+/// The alignment of the type is checked by the code generated for `unaligned = true`.
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bf_impl_unaligned {
+    ( $ty:ty ) => {
+        unsafe impl $crate::private::Unaligned for $ty {
+            fn only_derive_is_allowed_to_implement_this_trait() {}
+        }
+    };
+}
+
+/// Expands to nothing since the `zerocopy` crate feature is disabled.
+#[cfg(not(feature = "zerocopy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bf_impl_unaligned {
+    ( $ty:ty ) => {};
+}
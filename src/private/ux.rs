@@ -0,0 +1,128 @@
+//! Implements `Specifier` for the exact-width integers of the `ux` crate.
+//!
+//! Requires the `ux` crate feature.
+
+use crate::{
+    error::{
+        InvalidBitPattern,
+        OutOfBounds,
+    },
+    Specifier,
+};
+use core::convert::TryFrom as _;
+
+macro_rules! impl_specifier_for_ux_unsigned {
+    ( $prim:ty; $( $ty:ident: $bits:literal ),* $(,)? ) => {
+        $(
+            impl Specifier for ::ux::$ty {
+                const BITS: usize = $bits;
+                type Bytes = $prim;
+                type InOut = Self;
+
+                #[inline]
+                fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+                    Ok(<$prim>::from(input))
+                }
+
+                #[inline]
+                fn from_bytes(
+                    bytes: Self::Bytes,
+                ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+                    Self::try_from(bytes).map_err(|_| InvalidBitPattern::new(bytes))
+                }
+            }
+        )*
+    };
+}
+impl_specifier_for_ux_unsigned! {
+    u8;
+    u1: 1, u2: 2, u3: 3, u4: 4, u5: 5, u6: 6, u7: 7,
+}
+impl_specifier_for_ux_unsigned! {
+    u16;
+    u9: 9, u10: 10, u11: 11, u12: 12, u13: 13, u14: 14, u15: 15,
+}
+impl_specifier_for_ux_unsigned! {
+    u32;
+    u17: 17, u18: 18, u19: 19, u20: 20, u21: 21, u22: 22, u23: 23, u24: 24, u25: 25,
+    u26: 26, u27: 27, u28: 28, u29: 29, u30: 30, u31: 31,
+}
+impl_specifier_for_ux_unsigned! {
+    u64;
+    u33: 33, u34: 34, u35: 35, u36: 36, u37: 37, u38: 38, u39: 39, u40: 40, u41: 41,
+    u42: 42, u43: 43, u44: 44, u45: 45, u46: 46, u47: 47, u48: 48, u49: 49, u50: 50,
+    u51: 51, u52: 52, u53: 53, u54: 54, u55: 55, u56: 56, u57: 57, u58: 58, u59: 59,
+    u60: 60, u61: 61, u62: 62, u63: 63,
+}
+
+/// Signed integers are stored in two's complement within their `BITS` bits.
+macro_rules! impl_specifier_for_ux_signed {
+    ( $signed:ty, $prim:ty; $( $ty:ident: $bits:literal ),* $(,)? ) => {
+        $(
+            impl Specifier for ::ux::$ty {
+                const BITS: usize = $bits;
+                type Bytes = $prim;
+                type InOut = Self;
+
+                #[inline]
+                fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+                    let mask: $prim = (1 << $bits) - 1;
+                    Ok((<$signed>::from(input) as $prim) & mask)
+                }
+
+                #[inline]
+                fn from_bytes(
+                    bytes: Self::Bytes,
+                ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+                    if bytes >> $bits != 0 {
+                        return Err(InvalidBitPattern::new(bytes))
+                    }
+                    let shift = ::core::mem::size_of::<$prim>() * 8 - $bits;
+                    let value = ((bytes << shift) as $signed) >> shift;
+                    Self::try_from(value).map_err(|_| InvalidBitPattern::new(bytes))
+                }
+            }
+        )*
+    };
+}
+// `ux` provides no conversions between `i1` and `i8`, so its only two values
+// are mapped by hand.
+impl Specifier for ::ux::i1 {
+    const BITS: usize = 1;
+    type Bytes = u8;
+    type InOut = Self;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        Ok(u8::from(input == ::ux::i1::MIN))
+    }
+
+    #[inline]
+    fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        match bytes {
+            0 => Ok(::ux::i1::MAX),
+            1 => Ok(::ux::i1::MIN),
+            _ => Err(InvalidBitPattern::new(bytes)),
+        }
+    }
+}
+impl_specifier_for_ux_signed! {
+    i8, u8;
+    i2: 2, i3: 3, i4: 4, i5: 5, i6: 6, i7: 7,
+}
+impl_specifier_for_ux_signed! {
+    i16, u16;
+    i9: 9, i10: 10, i11: 11, i12: 12, i13: 13, i14: 14, i15: 15,
+}
+impl_specifier_for_ux_signed! {
+    i32, u32;
+    i17: 17, i18: 18, i19: 19, i20: 20, i21: 21, i22: 22, i23: 23, i24: 24, i25: 25,
+    i26: 26, i27: 27, i28: 28, i29: 29, i30: 30, i31: 31,
+}
+impl_specifier_for_ux_signed! {
+    i64, u64;
+    i33: 33, i34: 34, i35: 35, i36: 36, i37: 37, i38: 38, i39: 39, i40: 40, i41: 41,
+    i42: 42, i43: 43, i44: 44, i45: 45, i46: 46, i47: 47, i48: 48, i49: 49, i50: 50,
+    i51: 51, i52: 52, i53: 53, i54: 54, i55: 55, i56: 56, i57: 57, i58: 58, i59: 59,
+    i60: 60, i61: 61, i62: 62, i63: 63,
+}
@@ -0,0 +1,30 @@
+use core::fmt::Debug;
+
+/// Formats the value of a field followed by its bit range and raw bits in binary,
+/// e.g. `Green @ 0..2 = 0b01`.
+///
+/// Used by the `Debug` implementation of bitfields with `debug = "verbose"`.
+pub struct VerboseField<'a> {
+    /// The decoded value of the field.
+    pub value: &'a dyn Debug,
+    /// The offset of the first bit of the field.
+    pub offset: usize,
+    /// The number of bits of the field.
+    pub bits: usize,
+    /// The raw bits of the field.
+    pub raw: u128,
+}
+
+impl Debug for VerboseField<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.value.fmt(f)?;
+        write!(
+            f,
+            " @ {}..{} = 0b{:0width$b}",
+            self.offset,
+            self.offset + self.bits,
+            self.raw,
+            width = self.bits
+        )
+    }
+}
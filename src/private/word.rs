@@ -0,0 +1,140 @@
+use crate::private::WordAccess;
+
+/// Returns the range of bytes covered by `bits` bits starting at bit `offset`.
+///
+/// # Note
+///
+/// After inlining all inputs are compile-time constants for generated accessors.
+#[inline]
+fn covered_bytes(offset: usize, bits: usize) -> core::ops::Range<usize> {
+    let ls_byte = offset / 8;
+    let len = (offset % 8 + bits + 7) / 8;
+    ls_byte..(ls_byte + len)
+}
+
+macro_rules! impl_word_access {
+    ( $( ($prim:ty, $word:ty) ),* $(,)? ) => {
+        $(
+            impl WordAccess for $prim {
+                #[inline]
+                fn read_bits(bytes: &[u8], offset: usize, bits: usize) -> Self {
+                    debug_assert!(bits <= ::core::mem::size_of::<$prim>() * 8);
+                    let range = covered_bytes(offset, bits);
+                    let mut buffer = [0x00_u8; ::core::mem::size_of::<$word>()];
+                    buffer[..range.len()].copy_from_slice(&bytes[range]);
+                    let word = <$word>::from_le_bytes(buffer);
+                    let mask: $word = (0x01 << bits) - 1;
+                    ((word >> (offset % 8)) & mask) as $prim
+                }
+
+                #[inline]
+                fn write_bits(bytes: &mut [u8], offset: usize, bits: usize, value: Self) {
+                    debug_assert!(bits <= ::core::mem::size_of::<$prim>() * 8);
+                    let range = covered_bytes(offset, bits);
+                    let len = range.len();
+                    let mut buffer = [0x00_u8; ::core::mem::size_of::<$word>()];
+                    buffer[..len].copy_from_slice(&bytes[range.clone()]);
+                    let word = <$word>::from_le_bytes(buffer);
+                    let mask: $word = ((0x01 << bits) - 1) << (offset % 8);
+                    let word = (word & !mask) | (((value as $word) << (offset % 8)) & mask);
+                    bytes[range].copy_from_slice(&word.to_le_bytes()[..len]);
+                }
+
+                #[inline]
+                fn into_u128(self) -> u128 {
+                    self as u128
+                }
+
+                #[inline]
+                fn from_u128(value: u128) -> Self {
+                    value as $prim
+                }
+            }
+        )*
+    };
+}
+impl_word_access!((u8, u16), (u16, u32), (u32, u64), (u64, u128));
+
+impl WordAccess for u128 {
+    #[inline]
+    fn read_bits(bytes: &[u8], offset: usize, bits: usize) -> Self {
+        debug_assert!(bits <= 128);
+        if bits <= 64 {
+            return <u64 as WordAccess>::read_bits(bytes, offset, bits) as u128
+        }
+        let lo = <u64 as WordAccess>::read_bits(bytes, offset, 64);
+        let hi = <u64 as WordAccess>::read_bits(bytes, offset + 64, bits - 64);
+        (lo as u128) | ((hi as u128) << 64)
+    }
+
+    #[inline]
+    fn write_bits(bytes: &mut [u8], offset: usize, bits: usize, value: Self) {
+        debug_assert!(bits <= 128);
+        if bits <= 64 {
+            return <u64 as WordAccess>::write_bits(bytes, offset, bits, value as u64)
+        }
+        // Fields wider than 64 bits cover up to 17 bytes that are modified
+        // as a pair of words with a single load and store of the covered bytes.
+        let range = covered_bytes(offset, bits);
+        let len = range.len();
+        let mut buffer = [0x00_u8; 32];
+        buffer[..len].copy_from_slice(&bytes[range.clone()]);
+        let mut lo = [0x00_u8; 16];
+        let mut hi = [0x00_u8; 16];
+        lo.copy_from_slice(&buffer[..16]);
+        hi.copy_from_slice(&buffer[16..]);
+        let shift = (offset % 8) as u32;
+        let (mask_lo, mask_hi) = shl_wide(u128::MAX >> (128 - bits), shift);
+        let (value_lo, value_hi) = shl_wide(value, shift);
+        let lo = (u128::from_le_bytes(lo) & !mask_lo) | (value_lo & mask_lo);
+        let hi = (u128::from_le_bytes(hi) & !mask_hi) | (value_hi & mask_hi);
+        buffer[..16].copy_from_slice(&lo.to_le_bytes());
+        buffer[16..].copy_from_slice(&hi.to_le_bytes());
+        bytes[range].copy_from_slice(&buffer[..len]);
+    }
+
+    #[inline]
+    fn into_u128(self) -> u128 {
+        self
+    }
+
+    #[inline]
+    fn from_u128(value: u128) -> Self {
+        value
+    }
+}
+
+/// Shifts the given value to the left by `shift` bits as if it was a 256-bit integer.
+///
+/// Returns the low and high 128-bit halves of the result.
+#[inline]
+fn shl_wide(value: u128, shift: u32) -> (u128, u128) {
+    debug_assert!(shift < 128);
+    let hi = match shift {
+        0 => 0,
+        _ => value >> (128 - shift),
+    };
+    (value << shift, hi)
+}
+
+/// Reads `bits` bits starting at bit `offset` from the given bytes.
+///
+/// # Note
+///
+/// Shared by all accessors of bitfields with `min_code_size = true`
+/// and therefore never inlined.
+#[inline(never)]
+pub fn read_bits_shared(bytes: &[u8], offset: usize, bits: usize) -> u128 {
+    <u128 as WordAccess>::read_bits(bytes, offset, bits)
+}
+
+/// Writes the `bits` least significant bits of `value` starting at bit `offset` into the given bytes.
+///
+/// # Note
+///
+/// Shared by all accessors of bitfields with `min_code_size = true`
+/// and therefore never inlined.
+#[inline(never)]
+pub fn write_bits_shared(bytes: &mut [u8], offset: usize, bits: usize, value: u128) {
+    <u128 as WordAccess>::write_bits(bytes, offset, bits, value)
+}
@@ -0,0 +1,122 @@
+//! Functions to read and write bits of byte slices at arbitrary positions.
+//!
+//! These use the same bit order as the fields of `#[bitfield]` structs: bit `offset`
+//! is bit `offset % 8` of the byte at index `offset / 8` counting from the least significant bit.
+//! They are meant for manual bit manipulation next to generated bitfields.
+
+use crate::{
+    error::{
+        InvalidBitPattern,
+        OutOfBounds,
+    },
+    private::WordAccess,
+    Specifier,
+};
+
+/// Asserts that `bits` bits starting at bit `offset` are within the given bytes.
+#[inline]
+fn assert_in_bounds(bytes: &[u8], offset: usize, bits: usize) {
+    assert!(bits <= 128, "cannot access more than 128 bits at once");
+    assert!(
+        offset + bits <= bytes.len() * 8,
+        "bits {}..{} are out of bounds for {} bytes",
+        offset,
+        offset + bits,
+        bytes.len()
+    );
+}
+
+/// Reads `bits` bits starting at bit `offset` from the given bytes.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::raw;
+///
+/// assert_eq!(raw::read(&[0b1010_0000, 0b0000_0011], 5, 5), 0b11_101);
+/// ```
+///
+/// # Panics
+///
+/// - If `bits` is greater than 128.
+/// - If the bits are out of bounds for the given bytes.
+#[inline]
+pub fn read(bytes: &[u8], offset: usize, bits: usize) -> u128 {
+    assert_in_bounds(bytes, offset, bits);
+    <u128 as WordAccess>::read_bits(bytes, offset, bits)
+}
+
+/// Writes the `bits` least significant bits of `value` starting at bit `offset` into the given bytes.
+///
+/// All other bits of the given bytes are left unchanged.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::raw;
+///
+/// let mut bytes = [0x00; 2];
+/// raw::write(&mut bytes, 5, 5, 0b11_101);
+/// assert_eq!(bytes, [0b1010_0000, 0b0000_0011]);
+/// ```
+///
+/// # Panics
+///
+/// - If `bits` is greater than 128.
+/// - If the bits are out of bounds for the given bytes.
+#[inline]
+pub fn write(bytes: &mut [u8], offset: usize, bits: usize, value: u128) {
+    assert_in_bounds(bytes, offset, bits);
+    <u128 as WordAccess>::write_bits(bytes, offset, bits, value)
+}
+
+/// Reads a value of the given specifier starting at bit `offset` from the given bytes.
+///
+/// # Errors
+///
+/// If the read bits are an invalid bit pattern for the specifier.
+///
+/// # Panics
+///
+/// If the bits of the specifier are out of bounds for the given bytes.
+#[inline]
+pub fn read_specifier<T>(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<T::InOut, InvalidBitPattern<T::Bytes>>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    assert_in_bounds(bytes, offset, <T as Specifier>::BITS);
+    <T as Specifier>::from_bytes(<T::Bytes as WordAccess>::read_bits(
+        bytes,
+        offset,
+        <T as Specifier>::BITS,
+    ))
+}
+
+/// Writes a value of the given specifier starting at bit `offset` into the given bytes.
+///
+/// # Errors
+///
+/// If the value is out of bounds for the specifier. The bytes are left unchanged in this case.
+///
+/// # Panics
+///
+/// If the bits of the specifier are out of bounds for the given bytes.
+#[inline]
+pub fn write_specifier<T>(
+    bytes: &mut [u8],
+    offset: usize,
+    value: T::InOut,
+) -> Result<(), OutOfBounds>
+where
+    T: Specifier,
+    T::Bytes: WordAccess,
+{
+    assert_in_bounds(bytes, offset, <T as Specifier>::BITS);
+    let raw = <T as Specifier>::into_bytes(value)?;
+    <T::Bytes as WordAccess>::write_bits(bytes, offset, <T as Specifier>::BITS, raw);
+    Ok(())
+}
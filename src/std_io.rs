@@ -0,0 +1,56 @@
+//! Helpers to read and write bitfields from and to `std::io` streams.
+//!
+//! Requires the `std` crate feature.
+
+use crate::Bitfield;
+use std::io;
+
+/// Extension trait to read and write bitfields from and to `std::io` streams.
+///
+/// Implemented for all `#[bitfield]` structs with public byte conversions.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::std_io::BitfieldIo as _;
+///
+/// #[bitfield]
+/// pub struct Header {
+///     version: B4,
+///     length: B12,
+/// }
+///
+/// let mut buffer = Vec::new();
+/// Header::new().with_version(2).with_length(42).write_to(&mut buffer).unwrap();
+/// assert_eq!(buffer.len(), 2);
+///
+/// let header = Header::read_from(&mut &buffer[..]).unwrap();
+/// assert_eq!(header.version(), 2);
+/// assert_eq!(header.length(), 42);
+/// ```
+pub trait BitfieldIo: Bitfield {
+    /// Reads the bitfield from the given reader.
+    ///
+    /// # Errors
+    ///
+    /// - If reading from the reader fails or the reader runs out of bytes.
+    /// - If the read bytes contain bits at positions that are undefined for the bitfield.
+    ///   In this case the returned error is of kind [`io::ErrorKind::InvalidData`].
+    fn read_from<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = Self::zeroed_bytes();
+        reader.read_exact(bytes.as_mut())?;
+        Self::from_bytes(bytes).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Writes the bytes of the bitfield to the given writer.
+    ///
+    /// # Errors
+    ///
+    /// If writing to the writer fails.
+    fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.as_bytes().as_ref())
+    }
+}
+
+impl<T> BitfieldIo for T where T: Bitfield {}
@@ -0,0 +1,315 @@
+//! Exact-width unsigned integers for bitfield fields.
+//!
+//! The `B1`, .. `B128` specifiers return the next larger primitive from their getters
+//! which loses the range invariant checked by their setters. Using the types of this module
+//! such as [`U24`] as field types instead makes the getters return exact-width integers.
+
+use crate::{
+    error::{
+        InvalidBitPattern,
+        OutOfBounds,
+    },
+    Specifier,
+};
+
+/// An unsigned integer of exactly `BITS` bits stored in the primitive `T`.
+///
+/// Arithmetic operators panic upon overflowing the `BITS` bits just like the operators of
+/// the primitive integers do in debug mode. Use the `checked_*` and `wrapping_*` methods
+/// to handle overflows explicitly.
+///
+/// Usually used through its aliases such as [`U24`] which also serve as bitfield specifiers.
+///
+/// # Example
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use modular_bitfield::{
+///     prelude::*,
+///     uint::U24,
+/// };
+///
+/// #[bitfield]
+/// pub struct Packet {
+///     kind: B8,
+///     len: U24,
+/// }
+///
+/// let len = U24::try_from(0x12_3456).unwrap();
+/// let packet = Packet::new().with_len(len + U24::new(1).unwrap());
+/// assert_eq!(packet.len(), U24::new(0x12_3457).unwrap());
+/// assert_eq!(u32::from(packet.len()), 0x12_3457);
+/// assert!(U24::try_from(0x100_0000).is_err());
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UInt<T, const BITS: usize>(T);
+
+macro_rules! impl_uint_for_primitive {
+    ( $( $prim:ty ),* $(,)? ) => {
+        $(
+            impl<const BITS: usize> UInt<$prim, BITS> {
+                /// The smallest value of the integer.
+                pub const MIN: Self = Self(0);
+
+                /// The largest value of the integer.
+                pub const MAX: Self = Self(
+                    match BITS >= ::core::mem::size_of::<$prim>() * 8 {
+                        true => <$prim>::MAX,
+                        false => (1 << BITS) - 1,
+                    }
+                );
+
+                /// Creates a new integer from the given value.
+                ///
+                /// Returns `None` if the value does not fit into `BITS` bits.
+                #[inline]
+                pub const fn new(value: $prim) -> Option<Self> {
+                    match value > Self::MAX.0 {
+                        true => None,
+                        false => Some(Self(value)),
+                    }
+                }
+
+                /// Creates a new integer from the `BITS` least significant bits of the given value.
+                #[inline]
+                pub const fn new_truncated(value: $prim) -> Self {
+                    Self(value & Self::MAX.0)
+                }
+
+                /// Returns the value of the integer as its primitive.
+                #[inline]
+                pub const fn value(self) -> $prim {
+                    self.0
+                }
+
+                /// Checked integer addition. Returns `None` upon overflow.
+                #[inline]
+                pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                    self.0.checked_add(rhs.0).and_then(Self::new)
+                }
+
+                /// Checked integer subtraction. Returns `None` upon overflow.
+                #[inline]
+                pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    self.0.checked_sub(rhs.0).map(Self)
+                }
+
+                /// Checked integer multiplication. Returns `None` upon overflow.
+                #[inline]
+                pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    self.0.checked_mul(rhs.0).and_then(Self::new)
+                }
+
+                /// Checked integer division. Returns `None` if `rhs` is zero.
+                #[inline]
+                pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                    self.0.checked_div(rhs.0).map(Self)
+                }
+
+                /// Wrapping integer addition modulo `2^BITS`.
+                #[inline]
+                pub fn wrapping_add(self, rhs: Self) -> Self {
+                    Self::new_truncated(self.0.wrapping_add(rhs.0))
+                }
+
+                /// Wrapping integer subtraction modulo `2^BITS`.
+                #[inline]
+                pub fn wrapping_sub(self, rhs: Self) -> Self {
+                    Self::new_truncated(self.0.wrapping_sub(rhs.0))
+                }
+
+                /// Wrapping integer multiplication modulo `2^BITS`.
+                #[inline]
+                pub fn wrapping_mul(self, rhs: Self) -> Self {
+                    Self::new_truncated(self.0.wrapping_mul(rhs.0))
+                }
+            }
+
+            impl<const BITS: usize> ::core::convert::TryFrom<$prim> for UInt<$prim, BITS> {
+                type Error = OutOfBounds;
+
+                #[inline]
+                fn try_from(value: $prim) -> Result<Self, Self::Error> {
+                    Self::new(value).ok_or_else(|| OutOfBounds::with_value(value as u128, BITS))
+                }
+            }
+
+            impl<const BITS: usize> From<UInt<$prim, BITS>> for $prim {
+                #[inline]
+                fn from(value: UInt<$prim, BITS>) -> Self {
+                    value.0
+                }
+            }
+
+            impl<const BITS: usize> ::core::ops::Add for UInt<$prim, BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    self.checked_add(rhs).expect("attempt to add with overflow")
+                }
+            }
+
+            impl<const BITS: usize> ::core::ops::Sub for UInt<$prim, BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    self.checked_sub(rhs).expect("attempt to subtract with overflow")
+                }
+            }
+
+            impl<const BITS: usize> ::core::ops::Mul for UInt<$prim, BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: Self) -> Self {
+                    self.checked_mul(rhs).expect("attempt to multiply with overflow")
+                }
+            }
+
+            impl<const BITS: usize> ::core::ops::Div for UInt<$prim, BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: Self) -> Self {
+                    Self(self.0 / rhs.0)
+                }
+            }
+
+            impl<const BITS: usize> ::core::ops::Rem for UInt<$prim, BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn rem(self, rhs: Self) -> Self {
+                    Self(self.0 % rhs.0)
+                }
+            }
+
+            impl<const BITS: usize> ::core::ops::BitAnd for UInt<$prim, BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn bitand(self, rhs: Self) -> Self {
+                    Self(self.0 & rhs.0)
+                }
+            }
+
+            impl<const BITS: usize> ::core::ops::BitOr for UInt<$prim, BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn bitor(self, rhs: Self) -> Self {
+                    Self(self.0 | rhs.0)
+                }
+            }
+
+            impl<const BITS: usize> ::core::ops::BitXor for UInt<$prim, BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn bitxor(self, rhs: Self) -> Self {
+                    Self(self.0 ^ rhs.0)
+                }
+            }
+
+            impl<const BITS: usize> ::core::ops::Not for UInt<$prim, BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn not(self) -> Self {
+                    Self::new_truncated(!self.0)
+                }
+            }
+
+            impl<const BITS: usize> ::core::fmt::Debug for UInt<$prim, BITS> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::Debug::fmt(&self.0, f)
+                }
+            }
+
+            impl<const BITS: usize> ::core::fmt::Display for UInt<$prim, BITS> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.0, f)
+                }
+            }
+
+            impl<const BITS: usize> ::core::fmt::LowerHex for UInt<$prim, BITS> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::LowerHex::fmt(&self.0, f)
+                }
+            }
+
+            impl<const BITS: usize> ::core::fmt::UpperHex for UInt<$prim, BITS> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::UpperHex::fmt(&self.0, f)
+                }
+            }
+
+            impl<const BITS: usize> ::core::fmt::Binary for UInt<$prim, BITS> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::Binary::fmt(&self.0, f)
+                }
+            }
+
+            impl<const BITS: usize> Specifier for UInt<$prim, BITS> {
+                const BITS: usize = BITS;
+                type Bytes = $prim;
+                type InOut = Self;
+
+                #[inline]
+                fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+                    Ok(input.0)
+                }
+
+                #[inline]
+                fn from_bytes(
+                    bytes: Self::Bytes,
+                ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+                    Self::new(bytes).ok_or_else(|| InvalidBitPattern::new(bytes))
+                }
+            }
+        )*
+    };
+}
+impl_uint_for_primitive!(u8, u16, u32, u64, u128);
+
+macro_rules! define_uint_aliases {
+    ( $prim:ty; $( $name:ident: $bits:literal ),* $(,)? ) => {
+        $(
+            #[doc = concat!("An unsigned integer of exactly ", stringify!($bits), " bits.")]
+            pub type $name = UInt<$prim, $bits>;
+        )*
+    };
+}
+define_uint_aliases! {
+    u8;
+    U1: 1, U2: 2, U3: 3, U4: 4, U5: 5, U6: 6, U7: 7,
+}
+define_uint_aliases! {
+    u16;
+    U9: 9, U10: 10, U11: 11, U12: 12, U13: 13, U14: 14, U15: 15,
+}
+define_uint_aliases! {
+    u32;
+    U17: 17, U18: 18, U19: 19, U20: 20, U21: 21, U22: 22, U23: 23, U24: 24, U25: 25,
+    U26: 26, U27: 27, U28: 28, U29: 29, U30: 30, U31: 31,
+}
+define_uint_aliases! {
+    u64;
+    U33: 33, U34: 34, U35: 35, U36: 36, U37: 37, U38: 38, U39: 39, U40: 40, U41: 41,
+    U42: 42, U43: 43, U44: 44, U45: 45, U46: 46, U47: 47, U48: 48, U49: 49, U50: 50,
+    U51: 51, U52: 52, U53: 53, U54: 54, U55: 55, U56: 56, U57: 57, U58: 58, U59: 59,
+    U60: 60, U61: 61, U62: 62, U63: 63,
+}
+define_uint_aliases! {
+    u128;
+    U65: 65, U66: 66, U67: 67, U68: 68, U69: 69, U70: 70, U71: 71, U72: 72, U73: 73,
+    U74: 74, U75: 75, U76: 76, U77: 77, U78: 78, U79: 79, U80: 80, U81: 81, U82: 82,
+    U83: 83, U84: 84, U85: 85, U86: 86, U87: 87, U88: 88, U89: 89, U90: 90, U91: 91,
+    U92: 92, U93: 93, U94: 94, U95: 95, U96: 96, U97: 97, U98: 98, U99: 99, U100: 100,
+    U101: 101, U102: 102, U103: 103, U104: 104, U105: 105, U106: 106, U107: 107,
+    U108: 108, U109: 109, U110: 110, U111: 111, U112: 112, U113: 113, U114: 114,
+    U115: 115, U116: 116, U117: 117, U118: 118, U119: 119, U120: 120, U121: 121,
+    U122: 122, U123: 123, U124: 124, U125: 125, U126: 126, U127: 127,
+}
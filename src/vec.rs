@@ -0,0 +1,67 @@
+//! Helpers to convert bitfields from and into `alloc::vec::Vec<u8>` buffers.
+//!
+//! Requires the `alloc` crate feature.
+
+use crate::{
+    error::FromSliceError,
+    Bitfield,
+};
+use alloc::vec::Vec;
+
+/// Extension trait to convert bitfields from and into `Vec<u8>` based buffers.
+///
+/// Implemented for all `#[bitfield]` structs with public byte conversions.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::vec::BitfieldVec as _;
+///
+/// #[bitfield]
+/// pub struct Header {
+///     version: B4,
+///     length: B12,
+/// }
+///
+/// let header = Header::new().with_version(2).with_length(42);
+/// let mut buffer = header.to_vec();
+/// header.extend_bytes(&mut buffer);
+/// assert_eq!(buffer.len(), 4);
+///
+/// let header = Header::from_slice(&buffer[2..]).unwrap();
+/// assert_eq!(header.version(), 2);
+/// assert_eq!(header.length(), 42);
+/// ```
+pub trait BitfieldVec: Bitfield {
+    /// Returns the bytes of the bitfield as a newly allocated `Vec<u8>`.
+    fn to_vec(&self) -> Vec<u8> {
+        self.as_bytes().as_ref().to_vec()
+    }
+
+    /// Appends the bytes of the bitfield to the given buffer.
+    fn extend_bytes(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(self.as_bytes().as_ref())
+    }
+
+    /// Converts the prefix of the given byte slice into the bitfield.
+    ///
+    /// # Errors
+    ///
+    /// - If the byte slice is shorter than the bytes of the bitfield.
+    /// - If the bytes contain bits at positions that are undefined for the bitfield.
+    fn from_slice(bytes: &[u8]) -> Result<Self, FromSliceError> {
+        let mut array = Self::zeroed_bytes();
+        let len = array.as_ref().len();
+        if bytes.len() < len {
+            return Err(FromSliceError::TooShort {
+                expected: len,
+                found: bytes.len(),
+            })
+        }
+        array.as_mut().copy_from_slice(&bytes[..len]);
+        Self::from_bytes(array).map_err(|_| FromSliceError::InvalidPadding)
+    }
+}
+
+impl<T> BitfieldVec for T where T: Bitfield {}
@@ -1,7 +1,7 @@
 // Validates that in a degenerate case with a single bit, non-power-of-two enums
 // behave as expected.
 
-use modular_bitfield::error::InvalidBitPattern;
+use modular_bitfield::error::{FieldContext, InvalidBitPattern};
 use modular_bitfield::prelude::*;
 
 #[bitfield]
@@ -22,7 +22,15 @@ fn main() {
 
     // Initialized to all 0 bits.
     let entry = UselessStruct::new();
-    assert_eq!(entry.field_or_err(), Err(InvalidBitPattern{ invalid_bytes: 0 }));
+    assert_eq!(
+        entry.field_or_err(),
+        Err(InvalidBitPattern::new(0).with_context(FieldContext {
+            struct_name: "UselessStruct",
+            field_name: "field",
+            offset: 3,
+            bits: 1,
+        }))
+    );
 
     let entry = UselessStruct::new().with_field(ForciblyTrue::True);
     assert_eq!(entry.field_or_err(), Ok(ForciblyTrue::True));
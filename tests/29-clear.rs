@@ -0,0 +1,48 @@
+// Tests the generated `clear` and `clear_<field>` methods.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct MyTwoBytes {
+    a: B1,
+    b: B2,
+    c: B13,
+}
+
+// A field named `clear` keeps its getter instead of the generated `clear` method.
+#[bitfield]
+pub struct Control {
+    clear: bool,
+    enable: bool,
+    #[skip]
+    __: B6,
+}
+
+fn main() {
+    let mut bitfield = MyTwoBytes::new()
+        .with_a(1)
+        .with_b(3)
+        .with_c(42);
+
+    // Clearing a single field leaves its neighbours untouched.
+    bitfield.clear_b();
+    assert_eq!(bitfield.a(), 1);
+    assert_eq!(bitfield.b(), 0);
+    assert_eq!(bitfield.c(), 42);
+
+    bitfield.clear_c();
+    assert_eq!(bitfield.a(), 1);
+    assert_eq!(bitfield.b(), 0);
+    assert_eq!(bitfield.c(), 0);
+
+    // Clearing the whole bitfield resets every bit.
+    bitfield.set_b(2);
+    bitfield.set_c(1000);
+    bitfield.clear();
+    assert_eq!(bitfield.into_bytes(), [0x00; 2]);
+
+    let mut control = Control::new().with_clear(true).with_enable(true);
+    assert!(control.clear());
+    control.clear_clear();
+    assert!(!control.clear());
+}
@@ -0,0 +1,26 @@
+// Tests the generated `set_<field>_from` and `with_<field>_from` setters
+// that accept any value convertible into the field's in-out type.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct MyFourBytes {
+    a: bool,
+    b: B9,
+    c: B22,
+}
+
+fn main() {
+    let small: u8 = 200;
+    let mut bitfield = MyFourBytes::new()
+        .with_b_from(small)
+        .with_c_from(small);
+    assert_eq!(bitfield.b(), 200);
+    assert_eq!(bitfield.c(), 200);
+
+    bitfield.set_c_from(0xFFFF_u16);
+    bitfield.set_a_from(true);
+    assert!(bitfield.a());
+    assert_eq!(bitfield.b(), 200);
+    assert_eq!(bitfield.c(), 0xFFFF);
+}
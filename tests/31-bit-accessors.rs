@@ -0,0 +1,39 @@
+// Tests the generated `bit` and `set_bit` methods for positional access.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct MyTwoBytes {
+    a: bool,
+    b: B3,
+    c: B12,
+}
+
+// A field named `bit` keeps its own accessors.
+#[bitfield]
+pub struct Register {
+    bit: bool,
+    mode: B7,
+}
+
+fn main() {
+    let mut bitfield = MyTwoBytes::new().with_a(true).with_b(0b101);
+    assert!(bitfield.bit(0));
+    assert!(bitfield.bit(1));
+    assert!(!bitfield.bit(2));
+    assert!(bitfield.bit(3));
+    assert!((4..16).all(|index| !bitfield.bit(index)));
+
+    bitfield.set_bit(4, true);
+    bitfield.set_bit(15, true);
+    assert_eq!(bitfield.c(), 0b1000_0000_0001);
+
+    bitfield.set_bit(0, false);
+    assert!(!bitfield.a());
+    assert_eq!(bitfield.b(), 0b101);
+
+    let mut register = Register::new();
+    register.set_bit(true);
+    assert!(register.bit());
+    assert_eq!(register.mode(), 0);
+}
@@ -0,0 +1,48 @@
+// Tests the generated `as_bytes` and `as_bytes_mut` reference accessors.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct MyFourBytes {
+    a: bool,
+    b: B7,
+    c: B24,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    a: bool,
+    b: B6,
+}
+
+// Fields named `as_bytes` and `as_bytes_mut` keep their getters instead of the generated methods.
+#[bitfield]
+pub struct Packet {
+    as_bytes: B4,
+    as_bytes_mut: B4,
+}
+
+fn write_into(buffer: &mut [u8], bytes: &[u8]) {
+    buffer[..bytes.len()].copy_from_slice(bytes);
+}
+
+fn main() {
+    let mut bitfield = MyFourBytes::new().with_a(true).with_c(0xABCDEF);
+    assert_eq!(bitfield.as_bytes(), &[0x01, 0xEF, 0xCD, 0xAB]);
+
+    let mut buffer = [0u8; 6];
+    write_into(&mut buffer[1..], &bitfield.as_bytes()[..]);
+    assert_eq!(buffer, [0x00, 0x01, 0xEF, 0xCD, 0xAB, 0x00]);
+
+    bitfield.as_bytes_mut()[0] = 0b0000_0110;
+    assert!(!bitfield.a());
+    assert_eq!(bitfield.b(), 0b11);
+
+    let unfilled = Unfilled::new().with_b(5);
+    assert_eq!(unfilled.as_bytes(), &[0b0000_1010]);
+
+    let packet = Packet::new().with_as_bytes(3).with_as_bytes_mut(9);
+    assert_eq!(packet.as_bytes(), 3);
+    assert_eq!(packet.as_bytes_mut(), 9);
+    assert_eq!(packet.into_bytes(), [0x93]);
+}
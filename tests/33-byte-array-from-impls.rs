@@ -0,0 +1,33 @@
+// Tests the generated `From` and `TryFrom` impls between bitfields and byte arrays.
+
+use core::convert::TryFrom;
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct MyTwoBytes {
+    a: bool,
+    b: B15,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    a: bool,
+    b: B6,
+}
+
+fn to_array<T: Into<[u8; 2]>>(value: T) -> [u8; 2] {
+    value.into()
+}
+
+fn main() {
+    let bitfield = MyTwoBytes::from([0b0000_0011, 0x00]);
+    assert!(bitfield.a());
+    assert_eq!(bitfield.b(), 1);
+    assert_eq!(to_array(bitfield), [0b0000_0011, 0x00]);
+
+    let unfilled = Unfilled::try_from([0b0100_0001]).unwrap();
+    assert!(unfilled.a());
+    assert_eq!(unfilled.b(), 0b10_0000);
+    assert_eq!(<[u8; 1]>::from(unfilled), [0b0100_0001]);
+    assert!(Unfilled::try_from([0b1000_0000]).is_err());
+}
@@ -0,0 +1,41 @@
+// Tests the generated `from_bytes_slice` constructor.
+
+use modular_bitfield::{
+    error::FromSliceError,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct MyTwoBytes {
+    a: bool,
+    b: B15,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    a: bool,
+    b: B6,
+}
+
+fn main() {
+    let packet = [0x03, 0x00, 0xFF, 0xFF];
+    let bitfield = MyTwoBytes::from_bytes_slice(&packet[..]).unwrap();
+    assert!(bitfield.a());
+    assert_eq!(bitfield.b(), 1);
+    assert_eq!(
+        MyTwoBytes::from_bytes_slice(&packet[..1]).err(),
+        Some(FromSliceError::TooShort { expected: 2, found: 1 }),
+    );
+
+    let unfilled = Unfilled::from_bytes_slice(&[0b0000_0011, 0xFF]).unwrap();
+    assert!(unfilled.a());
+    assert_eq!(unfilled.b(), 1);
+    assert_eq!(
+        Unfilled::from_bytes_slice(&[0b1000_0000]).err(),
+        Some(FromSliceError::InvalidPadding),
+    );
+    assert_eq!(
+        Unfilled::from_bytes_slice(&[]).err(),
+        Some(FromSliceError::TooShort { expected: 1, found: 0 }),
+    );
+}
@@ -0,0 +1,34 @@
+// Tests the generated `Bitfield` trait implementation.
+
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+    Bitfield,
+};
+
+#[bitfield]
+pub struct MyTwoBytes {
+    a: bool,
+    b: B15,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    a: bool,
+    b: B6,
+}
+
+fn roundtrip<T: Bitfield>(bytes: T::Bytes) -> Result<T::Bytes, OutOfBounds> {
+    T::from_bytes(bytes).map(T::into_bytes)
+}
+
+fn main() {
+    assert_eq!(<MyTwoBytes as Bitfield>::zeroed_bytes(), [0x00; 2]);
+    assert_eq!(roundtrip::<MyTwoBytes>([0x12, 0x34]), Ok([0x12, 0x34]));
+    let bitfield = MyTwoBytes::new().with_a(true);
+    assert_eq!(Bitfield::as_bytes(&bitfield), &[0x01, 0x00]);
+
+    assert_eq!(<Unfilled as Bitfield>::zeroed_bytes(), [0x00; 1]);
+    assert_eq!(roundtrip::<Unfilled>([0b0111_1111]), Ok([0b0111_1111]));
+    assert_eq!(roundtrip::<Unfilled>([0b1000_0000]), Err(OutOfBounds::new()));
+}
@@ -0,0 +1,49 @@
+// Tests the generated explicit-endianness byte conversions.
+
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Header {
+    low: B8,
+    mid: B8,
+    high: B8,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    a: B8,
+    b: B7,
+}
+
+fn main() {
+    let header = Header::new().with_low(0x01).with_mid(0x02).with_high(0x03);
+    assert_eq!(header.to_le_bytes(), [0x01, 0x02, 0x03]);
+    let header = Header::new().with_low(0x01).with_mid(0x02).with_high(0x03);
+    assert_eq!(header.to_be_bytes(), [0x03, 0x02, 0x01]);
+    let header = Header::new().with_low(0x01).with_mid(0x02).with_high(0x03);
+    let expected = if cfg!(target_endian = "big") {
+        [0x03, 0x02, 0x01]
+    } else {
+        [0x01, 0x02, 0x03]
+    };
+    assert_eq!(header.to_ne_bytes(), expected);
+
+    let header = Header::from_be_bytes([0x03, 0x02, 0x01]);
+    assert_eq!(header.low(), 0x01);
+    assert_eq!(header.high(), 0x03);
+    let header = Header::from_le_bytes([0x03, 0x02, 0x01]);
+    assert_eq!(header.low(), 0x03);
+    assert_eq!(header.high(), 0x01);
+    let header = Header::from_ne_bytes(Header::from_le_bytes([0x03, 0x02, 0x01]).to_ne_bytes());
+    assert_eq!(header.low(), 0x03);
+
+    let unfilled = Unfilled::from_be_bytes([0x7F, 0xFF]).unwrap();
+    assert_eq!(unfilled.a(), 0xFF);
+    assert_eq!(unfilled.b(), 0x7F);
+    assert_eq!(unfilled.to_be_bytes(), [0x7F, 0xFF]);
+    assert_eq!(Unfilled::from_be_bytes([0xFF, 0x7F]).err(), Some(OutOfBounds::new()));
+    assert_eq!(Unfilled::from_le_bytes([0xFF, 0x7F]).map(Unfilled::into_bytes), Ok([0xFF, 0x7F]));
+}
@@ -0,0 +1,69 @@
+// Tests accessors of fields that start at a byte boundary and span whole bytes.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 4]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[bitfield]
+pub struct Packet {
+    #[bits = 4]
+    mode: Mode,
+    #[bits = 4]
+    other: Mode,
+    length: u16,
+    kind: B8,
+    flag: bool,
+    rest: B7,
+    payload: B32,
+    tail: modular_bitfield::specifiers::B64,
+}
+
+#[bitfield]
+pub struct Tuple(B8, B16, bool, B7);
+
+fn main() {
+    let mut packet = Packet::new()
+        .with_mode(Mode::B)
+        .with_other(Mode::C)
+        .with_length(0xBEEF)
+        .with_kind(0x42)
+        .with_flag(true)
+        .with_rest(0x7F)
+        .with_payload(0xDEAD_BEEF)
+        .with_tail(0x0123_4567_89AB_CDEF);
+    assert_eq!(
+        packet.into_bytes(),
+        [
+            0x21, 0xEF, 0xBE, 0x42, 0xFF, 0xEF, 0xBE, 0xAD, 0xDE, 0xEF, 0xCD, 0xAB, 0x89,
+            0x67, 0x45, 0x23, 0x01,
+        ]
+    );
+    packet = Packet::from_bytes([
+        0x21, 0xEF, 0xBE, 0x42, 0xFF, 0xEF, 0xBE, 0xAD, 0xDE, 0xEF, 0xCD, 0xAB, 0x89, 0x67,
+        0x45, 0x23, 0x01,
+    ]);
+    assert_eq!(packet.mode(), Mode::B);
+    assert_eq!(packet.other(), Mode::C);
+    assert_eq!(packet.length(), 0xBEEF);
+    assert_eq!(packet.kind(), 0x42);
+    assert!(packet.flag());
+    assert_eq!(packet.rest(), 0x7F);
+    assert_eq!(packet.payload(), 0xDEAD_BEEF);
+    assert_eq!(packet.tail(), 0x0123_4567_89AB_CDEF);
+
+    packet.clear_length();
+    packet.set_payload(0x1234_5678);
+    assert_eq!(packet.length(), 0);
+    assert_eq!(packet.kind(), 0x42);
+    assert_eq!(packet.payload(), 0x1234_5678);
+    assert_eq!(packet.other(), Mode::C);
+
+    let tuple = Tuple::new().with_0(0xAB).with_1(0xCDEF).with_2(true).with_3(1);
+    assert_eq!(tuple.into_bytes(), [0xAB, 0xEF, 0xCD, 0x03]);
+}
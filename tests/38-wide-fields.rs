@@ -0,0 +1,40 @@
+// Tests that setting fields wider than 64 bits at unaligned offsets
+// preserves the bits of all neighbouring fields.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Wide {
+    head: B3,
+    wide: B100,
+    mid: B5,
+    full: u128,
+    tail: B12,
+}
+
+fn main() {
+    let mut wide = Wide::new()
+        .with_head(0b101)
+        .with_mid(0b10011)
+        .with_full(u128::MAX)
+        .with_tail(0xA5);
+    wide.set_wide((1 << 100) - 1);
+    assert_eq!(wide.head(), 0b101);
+    assert_eq!(wide.wide(), (1 << 100) - 1);
+    assert_eq!(wide.mid(), 0b10011);
+    assert_eq!(wide.full(), u128::MAX);
+    assert_eq!(wide.tail(), 0xA5);
+
+    wide.set_wide(0x0123_4567_89AB_CDEF_0123_4567_8);
+    wide.set_full(0xFEDC_BA98_7654_3210_FEDC_BA98_7654_3210);
+    assert_eq!(wide.head(), 0b101);
+    assert_eq!(wide.wide(), 0x0123_4567_89AB_CDEF_0123_4567_8);
+    assert_eq!(wide.mid(), 0b10011);
+    assert_eq!(wide.full(), 0xFEDC_BA98_7654_3210_FEDC_BA98_7654_3210);
+    assert_eq!(wide.tail(), 0xA5);
+
+    wide.clear_wide();
+    assert_eq!(wide.head(), 0b101);
+    assert_eq!(wide.wide(), 0);
+    assert_eq!(wide.mid(), 0b10011);
+}
@@ -0,0 +1,75 @@
+use modular_bitfield::{
+    error::DecodeError,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Idle,
+    Running,
+    Stopped,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Filled {
+    enabled: bool,
+    mode: Mode,
+    #[skip]
+    __: B5,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug)]
+pub struct Unfilled {
+    mode: Mode,
+    value: B4,
+}
+
+// A field named `try_from_bytes` keeps its getter instead of the generated constructor.
+#[bitfield]
+pub struct Frame {
+    try_from_bytes: bool,
+    payload: B7,
+}
+
+fn main() {
+    let filled = Filled::try_from_bytes([0b0000_0011]).unwrap();
+    assert!(filled.enabled());
+    assert_eq!(filled.mode(), Mode::Running);
+    assert_eq!(
+        Filled::try_from_bytes([0b0000_0110]).unwrap_err(),
+        DecodeError::InvalidField {
+            field: "mode",
+            invalid_bits: 0b11,
+        },
+    );
+
+    let unfilled = Unfilled::try_from_bytes([0b0010_1010]).unwrap();
+    assert_eq!(unfilled.mode(), Mode::Stopped);
+    assert_eq!(unfilled.value(), 0b1010);
+    assert_eq!(
+        Unfilled::try_from_bytes([0b0100_0000]).unwrap_err(),
+        DecodeError::InvalidPadding,
+    );
+    assert_eq!(
+        Unfilled::try_from_bytes([0b0000_0011]).unwrap_err(),
+        DecodeError::InvalidField {
+            field: "mode",
+            invalid_bits: 0b11,
+        },
+    );
+    assert_eq!(
+        DecodeError::InvalidField {
+            field: "mode",
+            invalid_bits: 0b11,
+        }
+        .to_string(),
+        "encountered an invalid bit pattern for field mode: 0x3",
+    );
+
+    let frame = Frame::new().with_try_from_bytes(true).with_payload(5);
+    assert!(frame.try_from_bytes());
+    assert_eq!(frame.payload(), 5);
+}
@@ -0,0 +1,45 @@
+use modular_bitfield::{
+    layout::{
+        FieldDescriptor,
+        FieldKind,
+    },
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 2]
+pub enum Status {
+    Red,
+    Green,
+    Yellow,
+}
+
+#[bitfield]
+pub struct Header {
+    is_compact: bool,
+    status: Status,
+    #[skip]
+    __: B5,
+    length: u16,
+    payload: B8,
+}
+
+#[bitfield]
+pub struct Tuple(B3, bool, B4);
+
+fn main() {
+    assert_eq!(
+        Header::LAYOUT,
+        &[
+            FieldDescriptor { name: "is_compact", offset: 0, bits: 1, kind: FieldKind::Bool, skipped: false },
+            FieldDescriptor { name: "status", offset: 1, bits: 2, kind: FieldKind::Specifier, skipped: false },
+            FieldDescriptor { name: "__", offset: 3, bits: 5, kind: FieldKind::Unsigned, skipped: true },
+            FieldDescriptor { name: "length", offset: 8, bits: 16, kind: FieldKind::Unsigned, skipped: false },
+            FieldDescriptor { name: "payload", offset: 24, bits: 8, kind: FieldKind::Unsigned, skipped: false },
+        ][..],
+    );
+    assert_eq!(Header::LAYOUT[1].bit_range(), 1..3);
+    assert_eq!(Tuple::LAYOUT.len(), 3);
+    assert_eq!(Tuple::LAYOUT[2].name, "2");
+    assert_eq!(Tuple::LAYOUT[2].offset, 4);
+}
@@ -0,0 +1,73 @@
+use modular_bitfield::{
+    error::{
+        FieldAccessError,
+        OutOfBounds,
+    },
+    layout::Reflect,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Status {
+    Red,
+    Green,
+    Yellow,
+}
+
+#[bitfield]
+pub struct Header {
+    is_compact: bool,
+    status: Status,
+    #[skip]
+    __: B5,
+    length: u16,
+    payload: B8,
+}
+
+fn main() {
+    let mut header = Header::new().with_length(1000).with_status(Status::Green);
+    assert_eq!(header.get_field("length"), Some(1000));
+    assert_eq!(header.get_field("status"), Some(1));
+    assert_eq!(header.get_field("is_compact"), Some(0));
+    assert_eq!(header.get_field("unknown"), None);
+
+    assert_eq!(header.set_field("is_compact", 1), Ok(()));
+    assert_eq!(header.set_field("status", 2), Ok(()));
+    assert_eq!(header.set_field("payload", 0xFF), Ok(()));
+    assert!(header.is_compact());
+    assert_eq!(header.status(), Status::Yellow);
+    assert_eq!(header.payload(), 0xFF);
+    assert_eq!(header.length(), 1000);
+
+    assert_eq!(
+        header.set_field("payload", 0x100),
+        Err(FieldAccessError::OutOfBounds(OutOfBounds::with_value(0x100, 8)))
+    );
+    assert_eq!(
+        header.set_field("unknown", 0),
+        Err(FieldAccessError::UnknownField)
+    );
+    assert_eq!(header.payload(), 0xFF);
+
+    // Writes of invalid bit patterns to enum fields are rejected.
+    assert_eq!(
+        header.set_field("status", 3),
+        Err(FieldAccessError::InvalidBitPattern)
+    );
+    assert_eq!(header.status(), Status::Yellow);
+
+    // Skipped fields can neither be read nor written.
+    assert_eq!(header.get_field("__"), None);
+    assert_eq!(header.set_field("__", 1), Err(FieldAccessError::UnknownField));
+
+    assert_eq!(Header::field_descriptor("length").map(|field| field.offset), Some(8));
+    assert_eq!(<Header as Reflect>::LAYOUT.len(), 5);
+
+    let mut fields = header.fields();
+    assert_eq!(fields.next(), Some(("is_compact", 1)));
+    assert_eq!(fields.next(), Some(("status", 2)));
+    assert_eq!(fields.next(), Some(("length", 1000)));
+    assert_eq!(fields.next(), Some(("payload", 0xFF)));
+    assert_eq!(fields.next(), None);
+}
@@ -0,0 +1,75 @@
+use modular_bitfield::{
+    error::{
+        CursorError,
+        OutOfBounds,
+    },
+    io::{
+        BitReader,
+        BitWriter,
+    },
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Status {
+    Red,
+    Green,
+    Yellow,
+}
+
+#[bitfield(filled = false)]
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub struct Inner {
+    a: B4,
+    b: u8,
+}
+
+fn main() {
+    let mut bytes = [0xFF; 4];
+    let mut writer = BitWriter::new(&mut bytes);
+    assert_eq!(writer.write::<bool>(false), Ok(()));
+    assert_eq!(writer.write::<Status>(Status::Yellow), Ok(()));
+    assert_eq!(writer.skip(5), Ok(()));
+    assert_eq!(
+        writer.write::<Inner>(Inner::new().with_a(0x3).with_b(0xC5)),
+        Ok(())
+    );
+    assert_eq!(
+        writer.write::<B5>(0x20),
+        Err(CursorError::OutOfBounds(OutOfBounds::with_value(0x20, 5)))
+    );
+    assert_eq!(writer.write_bits(6, 0b10_1010), Ok(()));
+    assert_eq!(writer.position(), 26);
+    assert_eq!(
+        writer.write::<B7>(0),
+        Err(CursorError::UnexpectedEnd {
+            requested: 7,
+            remaining: 6,
+        })
+    );
+    assert_eq!(writer.position(), 26);
+
+    let mut reader = BitReader::new(&bytes);
+    assert_eq!(reader.read::<bool>(), Ok(false));
+    assert_eq!(reader.read::<Status>(), Ok(Status::Yellow));
+    assert_eq!(reader.read_bits(5), Ok(0b1_1111));
+    assert_eq!(reader.read::<Inner>(), Ok(Inner::new().with_a(0x3).with_b(0xC5)));
+    assert_eq!(reader.read::<B6>(), Ok(0b10_1010));
+    assert_eq!(reader.remaining_bits(), 6);
+    assert_eq!(reader.read_bits(6), Ok(0b11_1111));
+    assert_eq!(
+        reader.skip(1),
+        Err(CursorError::UnexpectedEnd {
+            requested: 1,
+            remaining: 0,
+        })
+    );
+
+    let mut reader = BitReader::new(&[0b0000_0011]);
+    assert_eq!(
+        reader.read::<Status>(),
+        Err(CursorError::InvalidBitPattern { invalid_bits: 0b11 })
+    );
+    assert_eq!(reader.position(), 0);
+}
@@ -0,0 +1,32 @@
+use modular_bitfield::{
+    prelude::*,
+    raw,
+};
+
+#[bitfield]
+pub struct Header {
+    is_compact: bool,
+    length: B12,
+    tag: B3,
+}
+
+fn main() {
+    let mut header = Header::new().with_length(0xABC).with_tag(5);
+    assert_eq!(raw::read(header.as_bytes(), 1, 12), 0xABC);
+    assert_eq!(raw::read_specifier::<B3>(header.as_bytes(), 13), Ok(5));
+
+    let mut bytes = header.into_bytes();
+    raw::write(&mut bytes, 0, 1, 1);
+    assert_eq!(raw::write_specifier::<B12>(&mut bytes, 1, 0x123), Ok(()));
+    assert!(raw::write_specifier::<B3>(&mut bytes, 13, 8).is_err());
+    header = Header::from_bytes(bytes);
+    assert!(header.is_compact());
+    assert_eq!(header.length(), 0x123);
+    assert_eq!(header.tag(), 5);
+
+    let mut wide = [0x00; 17];
+    raw::write(&mut wide, 3, 128, u128::MAX - 1);
+    assert_eq!(raw::read(&wide, 3, 128), u128::MAX - 1);
+    assert_eq!(raw::read(&wide, 0, 4), 0b0000);
+    assert_eq!(raw::read(&wide, 131, 5), 0);
+}
@@ -0,0 +1,29 @@
+use modular_bitfield::{
+    layout::CHeader,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Header {
+    is_compact: bool,
+    #[skip]
+    __: B3,
+    length: B12,
+    wide: B80,
+}
+
+fn main() {
+    assert_eq!(
+        CHeader::new("HEADER", Header::LAYOUT).to_string(),
+        "\
+#define HEADER_IS_COMPACT_OFFSET 0
+#define HEADER_IS_COMPACT_BITS 1
+#define HEADER_IS_COMPACT_MASK 0x1ULL
+#define HEADER_LENGTH_OFFSET 4
+#define HEADER_LENGTH_BITS 12
+#define HEADER_LENGTH_MASK 0xFFFULL
+#define HEADER_WIDE_OFFSET 16
+#define HEADER_WIDE_BITS 80
+"
+    );
+}
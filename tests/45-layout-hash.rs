@@ -0,0 +1,40 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    is_compact: bool,
+    length: B7,
+}
+
+#[bitfield]
+pub struct SameLayout {
+    is_compact: bool,
+    length: B7,
+}
+
+#[bitfield]
+pub struct RenamedField {
+    is_compact: bool,
+    len: B7,
+}
+
+#[bitfield]
+pub struct SwappedFields {
+    length: B7,
+    is_compact: bool,
+}
+
+#[bitfield]
+pub struct ResizedFields {
+    is_compact: B2,
+    length: B6,
+}
+
+fn main() {
+    // The fingerprint must be stable across compilations and platforms.
+    assert_eq!(Header::LAYOUT_HASH, 0x7B5A_FC82_46DC_D51D);
+    assert_eq!(Header::LAYOUT_HASH, SameLayout::LAYOUT_HASH);
+    assert_ne!(Header::LAYOUT_HASH, RenamedField::LAYOUT_HASH);
+    assert_ne!(Header::LAYOUT_HASH, SwappedFields::LAYOUT_HASH);
+    assert_ne!(Header::LAYOUT_HASH, ResizedFields::LAYOUT_HASH);
+}
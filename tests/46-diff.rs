@@ -0,0 +1,25 @@
+use modular_bitfield::{
+    layout::Reflect,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Register {
+    enable: bool,
+    #[skip]
+    __: B3,
+    mode: B4,
+    counter: u16,
+}
+
+fn main() {
+    let before = Register::new().with_enable(true).with_counter(100);
+    assert_eq!(before.diff(&before).count(), 0);
+
+    let after = Register::from_bytes([0b1010_1110, 0x64, 0x00]);
+    let changes = before
+        .diff(&after)
+        .map(|change| (change.field.name, change.old, change.new))
+        .collect::<Vec<_>>();
+    assert_eq!(changes, [("enable", 1, 0), ("mode", 0, 0b1010)]);
+}
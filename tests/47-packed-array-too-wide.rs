@@ -0,0 +1,11 @@
+use modular_bitfield::{
+    packed::PackedArray,
+    prelude::*,
+};
+
+// 11 elements of 12 bits each do not fit into the 128 bits of a packed array.
+const BITS: usize = <PackedArray<B12, 11> as Specifier>::BITS;
+
+fn main() {
+    let _ = BITS;
+}
@@ -0,0 +1,54 @@
+use modular_bitfield::{
+    packed::PackedArray,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+#[bits = 2]
+pub enum Color {
+    Red = 0,
+    Green = 1,
+    Blue = 2,
+}
+
+#[bitfield]
+pub struct Frame {
+    channel: B4,
+    samples: PackedArray<B12, 5>,
+}
+
+#[bitfield]
+pub struct Palette {
+    colors: PackedArray<Color, 4>,
+}
+
+fn main() {
+    let mut samples = PackedArray::<B12, 5>::new();
+    assert_eq!(samples.len(), 5);
+    samples.set(0, 0xABC);
+    samples.set(2, 0x123);
+    samples.set(4, 0xFFF);
+    assert!(samples.set_checked(1, 0x1000).is_err());
+    assert_eq!(samples.into_bits(), 0xFFF_000_123_000_ABC);
+
+    let mut frame = Frame::new().with_channel(0xF).with_samples(samples);
+    assert_eq!(frame.channel(), 0xF);
+    assert_eq!(
+        frame.samples().iter().collect::<Vec<_>>(),
+        [0xABC, 0x000, 0x123, 0x000, 0xFFF]
+    );
+    let mut samples = frame.samples();
+    samples.set(4, 0x001);
+    frame.set_samples(samples);
+    assert_eq!(frame.samples().get(4), 0x001);
+    assert_eq!(frame.channel(), 0xF);
+
+    let mut colors = PackedArray::<Color, 4>::default();
+    colors.set(1, Color::Blue);
+    colors.set(3, Color::Green);
+    let palette = Palette::new().with_colors(colors);
+    assert_eq!(palette.into_bytes(), [0b01_00_10_00]);
+    let palette = Palette::from_bytes([0b11_00_10_00]);
+    assert_eq!(palette.colors().get(1), Color::Blue);
+    assert!(palette.colors().get_or_err(3).is_err());
+}
@@ -0,0 +1,34 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    ready: bool,
+    error: bool,
+    count: B4,
+    #[skip(getters)]
+    reset: bool,
+    overflow: bool,
+}
+
+#[bitfield]
+pub struct Config {
+    enable: bool,
+    flags: B7,
+}
+
+fn main() {
+    let status = Status::new();
+    assert_eq!(status.set_flags().count(), 0);
+
+    let status = Status::new()
+        .with_ready(true)
+        .with_count(0xF)
+        .with_reset(true)
+        .with_overflow(true);
+    assert_eq!(status.set_flags().collect::<Vec<_>>(), ["ready", "overflow"]);
+
+    // The setter of field `flags` takes precedence over `set_flags`.
+    let mut config = Config::new();
+    config.set_flags(0x7F);
+    assert_eq!(config.flags(), 0x7F);
+}
@@ -0,0 +1,36 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Pending {
+    timer: bool,
+    uart: bool,
+    #[skip]
+    __: B2,
+    dma: B4,
+}
+
+#[bitfield(filled = false)]
+pub struct Status {
+    any: bool,
+    code: B4,
+}
+
+fn main() {
+    let pending = Pending::new();
+    assert!(pending.is_zero());
+    assert!(!pending.any());
+
+    let pending = Pending::new().with_dma(0b0100);
+    assert!(!pending.is_zero());
+    assert!(pending.any());
+
+    // Bits of `#[skip]` fields are ignored.
+    let pending = Pending::from_bytes([0b0000_1100]);
+    assert!(pending.is_zero());
+    assert!(!pending.any());
+
+    // The getter of field `any` takes precedence over the predicate.
+    let status = Status::new().with_code(3);
+    assert!(!status.any());
+    assert!(!status.is_zero());
+}
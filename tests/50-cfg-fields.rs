@@ -0,0 +1,32 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Header {
+    kind: B4,
+    #[cfg(any())]
+    disabled: B8,
+    #[cfg(all())]
+    enabled: B2,
+    #[cfg(not(any()))]
+    flag: bool,
+    last: bool,
+}
+
+fn main() {
+    let header = Header::from_bytes([0b1101_1010]);
+    assert_eq!(header.kind(), 0b1010);
+    assert_eq!(header.enabled(), 0b01);
+    assert!(header.flag());
+    assert!(header.last());
+    assert_eq!(
+        format!("{:?}", header),
+        "Header { kind: 10, enabled: 1, flag: true, last: true }",
+    );
+
+    assert_eq!(Header::LAYOUT[1].name, "disabled");
+    assert_eq!(Header::LAYOUT[1].bits, 0);
+    assert_eq!(Header::LAYOUT[2].offset, 4);
+    assert_eq!(Header::LAYOUT[4].offset, 7);
+    assert!(Header::new().is_zero());
+}
@@ -0,0 +1,29 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Narrow {
+    #[bits = 3]
+    small: u8,
+    #[bits = 12]
+    medium: u32,
+    rest: B1,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Narrow>(), 2);
+
+    let mut narrow = Narrow::new().with_small(0b101).with_medium(0xABC);
+    let small: u8 = narrow.small();
+    let medium: u32 = narrow.medium();
+    assert_eq!(small, 0b101);
+    assert_eq!(medium, 0xABC);
+    assert_eq!(narrow.as_bytes(), &[0b1110_0101, 0b0101_0101]);
+
+    assert!(narrow.set_small_checked(0b1000).is_err());
+    assert!(narrow.set_medium_checked(0x1000).is_err());
+    assert_eq!(narrow.small(), 0b101);
+    assert_eq!(narrow.medium(), 0xABC);
+
+    narrow.set_small(0b111);
+    assert_eq!(narrow.small(), 0b111);
+}
@@ -0,0 +1,29 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Header {
+    kind: B4,
+    extension: B0,
+    payload: B4,
+    end: B0,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Header>(), 1);
+    assert_eq!(<B0 as Specifier>::BITS, 0);
+
+    let mut header = Header::new().with_kind(0x3).with_payload(0xA);
+    let marker: () = header.extension();
+    assert_eq!(marker, ());
+    header.set_extension(());
+    header.set_end(());
+    assert_eq!(header.end(), ());
+    assert_eq!(header.kind(), 0x3);
+    assert_eq!(header.payload(), 0xA);
+    assert_eq!(header.as_bytes(), &[0xA3]);
+    assert_eq!(
+        format!("{:?}", header),
+        "Header { kind: 3, extension: (), payload: 10, end: () }",
+    );
+}
@@ -0,0 +1,30 @@
+use core::marker::PhantomData;
+use modular_bitfield::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PortA;
+
+#[bitfield]
+#[derive(Debug, Clone, Copy)]
+pub struct Reg {
+    enabled: bool,
+    #[marker]
+    port: PortA,
+    mode: B7,
+    brand: PhantomData<fn() -> PortA>,
+}
+
+const RESET: Reg = Reg::new();
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Reg>(), 1);
+    assert_eq!(Reg::LAYOUT.len(), 2);
+    assert_eq!(Reg::LAYOUT[1].offset, 1);
+
+    let reg = Reg::from_bytes([0b0000_1011]);
+    assert!(reg.enabled());
+    assert_eq!(reg.mode(), 0b101);
+    assert_eq!(reg.port, PortA);
+    assert_eq!(RESET.into_bytes(), [0x00]);
+    assert_eq!(format!("{:?}", reg), "Reg { enabled: true, mode: 5 }");
+}
@@ -0,0 +1,31 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Record {
+    kind: B4,
+    mac: [u8; 6],
+    uuid: [u8; 16],
+    tail: B4,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Record>(), 23);
+    assert_eq!(<[u8; 6] as Specifier>::BITS, 48);
+
+    let mac = [0x00, 0x1B, 0x44, 0x11, 0x3A, 0xB7];
+    let uuid = *b"0123456789abcdef";
+    let mut record = Record::new().with_kind(0xA).with_mac(mac).with_uuid(uuid);
+    assert_eq!(record.kind(), 0xA);
+    assert_eq!(record.mac(), mac);
+    assert_eq!(record.uuid(), uuid);
+    assert_eq!(record.tail(), 0);
+    assert_eq!(record.as_bytes()[0], 0x0A);
+    assert_eq!(record.as_bytes()[1], 0xB0);
+
+    record.set_tail(0xF);
+    record.set_mac([0xFF; 6]);
+    assert_eq!(record.mac(), [0xFF; 6]);
+    assert_eq!(record.uuid(), uuid);
+    assert_eq!(record.tail(), 0xF);
+}
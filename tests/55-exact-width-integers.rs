@@ -0,0 +1,37 @@
+use core::convert::TryFrom;
+use modular_bitfield::{
+    prelude::*,
+    uint::{
+        U24,
+        U4,
+    },
+};
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Packet {
+    kind: U4,
+    flags: B4,
+    len: U24,
+}
+
+fn main() {
+    let len = U24::try_from(0xAB_CDEF).unwrap();
+    let mut packet = Packet::new().with_kind(U4::new(0x3).unwrap()).with_len(len);
+    let kind: U4 = packet.kind();
+    assert_eq!(kind.value(), 0x3);
+    assert_eq!(packet.len(), len);
+    assert_eq!(packet.as_bytes(), &[0x03, 0xEF, 0xCD, 0xAB]);
+
+    assert!(U24::try_from(0x100_0000).is_err());
+    assert_eq!(U24::MAX.value(), 0xFF_FFFF);
+    assert_eq!(U4::new(0xF).unwrap().checked_add(U4::new(1).unwrap()), None);
+    assert_eq!(U4::new(0xF).unwrap().wrapping_add(U4::new(2).unwrap()), U4::new(1).unwrap());
+    assert!(U4::new(2).unwrap() < U4::new(3).unwrap());
+    assert_eq!(!U4::MIN, U4::MAX);
+    assert_eq!(format!("{:?} {} {:x}", kind, len, len), "3 11259375 abcdef");
+
+    packet.set_len(len - U24::new(0xF).unwrap());
+    assert_eq!(u32::from(packet.len()), 0xAB_CDE0);
+    assert_eq!(<U24 as Specifier>::BITS, 24);
+}
@@ -0,0 +1,40 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, PartialEq)]
+pub struct Normal {
+    value: B12,
+    mode: B4,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug)]
+pub struct Short {
+    low: B8,
+    high: B6,
+}
+
+#[bitfield]
+#[derive(Clone, Copy)]
+pub union Register {
+    normal: Normal,
+    short: Short,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Register>(), 2);
+
+    let mut register = Register::new();
+    register.set_normal(Normal::new().with_value(0x321).with_mode(0x2));
+    assert_eq!(register.normal(), Normal::new().with_value(0x321).with_mode(0x2));
+    assert_eq!(register.short().low(), 0x21);
+    assert_eq!(register.short().high(), 0x23);
+
+    let register = register.with_normal(Normal::new().with_mode(0xF));
+    assert!(register.short_or_err().is_err());
+    assert_eq!(register.as_bytes(), &[0x00, 0xF0]);
+
+    let register = Register::from_bytes([0x00, 0x3F]);
+    assert_eq!(register.short().high(), 0x3F);
+    assert_eq!(register.into_bytes(), [0x00, 0x3F]);
+}
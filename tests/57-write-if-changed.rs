@@ -0,0 +1,39 @@
+// Tests that `write_if_changed` only writes bytes that differ from the current image.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Copy, Clone)]
+pub struct Config {
+    baud: B4,
+    parity: B2,
+    stop_bits: B2,
+    address: u8,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Full;
+
+fn main() {
+    let stored = Config::new().with_baud(3).with_address(0x42);
+    let mut written = Vec::new();
+
+    let unchanged = stored;
+    let result = unchanged.write_if_changed(&stored, |bytes| -> Result<(), Full> {
+        written.extend_from_slice(bytes);
+        Ok(())
+    });
+    assert_eq!(result, Ok(false));
+    assert!(written.is_empty());
+
+    let changed = stored.with_parity(1);
+    let result = changed.write_if_changed(&stored, |bytes| -> Result<(), Full> {
+        written.extend_from_slice(bytes);
+        Ok(())
+    });
+    assert_eq!(result, Ok(true));
+    assert_eq!(written, changed.into_bytes());
+
+    let result = changed.write_if_changed(&stored, |_| Err(Full));
+    assert_eq!(result, Err(Full));
+}
@@ -0,0 +1,39 @@
+// Tests that `from_bytes` of unfilled bitfields can be used in const contexts.
+
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+
+#[bitfield(filled = false)]
+#[derive(Debug, Copy, Clone)]
+pub struct Register {
+    enable: bool,
+    mode: B3,
+    divider: B8,
+}
+
+const VALID: Result<Register, OutOfBounds> = Register::from_bytes([0b1111_0011, 0b0000_1010]);
+const INVALID: Result<Register, OutOfBounds> = Register::from_bytes([0x00, 0xF0]);
+
+const TABLE: [Register; 2] = [
+    match Register::from_bytes([0x01, 0x00]) {
+        Ok(register) => register,
+        Err(_) => Register::new(),
+    },
+    match Register::from_bytes([0x00, 0xFF]) {
+        Ok(register) => register,
+        Err(_) => Register::new(),
+    },
+];
+
+fn main() {
+    let valid = VALID.unwrap();
+    assert!(valid.enable());
+    assert_eq!(valid.mode(), 0b001);
+    assert_eq!(valid.divider(), 0b1010_1111);
+    assert_eq!(INVALID.err(), Some(OutOfBounds::new()));
+
+    assert!(TABLE[0].enable());
+    assert_eq!(TABLE[1].into_bytes(), [0x00, 0x00]);
+}
@@ -0,0 +1,24 @@
+// Tests the unchecked `from_bytes_unchecked` constructor of unfilled bitfields.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+pub struct Register {
+    enable: bool,
+    mode: B3,
+    divider: B8,
+}
+
+const DEFAULT: Register = Register::from_bytes_unchecked([0b0101_0011, 0b0000_0001]);
+static UNDEFINED: Register = Register::from_bytes_unchecked([0x00, 0xF0]);
+
+fn main() {
+    assert!(DEFAULT.enable());
+    assert_eq!(DEFAULT.mode(), 0b001);
+    assert_eq!(DEFAULT.divider(), 0b0001_0101);
+
+    // Bits at undefined positions are not checked and retained as is.
+    assert!(Register::from_bytes([0x00, 0xF0]).is_err());
+    assert_eq!(UNDEFINED.divider(), 0);
+    assert_eq!(UNDEFINED.as_bytes(), &[0x00, 0xF0]);
+}
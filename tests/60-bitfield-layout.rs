@@ -0,0 +1,29 @@
+// Tests the `bitfield_layout!` macro for anonymous layouts over integers.
+
+use modular_bitfield::prelude::*;
+
+fn decode(raw: u32) -> (u8, u8, u8, u16) {
+    let insn = bitfield_layout! { u32 => { imm: 16, rt: 5, rs: 5, opcode: 6 } };
+    let insn = insn(raw);
+    (insn.opcode(), insn.rs(), insn.rt(), insn.imm())
+}
+
+fn main() {
+    assert_eq!(decode(0x2508_0004), (0x09, 8, 8, 4));
+
+    let flags = bitfield_layout! { u8 => { ready: 1, mode: 3 } };
+    let mut value = flags(0);
+    value.set_ready(1);
+    value.set_mode(0b101);
+    assert_eq!(value.into_bits(), 0b0000_1011);
+    assert_eq!(value.with_mode(0).into_bits(), 0b0000_0001);
+    assert_eq!(format!("{:?}", value), "BitfieldLayout { ready: 1, mode: 5 }");
+
+    let full = bitfield_layout! { u64 => { low: 32, high: 32 } };
+    let full = full(u64::MAX).with_low(0);
+    assert_eq!(full.high(), u32::MAX);
+    assert_eq!(full.into_bits(), 0xFFFF_FFFF_0000_0000);
+
+    let result = std::panic::catch_unwind(|| flags(0).with_mode(0b1000));
+    assert!(result.is_err());
+}
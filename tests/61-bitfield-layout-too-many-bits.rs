@@ -0,0 +1,5 @@
+use modular_bitfield::prelude::*;
+
+fn main() {
+    let _ = bitfield_layout! { u16 => { a: 8, b: 4, c: 5 } };
+}
@@ -0,0 +1,69 @@
+// Tests the runtime defined `DynBitfield` layouts.
+
+use modular_bitfield::{
+    dynamic::{
+        DynBitfield,
+        DynField,
+    },
+    error::{
+        DynAccessError,
+        InvalidWidth,
+    },
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Header {
+    version: B3,
+    urgent: bool,
+    length: B20,
+}
+
+fn main() {
+    let names = ["version", "urgent", "length"];
+    let fields = [(names[0], 3), (names[1], 1), (names[2], 20)];
+    let layout = DynBitfield::new(&fields).unwrap();
+    assert_eq!(layout.bits(), 24);
+    assert_eq!(layout.bytes(), 3);
+    assert_eq!(layout.len(), 3);
+    assert_eq!(
+        layout.field(2),
+        Some(DynField {
+            name: "length",
+            offset: 4,
+            bits: 20,
+        })
+    );
+
+    // Dynamic layouts use the same bit order as `#[bitfield]` structs.
+    let header = Header::new().with_version(5).with_urgent(true).with_length(0xBEEF);
+    let mut buffer = header.into_bytes();
+    assert_eq!(layout.get_by_name(&buffer, "version"), Ok(5));
+    assert_eq!(layout.get(&buffer, 1), Ok(1));
+    assert_eq!(layout.get(&buffer, 2), Ok(0xBEEF));
+
+    layout.set_by_name(&mut buffer, "length", 0xF_FFFF).unwrap();
+    assert_eq!(Header::from_bytes(buffer).length(), 0xF_FFFF);
+    assert_eq!(Header::from_bytes(buffer).version(), 5);
+
+    assert_eq!(layout.get_by_name(&buffer, "missing"), Err(DynAccessError::UnknownField));
+    assert_eq!(
+        layout.get(&buffer, 3),
+        Err(DynAccessError::IndexOutOfRange { index: 3, len: 3 })
+    );
+    assert_eq!(
+        layout.get(&buffer[..2], 0),
+        Err(DynAccessError::TooShort {
+            expected: 3,
+            found: 2,
+        })
+    );
+    assert!(matches!(
+        layout.set(&mut buffer, 0, 8),
+        Err(DynAccessError::OutOfBounds(_))
+    ));
+    assert_eq!(
+        DynBitfield::new(&[("a", 4), ("b", 0)]),
+        Err(InvalidWidth { index: 1, bits: 0 })
+    );
+}
@@ -0,0 +1,70 @@
+use modular_bitfield::{
+    error::{
+        FieldContext,
+        InvalidBitPattern,
+    },
+    flags::Flags,
+    prelude::*,
+    Specifier,
+};
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Capability {
+    Read,
+    Write,
+    Execute,
+    Share,
+}
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+#[bits = 2]
+pub enum Sparse {
+    A = 0,
+    B = 2,
+}
+
+#[bitfield]
+pub struct Permissions {
+    owner: Flags<Capability>,
+    sparse: Flags<Sparse>,
+}
+
+fn main() {
+    assert_eq!(<Flags<Capability> as Specifier>::BITS, 4);
+
+    let mut owner = Flags::<Capability>::new();
+    assert!(owner.is_empty());
+    owner.insert(Capability::Read);
+    owner.insert(Capability::Execute);
+    owner.insert(Capability::Execute);
+    assert_eq!(owner.len(), 2);
+    assert_eq!(owner.into_bits(), 0b0101);
+    assert!(owner.contains(Capability::Execute));
+    assert!(!owner.contains(Capability::Write));
+    owner.remove(Capability::Read);
+    assert_eq!(owner.iter().collect::<Vec<_>>(), [Capability::Execute]);
+
+    let mut sparse = Flags::<Sparse>::new();
+    sparse.insert(Sparse::B);
+    let permissions = Permissions::new().with_owner(owner).with_sparse(sparse);
+    assert_eq!(permissions.into_bytes(), [0b0100_0100]);
+
+    let permissions = Permissions::from_bytes([0b0110_1011]);
+    assert_eq!(
+        permissions.owner().into_iter().collect::<Vec<_>>(),
+        [Capability::Read, Capability::Write, Capability::Share]
+    );
+    assert_eq!(
+        permissions.sparse_or_err(),
+        Err(InvalidBitPattern::new(0b0110).with_context(FieldContext {
+            struct_name: "Permissions",
+            field_name: "sparse",
+            offset: 4,
+            bits: 4,
+        }))
+    );
+    assert_eq!(
+        <Flags<Sparse> as Specifier>::from_bytes(0b0101),
+        Ok(Flags::from_bits(0b0101))
+    );
+}
@@ -0,0 +1,55 @@
+#![deny(improper_ctypes_definitions)]
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Status {
+    ready: bool,
+    error: B7,
+    count: B8,
+}
+
+#[bitfield]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Control {
+    enable: bool,
+    mode: B3,
+    divider: B28,
+}
+
+#[bitfield]
+#[repr(transparent)]
+pub struct Raw {
+    value: B24,
+}
+
+#[repr(C)]
+pub struct Block {
+    status: Status,
+    control: Control,
+}
+
+pub extern "C" fn bump(status: Status) -> Status {
+    status.with_count(status.count() + 1)
+}
+
+pub extern "C" fn enable(mut block: Block) -> Block {
+    block.control.set_enable(true);
+    block
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Status>(), 2);
+    assert_eq!(core::mem::align_of::<Control>(), 1);
+    assert_eq!(core::mem::size_of::<Block>(), 6);
+    assert_eq!(core::mem::size_of::<Raw>(), 3);
+
+    assert_eq!(bump(Status::new()).count(), 1);
+    let block = enable(Block {
+        status: Status::new(),
+        control: Control::new(),
+    });
+    assert!(block.control.enable());
+}
@@ -0,0 +1,32 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Word {
+    low: B4,
+    mid: B16,
+    high: B4,
+}
+
+#[bitfield]
+pub struct Named {
+    swap_bytes: bool,
+    value: B7,
+}
+
+fn main() {
+    let word = Word::from_bytes([0x12, 0x34, 0x56]);
+    assert_eq!(word.swap_bytes().into_bytes(), [0x56, 0x34, 0x12]);
+    assert_eq!(word.swap_bytes().swap_bytes(), word);
+
+    let word = Word::new().with_low(0b0001);
+    let reversed = word.reverse_bits();
+    assert_eq!(reversed.into_bytes(), [0x00, 0x00, 0x80]);
+    assert_eq!(reversed.high(), 0b1000);
+    assert_eq!(reversed.reverse_bits(), word);
+
+    // The getter of the `swap_bytes` field takes precedence.
+    let named = Named::new().with_swap_bytes(true);
+    assert!(named.swap_bytes());
+    assert_eq!(named.reverse_bits().into_bytes(), [0x80]);
+}
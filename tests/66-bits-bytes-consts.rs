@@ -0,0 +1,36 @@
+use modular_bitfield::{
+    prelude::*,
+    Specifier,
+};
+
+#[bitfield]
+pub struct Header {
+    version: B4,
+    length: B12,
+}
+
+#[bitfield(filled = false)]
+#[derive(BitfieldSpecifier)]
+pub struct Partial {
+    a: bool,
+    b: B10,
+}
+
+#[bitfield(bits = 24)]
+pub struct Explicit {
+    a: B8,
+    b: B16,
+}
+
+fn main() {
+    assert_eq!(Header::BITS, 16);
+    assert_eq!(Header::BYTES, 2);
+    assert_eq!(Partial::BITS, 11);
+    assert_eq!(Partial::BYTES, 2);
+    assert_eq!(<Partial as Specifier>::BITS, Partial::BITS);
+    assert_eq!(Explicit::BITS, 24);
+    assert_eq!(Explicit::BYTES, 3);
+
+    let buffer = [0u8; Header::BYTES + Explicit::BYTES];
+    assert_eq!(buffer.len(), 5);
+}
@@ -0,0 +1,30 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum State {
+    Idle,
+    Running,
+    Paused,
+    Done,
+}
+
+#[bitfield]
+pub struct Status {
+    state: State,
+    ready: bool,
+    count: B5,
+    #[skip(setters)]
+    fixed: B8,
+}
+
+fn main() {
+    let mut status = Status::new().with_state(State::Running).with_count(3);
+    assert_eq!(status.replace_state(State::Paused), State::Running);
+    assert_eq!(status.state(), State::Paused);
+    assert!(!status.replace_ready(true));
+    assert!(status.ready());
+    assert_eq!(status.replace_count(31), 3);
+    assert_eq!(status.replace_count(0), 31);
+    assert_eq!(status.count(), 0);
+    assert_eq!(status.fixed(), 0);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Latch {
+    count: B7,
+    take_count: bool,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Events {
+    overflow: bool,
+    count: B7,
+    #[skip(setters)]
+    id: B8,
+}
+
+#[bitfield(default = 0x0502)]
+pub struct Latch {
+    level: B4,
+    pending: B4,
+    counter: B8,
+}
+
+fn main() {
+    let mut events = Events::new().with_overflow(true).with_count(42);
+    assert!(events.take_overflow());
+    assert!(!events.overflow());
+    assert_eq!(events.take_count(), 42);
+    assert_eq!(events.take_count(), 0);
+    assert_eq!(events.into_bytes(), [0x00, 0x00]);
+
+    let mut latch = Latch::new().with_counter(9).with_pending(7);
+    assert_eq!(latch.take_counter(), 9);
+    assert_eq!(latch.counter(), 5);
+    assert_eq!(latch.take_pending(), 7);
+    assert_eq!(latch.pending(), 0);
+    assert_eq!(latch.take_level(), 2);
+    assert_eq!(latch.level(), 2);
+}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Record {
+    blob: [u8; 17],
+    tail: B8,
+}
+
+fn main() {
+    let record = Record::new();
+    let _ = record.blob();
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    #[access(read_only)]
+    #[access(w1c)]
+    ready: bool,
+    mode: B7,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    #[access(read_once)]
+    ready: bool,
+    mode: B7,
+}
+
+fn main() {}
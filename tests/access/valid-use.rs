@@ -0,0 +1,49 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(ptr_view = true)]
+#[derive(Debug)]
+pub struct Status {
+    #[access(read_only)]
+    ready: bool,
+    #[access(write_only)]
+    command: B3,
+    #[access(w1c)]
+    overflow: bool,
+    #[access(w1c)]
+    underflow: bool,
+    #[access(w1s)]
+    start: bool,
+    #[access(read_write)]
+    mode: bool,
+}
+
+fn main() {
+    let mut status = Status::from_bytes([0b0011_0001]);
+    assert!(status.ready());
+    assert!(status.overflow());
+    assert!(status.underflow());
+    assert!(!status.start());
+
+    status.set_command(0b101);
+    assert_eq!(status.as_bytes(), &[0b0011_1011]);
+
+    // Clearing `overflow` must not clear `underflow` when written back.
+    status.clear_overflow();
+    assert_eq!(status.as_bytes(), &[0b0001_1011]);
+
+    status.set_start();
+    status.set_mode(true);
+    assert_eq!(status.as_bytes(), &[0b1100_1011]);
+
+    // Write only fields are not printed.
+    assert_eq!(
+        format!("{:?}", status),
+        "Status { ready: true, overflow: false, underflow: false, start: true, mode: true }",
+    );
+
+    let mut register = [0b0011_0000];
+    // SAFETY: `register` outlives `status` and is valid for reads and writes.
+    let status = unsafe { StatusPtr::from_ptr(register.as_mut_ptr()) };
+    status.clear_underflow();
+    assert_eq!(register, [0b0010_0000]);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(accessor_trait = "Access", accessor_trait = "Other")]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+mod header {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(accessor_trait = "HeaderAccess")]
+    #[derive(Debug)]
+    pub struct Header {
+        pub kind: B4,
+        pub len: B12,
+    }
+
+    impl Header {
+        pub fn len(&self) -> usize {
+            2
+        }
+    }
+}
+
+use header::{
+    Header,
+    HeaderAccess,
+};
+
+fn total_len<T: HeaderAccess>(header: &T) -> u16 {
+    header.len()
+}
+
+fn main() {
+    let mut header = Header::new().with_kind(0x3).with_len(0x123);
+    assert_eq!(header.kind(), 0x3);
+    assert_eq!(total_len(&header), 0x123);
+    assert_eq!(header.len(), 2);
+    header.set_kind(0xF);
+    assert!(header.set_len_checked(0x1000).is_err());
+    assert_eq!(HeaderAccess::len(&header), 0x123);
+    assert_eq!(header.into_bytes(), [0x3F, 0x12]);
+}
@@ -0,0 +1,46 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(accessors = "outline", views = true)]
+#[derive(Debug)]
+pub struct Register {
+    enabled: bool,
+    r#type: B3,
+    counter: B12,
+    aligned: u16,
+}
+
+#[bitfield(accessors = "outline", min_code_size = true)]
+pub struct Tuple(B4, B12);
+
+#[bitfield(accessors = "inline")]
+pub struct Inlined {
+    a: B4,
+    b: B4,
+}
+
+fn main() {
+    let mut register = Register::new()
+        .with_enabled(true)
+        .with_type(5)
+        .with_counter(0xABC)
+        .with_aligned(0xBEEF);
+    assert!(register.enabled());
+    assert_eq!(register.r#type(), 5);
+    assert_eq!(register.counter(), 0xABC);
+    assert_eq!(register.aligned(), 0xBEEF);
+    register.clear_counter();
+    assert_eq!(register.counter(), 0);
+
+    let mut bytes = register.into_bytes();
+    let mut view = RegisterViewMut::new(&mut bytes);
+    view.set_counter(0x123);
+    assert_eq!(view.counter(), 0x123);
+    assert_eq!(view.aligned(), 0xBEEF);
+
+    let tuple = Tuple::new().with_0(0xA).with_1(0xBCD);
+    assert_eq!(tuple.get_0(), 0xA);
+    assert_eq!(tuple.get_1(), 0xBCD);
+
+    let inlined = Inlined::new().with_a(1).with_b(2);
+    assert_eq!(inlined.into_bytes(), [0x21]);
+}
@@ -0,0 +1,42 @@
+use modular_bitfield::{
+    error::FromSliceError,
+    prelude::*,
+    vec::BitfieldVec as _,
+};
+
+#[bitfield]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Header {
+    version: B4,
+    length: B12,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug)]
+pub struct Flags {
+    a: bool,
+    b: bool,
+    c: B4,
+}
+
+fn main() {
+    let header = Header::new().with_version(3).with_length(0x123);
+    assert_eq!(header.to_vec(), header.into_bytes().to_vec());
+
+    let mut buffer = vec![0xff];
+    header.extend_bytes(&mut buffer);
+    header.extend_bytes(&mut buffer);
+    assert_eq!(buffer.len(), 5);
+    assert_eq!(Header::from_slice(&buffer[1..]), Ok(header));
+    assert_eq!(
+        Header::from_slice(&buffer[..1]),
+        Err(FromSliceError::TooShort {
+            expected: 2,
+            found: 1
+        })
+    );
+    assert_eq!(
+        Flags::from_slice(&[0xff]).unwrap_err(),
+        FromSliceError::InvalidPadding
+    );
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(as_ref = true, as_ref = false)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(as_ref = true, filled = false)]
+pub struct Header {
+    is_compact: bool,
+    is_secure: bool,
+    status: B4,
+}
+
+fn main() {
+    let header = Header::new().with_is_secure(true);
+    let bytes: &[u8] = header.as_ref();
+    assert_eq!(bytes, &[0b0000_0010]);
+}
@@ -0,0 +1,28 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(as_ref = true)]
+pub struct Header {
+    is_compact: bool,
+    is_secure: bool,
+    status: B14,
+}
+
+fn checksum(bytes: impl AsRef<[u8]>) -> u8 {
+    bytes.as_ref().iter().fold(0, |acc, byte| acc ^ byte)
+}
+
+fn fill(mut buffer: impl AsMut<[u8]>) {
+    for byte in buffer.as_mut() {
+        *byte = 0xFF;
+    }
+}
+
+fn main() {
+    let mut header = Header::new().with_is_compact(true).with_status(0b11);
+    assert_eq!(header.as_ref(), &[0b0000_1101, 0x00][..]);
+    assert_eq!(checksum(&header), 0b0000_1101);
+    fill(&mut header);
+    assert!(header.is_compact());
+    assert!(header.is_secure());
+    assert_eq!(header.status(), 0x3FFF);
+}
@@ -0,0 +1,95 @@
+use core::sync::atomic::Ordering;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Idle,
+    Running,
+    Stopped,
+}
+
+#[bitfield(atomic)]
+#[derive(Debug)]
+pub struct Status {
+    ready: bool,
+    #[skip(setters)]
+    error: bool,
+    #[skip]
+    __: B6,
+    counter: B12,
+    mode: Mode,
+    #[skip]
+    __: B10,
+}
+
+#[bitfield(atomic = true)]
+pub struct Flags {
+    a: bool,
+    b: B7,
+}
+
+static STATUS: AtomicStatus = AtomicStatus::new();
+
+fn main() {
+    assert!(!STATUS.ready(Ordering::SeqCst));
+    STATUS.set_ready(true, Ordering::SeqCst);
+    STATUS.set_counter(0xABC, Ordering::SeqCst);
+    STATUS.set_mode(Mode::Running, Ordering::Release);
+    assert!(STATUS.ready(Ordering::SeqCst));
+    assert_eq!(STATUS.counter(Ordering::SeqCst), 0xABC);
+    assert_eq!(STATUS.mode(Ordering::Acquire), Mode::Running);
+    assert!(STATUS.set_counter_checked(0x1000, Ordering::SeqCst).is_err());
+    assert_eq!(STATUS.counter(Ordering::SeqCst), 0xABC);
+
+    assert_eq!(
+        STATUS.fetch_update_counter(Ordering::SeqCst, Ordering::SeqCst, |counter| Some(counter + 1)),
+        Ok(0xABC),
+    );
+    assert_eq!(
+        STATUS.fetch_update_counter(Ordering::SeqCst, Ordering::SeqCst, |_| None),
+        Err(0xABD),
+    );
+    assert!(STATUS.ready(Ordering::SeqCst));
+    assert_eq!(STATUS.mode(Ordering::SeqCst), Mode::Running);
+
+    let previous = STATUS
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |status| {
+            Some(status.with_ready(false))
+        })
+        .unwrap();
+    assert!(previous.ready());
+    assert_eq!(STATUS.load(Ordering::SeqCst).into_bytes(), [0x00, 0xBD, 0x1A, 0x00]);
+
+    assert_eq!(
+        STATUS.compare_exchange_counter(0xABD, 0x123, Ordering::SeqCst, Ordering::SeqCst),
+        Ok(0xABD),
+    );
+    assert_eq!(
+        STATUS.compare_exchange_counter(0xABD, 0x456, Ordering::SeqCst, Ordering::SeqCst),
+        Err(0x123),
+    );
+    assert_eq!(
+        STATUS.compare_exchange_mode(Mode::Running, Mode::Stopped, Ordering::AcqRel, Ordering::Acquire),
+        Ok(Mode::Running),
+    );
+    assert!(!STATUS.ready(Ordering::SeqCst));
+    assert_eq!(STATUS.counter(Ordering::SeqCst), 0x123);
+
+    let current = STATUS.load(Ordering::SeqCst);
+    let previous = STATUS
+        .compare_exchange(current, Status::new().with_ready(true), Ordering::SeqCst, Ordering::SeqCst)
+        .unwrap();
+    assert_eq!(previous.counter(), 0x123);
+    assert!(STATUS
+        .compare_exchange(Status::new(), Status::new(), Ordering::SeqCst, Ordering::SeqCst)
+        .is_err());
+
+    STATUS.store(Status::new(), Ordering::SeqCst);
+    assert_eq!(STATUS.load(Ordering::SeqCst).into_bytes(), [0x00; 4]);
+
+    let flags = AtomicFlags::from(Flags::new().with_b(42));
+    flags.set_a(true, Ordering::Relaxed);
+    assert_eq!(flags.b(Ordering::Relaxed), 42);
+    assert_eq!(flags.into_inner().into_bytes(), [0b0101_0101]);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_ops = true, bit_ops = false)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_ops = true, filled = false)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Flags {
+    read: bool,
+    write: bool,
+    level: B8,
+}
+
+fn main() {
+    let flags = !Flags::new().with_read(true);
+    assert!(!flags.read());
+    assert!(flags.write());
+    assert_eq!(flags.level(), 0xFF);
+    assert_eq!(flags.into_bytes(), [0b1111_1110, 0b0000_0011]);
+    assert!(Flags::from_bytes(flags.into_bytes()).is_ok());
+}
@@ -0,0 +1,32 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_ops = true)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Flags {
+    read: bool,
+    write: bool,
+    execute: bool,
+    level: B13,
+}
+
+fn main() {
+    let read = Flags::new().with_read(true);
+    let write = Flags::new().with_write(true);
+    let level = Flags::new().with_level(0x1FFF);
+
+    let mut flags = read | write;
+    assert!(flags.read());
+    assert!(flags.write());
+    assert!(!flags.execute());
+    assert_eq!(flags & write, write);
+    assert_eq!(flags ^ read, write);
+
+    flags |= level;
+    assert_eq!(flags.level(), 0x1FFF);
+    flags &= !read;
+    assert!(!flags.read());
+    assert!(flags.write());
+    flags ^= write;
+    assert_eq!(flags, level);
+    assert_eq!((!level).into_bytes(), [0b0000_0111, 0x00]);
+}
@@ -0,0 +1,17 @@
+use core::convert::TryFrom;
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 20, filled = false)]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Address {
+    offset: B12,
+    page: B7,
+}
+
+fn main() {
+    let address = Address::new().with_offset(0xabc).with_page(0x12);
+    assert_eq!(u32::from(address), 0x12abc);
+    assert_eq!(Address::try_from(0x12abc_u32), Ok(address));
+    assert!(Address::try_from(0x112abc_u32).is_err());
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 40)]
+#[repr(u32)]
+pub struct SignInteger {
+    sign: bool,
+    value: B39,
+}
+
+fn main() {}
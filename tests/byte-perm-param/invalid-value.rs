@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(byte_perm = "swap64")]
+pub struct Counter {
+    value: u32,
+}
+
+fn main() {}
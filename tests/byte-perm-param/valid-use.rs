@@ -0,0 +1,31 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(byte_perm = "swap16")]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Counter {
+    value: u32,
+}
+
+#[bitfield(byte_perm = "swap32", filled = false)]
+#[derive(Debug)]
+pub struct Wide {
+    low: u32,
+    high: B30,
+}
+
+fn main() {
+    let counter = Counter::new().with_value(0xAABB_CCDD);
+    assert_eq!(counter.into_bytes(), [0xDD, 0xCC, 0xBB, 0xAA]);
+    assert_eq!(counter.to_perm_bytes(), [0xBB, 0xAA, 0xDD, 0xCC]);
+    assert_eq!(Counter::from_perm_bytes([0xBB, 0xAA, 0xDD, 0xCC]), counter);
+
+    let wide = Wide::new().with_low(0x0403_0201).with_high(0x0807_0605);
+    assert_eq!(
+        wide.to_perm_bytes(),
+        [0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04]
+    );
+    let wide = Wide::from_perm_bytes([0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04]).unwrap();
+    assert_eq!(wide.low(), 0x0403_0201);
+    assert_eq!(wide.high(), 0x0807_0605);
+    assert!(Wide::from_perm_bytes([0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00]).is_err());
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bytes_field = "raw", bytes_field = "data")]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
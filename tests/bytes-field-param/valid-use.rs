@@ -0,0 +1,37 @@
+use modular_bitfield::prelude::*;
+
+mod header {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(bytes_field = "raw", bytes_field_vis = "pub")]
+    #[repr(transparent)]
+    #[derive(Debug)]
+    pub struct Header {
+        pub kind: B4,
+        pub len: B12,
+    }
+}
+
+#[bitfield(bytes_field = "storage")]
+pub struct Private {
+    a: B4,
+    b: B4,
+}
+
+const HEADER: header::Header = header::Header { raw: [0x21, 0x43] };
+
+fn main() {
+    assert_eq!(HEADER.kind(), 0x1);
+    assert_eq!(HEADER.len(), 0x432);
+    assert_eq!(core::mem::size_of::<header::Header>(), 2);
+
+    let mut header = header::Header::new().with_kind(0xF);
+    header.raw[1] = 0xFF;
+    assert_eq!(header.len(), 0xFF0);
+    assert_eq!(modular_bitfield::Bitfield::as_bytes(&header), &[0x0F, 0xFF]);
+    assert_eq!(header.into_bytes(), [0x0F, 0xFF]);
+
+    let private = Private::new().with_a(1).with_b(2);
+    assert_eq!(private.storage, [0x21]);
+    assert_eq!(private.into_bytes(), [0x21]);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bytes_vis = "pub", bytes_vis = "pub(crate)")]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
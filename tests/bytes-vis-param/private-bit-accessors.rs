@@ -0,0 +1,15 @@
+mod header {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(bytes_vis = "")]
+    pub struct Header {
+        pub is_compact: bool,
+        pub status: B7,
+    }
+}
+
+fn main() {
+    let mut header = header::Header::new();
+    header.set_bit(7, true);
+    let _ = header.bit(7);
+}
@@ -0,0 +1,31 @@
+use modular_bitfield::prelude::*;
+
+mod header {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(bytes_vis = "pub(crate)")]
+    pub struct Header {
+        pub is_compact: bool,
+        pub is_secure: bool,
+        pub status: B6,
+    }
+}
+
+#[bitfield(bytes_vis = "")]
+pub struct Private {
+    a: B4,
+    b: B4,
+}
+
+fn main() {
+    let header = header::Header::from_bytes([0b0000_1101]);
+    assert!(header.is_compact());
+    assert!(!header.is_secure());
+    assert_eq!(header.status(), 0b11);
+    assert_eq!(header.into_bytes(), [0b0000_1101]);
+
+    let private = Private::from_bytes([0x21]);
+    assert_eq!(private.a(), 1);
+    assert_eq!(private.b(), 2);
+    assert_eq!(private.into_bytes(), [0x21]);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(check_reserved = true, check_reserved = false)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(check_reserved = true)]
+pub struct Register {
+    enable: bool,
+    #[skip]
+    __: B3,
+    mode: B4,
+    #[skip(setters)]
+    status: B4,
+    #[skip]
+    reserved: B4,
+}
+
+#[bitfield(filled = false, check_reserved = true)]
+pub struct Unfilled {
+    a: B3,
+    #[skip]
+    reserved: B2,
+}
+
+fn main() {
+    let mut register = Register::new().with_enable(true).with_mode(0b1010);
+    register.set_mode(0b0101);
+    register.set_bit(0, false);
+    assert!(!register.enable());
+    assert_eq!(register.mode(), 0b0101);
+
+    let register = Register::from_bytes([0b1111_0001, 0b0000_1111]);
+    assert_eq!(register.status(), 0b1111);
+
+    let unfilled = Unfilled::from_bytes([0b0000_0101]).unwrap();
+    assert_eq!(unfilled.a(), 0b101);
+}
@@ -0,0 +1,39 @@
+use core::cell::RefCell;
+use critical_section::Mutex;
+use modular_bitfield::{
+    critical_section::ModifyCs as _,
+    prelude::*,
+};
+
+#[bitfield(ptr_view = true)]
+pub struct Status {
+    ready: bool,
+    error: bool,
+    #[skip]
+    __: B2,
+    counter: B12,
+}
+
+static SHARED: Mutex<RefCell<Status>> = Mutex::new(RefCell::new(Status::new()));
+
+fn main() {
+    let mut register = [0x00; 2];
+    // SAFETY: `register` outlives `status` and is valid for reads and writes.
+    let status = unsafe { StatusPtr::from_ptr(register.as_mut_ptr()) };
+    let previous = status.modify_cs(|status| {
+        let previous = status.counter();
+        status.set_ready(true);
+        status.set_counter(previous + 0xABC);
+        previous
+    });
+    assert_eq!(previous, 0);
+    assert_eq!(register, [0xC1, 0xAB]);
+
+    SHARED.modify_cs(|status| status.set_error(true));
+    SHARED.modify_cs(|status| status.set_counter(42));
+    critical_section::with(|cs| {
+        let status = SHARED.borrow(cs).borrow();
+        assert!(status.error());
+        assert_eq!(status.counter(), 42);
+    });
+}
@@ -0,0 +1,65 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 2]
+pub enum Status {
+    Red,
+    Green,
+    Yellow,
+}
+
+#[bitfield(debug = "raw")]
+pub struct DataPackage {
+    status: Status,
+    contents: B4,
+    is_alive: bool,
+    is_received: bool,
+}
+
+#[bitfield(debug = "fields")]
+#[derive(Debug)]
+pub struct Fields {
+    a: B4,
+    b: B4,
+}
+
+#[bitfield(debug = "verbose")]
+pub struct Verbose {
+    status: Status,
+    #[skip]
+    __: B2,
+    contents: B12,
+}
+
+fn main() {
+    let package = DataPackage::new()
+        .with_status(Status::Green)
+        .with_contents(0b1010)
+        .with_is_alive(true);
+    assert_eq!(
+        format!("{:?}", package),
+        "DataPackage { bytes: [0x69], status: Green, contents: 10, is_alive: true, is_received: false }",
+    );
+
+    // Invalid bit patterns are printed on a best-effort basis instead of panicking.
+    let package = DataPackage::from_bytes([0b0101_1011]);
+    assert_eq!(
+        format!("{:?}", package),
+        "DataPackage { bytes: [0x5B], status: InvalidBitPattern { invalid_bytes: 3, struct_name: \"DataPackage\", field_name: \"status\", bits: 0..2 }, contents: 6, is_alive: true, is_received: false }",
+    );
+
+    let fields = Fields::new().with_a(1).with_b(2);
+    assert_eq!(format!("{:?}", fields), "Fields { a: 1, b: 2 }");
+
+    let verbose = Verbose::new()
+        .with_status(Status::Yellow)
+        .with_contents(0xABC);
+    assert_eq!(
+        format!("{:?}", verbose),
+        "Verbose { status: Yellow @ 0..2 = 0b10, contents: 2748 @ 4..16 = 0b101010111100 }",
+    );
+    assert_eq!(
+        format!("{:#?}", verbose),
+        "Verbose {\n    status: Yellow @ 0..2 = 0b10,\n    contents: 2748 @ 4..16 = 0b101010111100,\n}",
+    );
+}
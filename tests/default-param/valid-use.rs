@@ -0,0 +1,57 @@
+use core::sync::atomic::Ordering;
+use modular_bitfield::prelude::*;
+
+#[bitfield(default = 0x0000_8421)]
+#[derive(Default, Debug)]
+pub struct Control {
+    mode: B4,
+    prescaler: B8,
+    enabled: B4,
+    reserved: B16,
+}
+
+#[bitfield(default = "[0x05, 0x01]", filled = false)]
+pub struct Unfilled {
+    a: B4,
+    b: B5,
+}
+
+#[bitfield(default = 0x0F, atomic = true)]
+pub struct Shared {
+    low: B4,
+    high: B4,
+}
+
+const RESET: Control = Control::new();
+
+fn main() {
+    assert_eq!(RESET.into_bytes(), [0x21, 0x84, 0x00, 0x00]);
+
+    let mut control = Control::default();
+    assert_eq!(control.mode(), 0x1);
+    assert_eq!(control.prescaler(), 0x42);
+    assert_eq!(control.enabled(), 0x8);
+    control.set_prescaler(0xFF);
+    control.set_enabled(0x3);
+    control.clear_prescaler();
+    assert_eq!(control.prescaler(), 0x42);
+    assert_eq!(control.enabled(), 0x3);
+    control.clear();
+    assert_eq!(control.into_bytes(), [0x00; 4]);
+
+    let mut zeroed = Control::new();
+    zeroed.clear();
+    zeroed.clear_enabled();
+    assert_eq!(zeroed.into_bytes(), [0x00, 0x80, 0x00, 0x00]);
+
+    let shared = AtomicShared::from(Shared::new());
+    shared.set_high(0x3, Ordering::Relaxed);
+    shared.set_low(0x5, Ordering::Relaxed);
+    assert_eq!(shared.compare_exchange_low(0x5, 0x6, Ordering::Relaxed, Ordering::Relaxed), Ok(0x5));
+    assert_eq!(shared.compare_exchange_high(0x4, 0x7, Ordering::Relaxed, Ordering::Relaxed), Err(0x3));
+    assert_eq!(shared.into_inner().into_bytes(), [0x36]);
+
+    let unfilled = Unfilled::new();
+    assert_eq!(unfilled.a(), 0x5);
+    assert_eq!(unfilled.b(), 0x10);
+}
@@ -0,0 +1,53 @@
+// Tests decoding of enum specifiers that either cover every bit pattern
+// of their width or leave some bit patterns invalid.
+
+use modular_bitfield::{
+    error::InvalidBitPattern,
+    prelude::*,
+    Specifier,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Opcode {
+    Load = 2,
+    Store = 0,
+    r#Move = 3,
+    Jump = 1,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 3]
+pub enum Sparse {
+    A = 1,
+    B = 4,
+    C = 6,
+}
+
+#[bitfield]
+pub struct Instruction {
+    opcode: Opcode,
+    sparse: Sparse,
+    rest: B3,
+}
+
+fn main() {
+    assert_eq!(<Opcode as Specifier>::from_bytes(0), Ok(Opcode::Store));
+    assert_eq!(<Opcode as Specifier>::from_bytes(1), Ok(Opcode::Jump));
+    assert_eq!(<Opcode as Specifier>::from_bytes(2), Ok(Opcode::Load));
+    assert_eq!(<Opcode as Specifier>::from_bytes(3), Ok(Opcode::Move));
+
+    assert_eq!(<Sparse as Specifier>::from_bytes(1), Ok(Sparse::A));
+    assert_eq!(<Sparse as Specifier>::from_bytes(4), Ok(Sparse::B));
+    assert_eq!(<Sparse as Specifier>::from_bytes(6), Ok(Sparse::C));
+    assert_eq!(
+        <Sparse as Specifier>::from_bytes(5),
+        Err(InvalidBitPattern::new(5))
+    );
+
+    let instruction = Instruction::from_bytes([0b000_110_11]);
+    assert_eq!(instruction.opcode(), Opcode::Move);
+    assert_eq!(instruction.sparse(), Sparse::C);
+    let instruction = Instruction::from_bytes([0b000_111_00]);
+    assert_eq!(instruction.opcode(), Opcode::Store);
+    assert!(instruction.sparse_or_err().is_err());
+}
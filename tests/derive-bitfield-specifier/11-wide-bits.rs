@@ -0,0 +1,32 @@
+// Enums whose `#[bits = N]` attribute is more than one bit wider than required
+// by their variants are reported through the `deprecated` lint which can be denied.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 4]
+#[deny(deprecated)]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[derive(BitfieldSpecifier)]
+#[bits = 3]
+#[deny(deprecated)]
+pub enum Spare {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[derive(BitfieldSpecifier)]
+#[bits = 4]
+#[allow(deprecated)]
+pub enum Reserved {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,59 @@
+// Tests enum specifiers with a signed `#[repr(iN)]` whose negative discriminants
+// are stored in two's complement within the bits of the specifier.
+
+use modular_bitfield::{
+    error::InvalidBitPattern,
+    prelude::*,
+    Specifier,
+};
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq)]
+#[repr(i8)]
+pub enum Trim {
+    Minus2 = -2,
+    Minus1 = -1,
+    Zero = 0,
+    Plus1 = 1,
+}
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq)]
+#[repr(i16)]
+#[bits = 4]
+pub enum Offset {
+    Cold = -8,
+    Cool = -3,
+    Normal = 0,
+    Warm = 5,
+}
+
+#[bitfield]
+pub struct Calibration {
+    trim: Trim,
+    offset: Offset,
+    rest: B2,
+}
+
+fn main() {
+    assert_eq!(<Trim as Specifier>::BITS, 2);
+    assert_eq!(<Trim as Specifier>::into_bytes(Trim::Minus2), Ok(0b10));
+    assert_eq!(<Trim as Specifier>::into_bytes(Trim::Minus1), Ok(0b11));
+    assert_eq!(<Trim as Specifier>::from_bytes(0b10), Ok(Trim::Minus2));
+    assert_eq!(<Trim as Specifier>::from_bytes(0b01), Ok(Trim::Plus1));
+
+    assert_eq!(<Offset as Specifier>::into_bytes(Offset::Cold), Ok(0b1000));
+    assert_eq!(<Offset as Specifier>::into_bytes(Offset::Cool), Ok(0b1101));
+    assert_eq!(<Offset as Specifier>::from_bytes(0b1101), Ok(Offset::Cool));
+    assert_eq!(
+        <Offset as Specifier>::from_bytes(0b1111),
+        Err(InvalidBitPattern::new(0b1111))
+    );
+
+    let mut calibration = Calibration::new()
+        .with_trim(Trim::Minus1)
+        .with_offset(Offset::Cool);
+    assert_eq!(calibration.into_bytes(), [0b00_1101_11]);
+    calibration = Calibration::from_bytes([0b11_1000_10]);
+    assert_eq!(calibration.trim(), Trim::Minus2);
+    assert_eq!(calibration.offset(), Offset::Cold);
+    assert_eq!(calibration.rest(), 0b11);
+}
@@ -0,0 +1,15 @@
+// Signed bitfield enums with any discriminant outside of the two's complement
+// range -2^(BITS-1)..2^(BITS-1) should fail to compile.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[repr(i8)]
+pub enum Trim {
+    Minus3 = -3,
+    Minus1 = -1,
+    Zero = 0,
+    Plus1 = 1,
+}
+
+fn main() {}
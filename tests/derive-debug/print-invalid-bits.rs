@@ -20,13 +20,16 @@ fn main() {
     let package = DataPackage::from_bytes([0b01011011]);
     assert_eq!(
         format!("{:?}", package),
-        "DataPackage { status: InvalidBitPattern { invalid_bytes: 3 }, contents: 6, is_alive: true, is_received: false }",
+        "DataPackage { status: InvalidBitPattern { invalid_bytes: 3, struct_name: \"DataPackage\", field_name: \"status\", bits: 0..2 }, contents: 6, is_alive: true, is_received: false }",
     );
     assert_eq!(
         format!("{:#X?}", package),
         "DataPackage {\n    \
             status: InvalidBitPattern {\n        \
-                invalid_bytes: 0x3,\n    \
+                invalid_bytes: 0x3,\n        \
+                struct_name: \"DataPackage\",\n        \
+                field_name: \"status\",\n        \
+                bits: 0x0..0x2,\n    \
             },\n    \
             contents: 0x6,\n    \
             is_alive: true,\n    \
@@ -12,5 +12,5 @@ fn main() {
     assert_eq!(Unfilled::from_bytes([0b0000_0001]), Ok(Unfilled::new().with_a(1)));
     assert_eq!(Unfilled::from_bytes([0b0000_0010]), Ok(Unfilled::new().with_a(2)));
     assert_eq!(Unfilled::from_bytes([0b0000_0011]), Ok(Unfilled::new().with_a(3)));
-    assert_eq!(Unfilled::from_bytes([0b0000_0100]), Err(OutOfBounds));
+    assert_eq!(Unfilled::from_bytes([0b0000_0100]), Err(OutOfBounds::new()));
 }
@@ -0,0 +1,51 @@
+// Tests the `embedded-io` helpers enabled by the `embedded-io` crate feature.
+
+use modular_bitfield::{
+    embedded_io::{
+        BitfieldEmbeddedIo as _,
+        ReadError,
+    },
+    prelude::*,
+};
+
+#[bitfield]
+pub struct MyTwoBytes {
+    a: bool,
+    b: B15,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    a: bool,
+    b: B6,
+}
+
+fn main() {
+    let mut buffer = [0x00; 3];
+    let mut writer = &mut buffer[..];
+    MyTwoBytes::new().with_a(true).with_b(2).write_to(&mut writer).unwrap();
+    Unfilled::new().with_b(3).write_to(&mut writer).unwrap();
+    // The writer has no space left.
+    assert!(Unfilled::new().write_to(&mut writer).is_err());
+    assert_eq!(buffer, [0b0000_0101, 0x00, 0b0000_0110]);
+
+    let mut reader = &buffer[..];
+    let bitfield = MyTwoBytes::read_from(&mut reader).unwrap();
+    assert!(bitfield.a());
+    assert_eq!(bitfield.b(), 2);
+    let unfilled = Unfilled::read_from(&mut reader).unwrap();
+    assert!(!unfilled.a());
+    assert_eq!(unfilled.b(), 3);
+
+    // The reader has run out of bytes.
+    assert_eq!(
+        MyTwoBytes::read_from(&mut reader).err(),
+        Some(ReadError::UnexpectedEof),
+    );
+
+    // The padding bit of `Unfilled` is set.
+    assert_eq!(
+        Unfilled::read_from(&mut &[0b1000_0000][..]).err(),
+        Some(ReadError::OutOfBounds),
+    );
+}
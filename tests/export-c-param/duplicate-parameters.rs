@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(export_c, export_c = false)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
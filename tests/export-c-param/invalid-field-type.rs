@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(export_c)]
+pub struct Wide {
+    pub low: B64,
+    pub high: u64,
+    pub rest: u128,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(export_c_prefix = "status")]
+pub struct Status {
+    pub ready: bool,
+    pub code: B7,
+}
+
+fn main() {}
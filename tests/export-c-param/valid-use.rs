@@ -0,0 +1,53 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(export_c)]
+pub struct UartControl {
+    pub enable: bool,
+    #[skip(setters)]
+    pub busy: bool,
+    pub mode: B2,
+    pub divisor: B12,
+}
+
+#[bitfield(export_c = true)]
+pub struct Pin(pub bool, pub B7);
+
+mod spi {
+    use modular_bitfield::prelude::*;
+
+    // Private fields are not exported and may be of any type.
+    #[bitfield(export_c, export_c_prefix = "spi_ctrl")]
+    pub struct UartControl {
+        pub enable: bool,
+        pub speed: u8,
+        raw: [u8; 2],
+        reserved: B7,
+    }
+
+    // Not generated since `raw` is private.
+    pub fn spi_ctrl_get_raw(_: &UartControl) {}
+}
+
+fn main() {
+    let mut control = UartControl::new();
+    assert!(uart_control_set_enable(&mut control, true));
+    assert!(uart_control_set_mode(&mut control, 0b10));
+    assert!(uart_control_set_divisor(&mut control, 0x123));
+    assert!(!uart_control_set_divisor(&mut control, 0x1000));
+    assert!(uart_control_get_enable(&control));
+    assert!(!uart_control_get_busy(&control));
+    assert_eq!(uart_control_get_mode(&control), 0b10);
+    assert_eq!(uart_control_get_divisor(&control), 0x123);
+    assert_eq!(control.divisor(), 0x123);
+
+    let mut pin = Pin::new();
+    assert!(pin_set_1(&mut pin, 42));
+    assert!(!pin_get_0(&pin));
+    assert_eq!(pin_get_1(&pin), 42);
+
+    let mut spi = spi::UartControl::new();
+    assert!(spi::spi_ctrl_set_speed(&mut spi, 200));
+    assert!(spi::spi_ctrl_set_enable(&mut spi, true));
+    assert_eq!(spi::spi_ctrl_get_speed(&spi), 200);
+    spi::spi_ctrl_get_raw(&spi);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(field_errors = true, field_errors = false)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
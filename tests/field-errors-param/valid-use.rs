@@ -0,0 +1,63 @@
+use modular_bitfield::{
+    error::DecodeError,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 3]
+pub enum Channel {
+    Left,
+    Right,
+}
+
+#[bitfield(field_errors = true)]
+#[derive(Debug)]
+pub struct Control {
+    enabled: bool,
+    power_mode: Mode,
+    audio_channel: Channel,
+    level: B2,
+}
+
+#[bitfield(field_errors = true)]
+pub struct Plain {
+    a: B4,
+    b: B4,
+}
+
+fn main() {
+    let control = Control::new().with_power_mode(Mode::Fast);
+    assert_eq!(control.try_get_all(), Ok(()));
+
+    let control = Control::from_bytes([0b0000_0110]);
+    assert_eq!(control.try_get_all(), Err(ControlError::PowerMode(0b11)));
+
+    let control = Control::from_bytes([0b0010_0000]);
+    let error = control.try_get_all().unwrap_err();
+    assert_eq!(error, ControlError::AudioChannel(0b100));
+    assert_eq!(
+        error.to_string(),
+        "encountered an invalid bit pattern for field audio_channel: 0x4"
+    );
+    assert_eq!(
+        DecodeError::from(error),
+        DecodeError::InvalidField {
+            field: "audio_channel",
+            invalid_bits: 0b100,
+        }
+    );
+    match Control::from_bytes([0b0000_0110]).try_get_all() {
+        Err(ControlError::PowerMode(bits)) => assert_eq!(bits, 3),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    assert_eq!(Plain::new().try_get_all(), Ok(()));
+}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Interrupt {
+    #[flags]
+    timer: bool,
+    vector: B6,
+    #[flags]
+    uart: bool,
+}
+
+fn main() {}
@@ -0,0 +1,52 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Interrupt {
+    vector: B4,
+    #[flags]
+    timer: bool,
+    #[flags]
+    uart: bool,
+    #[flags]
+    dma: bool,
+    #[flags]
+    gpio: bool,
+    pending: B8,
+}
+
+fn main() {
+    assert_eq!(InterruptFlags::empty().bits(), 0);
+    assert_eq!(InterruptFlags::all().bits(), 0b1111);
+    assert_eq!(InterruptFlags::from_bits_truncate(0xFF), InterruptFlags::all());
+    assert_eq!(
+        !InterruptFlags::TIMER,
+        InterruptFlags::UART | InterruptFlags::DMA | InterruptFlags::GPIO
+    );
+
+    let mut flags = InterruptFlags::TIMER | InterruptFlags::GPIO;
+    assert!(flags.contains(InterruptFlags::TIMER));
+    assert!(!flags.contains(InterruptFlags::TIMER | InterruptFlags::UART));
+    flags.insert(InterruptFlags::UART);
+    flags.remove(InterruptFlags::TIMER);
+    assert_eq!(flags & InterruptFlags::UART, InterruptFlags::UART);
+    assert!((flags & InterruptFlags::DMA).is_empty());
+
+    let mut irq = Interrupt::new().with_vector(0xF).with_pending(0xAB);
+    irq.set_flag_group(flags);
+    assert!(!irq.timer());
+    assert!(irq.uart());
+    assert!(!irq.dma());
+    assert!(irq.gpio());
+    assert_eq!(irq.vector(), 0xF);
+    assert_eq!(irq.pending(), 0xAB);
+    assert_eq!(irq.into_bytes(), [0b1010_1111, 0xAB]);
+
+    let irq = Interrupt::from_bytes([0b0101_0000, 0x00]);
+    assert_eq!(irq.flag_group(), InterruptFlags::TIMER | InterruptFlags::DMA);
+    assert_eq!(InterruptFlags::from(irq), InterruptFlags::TIMER | InterruptFlags::DMA);
+    assert_eq!(
+        Interrupt::from(InterruptFlags::DMA),
+        Interrupt::new().with_dma(true)
+    );
+}
@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8)]
+#[derive(BitfieldSpecifier)]
+pub struct Flags {
+    ready: bool,
+    level: B7,
+}
+
+#[bitfield]
+pub struct Header {
+    #[flatten(ready, level)]
+    flags: Flags,
+    length: u8,
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Priority {
+    Low,
+    High,
+}
+
+#[bitfield(bits = 8)]
+#[derive(BitfieldSpecifier)]
+pub struct Flags {
+    ready: bool,
+    priority: Priority,
+    level: B6,
+}
+
+#[bitfield]
+pub struct Header {
+    version: B4,
+    #[flatten(ready: bool, priority: Priority, level: B6)]
+    flags: Flags,
+    #[flatten(level: B6)]
+    #[skip(setters)]
+    status: Flags,
+    length: B4,
+}
+
+fn main() {
+    let mut header = Header::new().with_version(1).with_flags_ready(true).with_length(0xF);
+    header.set_flags_priority(Priority::High);
+    header.set_flags_level(63);
+    assert!(header.flags_ready());
+    assert_eq!(header.flags_priority(), Priority::High);
+    assert_eq!(header.flags_level(), 63);
+    assert_eq!(header.flags().level(), 63);
+    assert_eq!(header.status_level(), 0);
+    assert_eq!(header.version(), 1);
+    assert_eq!(header.length(), 0xF);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(gen_tests = true, gen_tests = false)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield(gen_tests)]
+pub struct Header {
+    is_compact: bool,
+    mode: Mode,
+    #[skip]
+    __: B1,
+    length: B12,
+    #[skip(setters)]
+    checksum: B8,
+    wide: u64,
+}
+
+#[bitfield(gen_tests = true, filled = false)]
+pub struct Unfilled(B3, bool);
+
+#[bitfield(gen_tests = false)]
+pub struct Disabled {
+    a: B8,
+}
+
+fn main() {
+    let header = Header::new().with_length(42);
+    assert_eq!(header.length(), 42);
+    assert_eq!(Unfilled::new().with_0(5).get_0(), 5);
+    assert_eq!(Disabled::new().a(), 0);
+}
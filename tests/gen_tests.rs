@@ -0,0 +1,52 @@
+//! Runs the tests generated by `gen_tests` for bitfields whose other parameters
+//! could interfere with them.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(gen_tests, default = 0x80)]
+pub struct WithDefault {
+    low: B7,
+    high: bool,
+}
+
+#[bitfield(gen_tests, check_reserved = true)]
+pub struct WithReserved {
+    enabled: bool,
+    level: B3,
+    #[skip]
+    __: B4,
+}
+
+#[derive(BitfieldSpecifier)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    Slow = 1,
+    Fast = 3,
+}
+
+#[bitfield(filled = false, gen_tests)]
+#[derive(BitfieldSpecifier)]
+pub struct Timing {
+    delay: B3,
+    strobe: bool,
+}
+
+fn is_even(value: &u8) -> Result<(), ()> {
+    match value % 2 {
+        0 => Ok(()),
+        _ => Err(()),
+    }
+}
+
+#[bitfield(gen_tests)]
+pub struct WithSpecifiers {
+    mode: Mode,
+    timing: Timing,
+    #[bits = 4]
+    ratio: B4,
+    #[validate(is_even)]
+    step: u8,
+    #[skip]
+    __: B6,
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    #[group = "irq lines"]
+    timer: bool,
+    rest: B7,
+}
+
+fn main() {}
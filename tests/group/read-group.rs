@@ -0,0 +1,66 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield]
+pub struct Instruction {
+    enabled: bool,
+    #[group = "decode"]
+    op: B3,
+    #[group = "decode"]
+    mode: Mode,
+    #[group = "decode"]
+    rd: B5,
+    #[group = "decode"]
+    rs: B5,
+    #[group = "decode"]
+    imm: B12,
+    #[group = "decode"]
+    last: bool,
+    #[group = "single"]
+    wide: u32,
+    __: B3,
+}
+
+#[bitfield(no_panic)]
+pub struct Quiet {
+    #[group = "g"]
+    a: B4,
+    #[group = "g"]
+    b: B4,
+}
+
+// `read_group_g` is not generated for `no_panic` bitfields since it could panic.
+impl Quiet {
+    fn read_group_g(&self) -> bool {
+        true
+    }
+}
+
+fn main() {
+    let insn = Instruction::new()
+        .with_enabled(true)
+        .with_op(0b101)
+        .with_mode(Mode::Fast)
+        .with_rd(17)
+        .with_rs(31)
+        .with_imm(0xABC)
+        .with_last(true)
+        .with_wide(0xDEAD_BEEF);
+    assert_eq!(
+        insn.read_group_decode(),
+        (0b101, Mode::Fast, 17, 31, 0xABC, true)
+    );
+    assert_eq!(insn.read_group_single(), (0xDEAD_BEEF,));
+
+    let mut quiet = Quiet::from_bytes([0xFF]);
+    quiet.clear_group_g();
+    assert!(quiet.read_group_g());
+    assert_eq!(quiet.into_bytes(), [0x00]);
+}
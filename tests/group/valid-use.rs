@@ -0,0 +1,35 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    #[group = "irq"]
+    timer: bool,
+    #[group = "dma"]
+    channel: B3,
+    #[group = "irq"]
+    uart: bool,
+    enabled: bool,
+    #[group = "dma"]
+    length: B10,
+}
+
+fn main() {
+    assert_eq!(Status::IRQ_MASK, [0b0001_0001, 0x00]);
+    assert_eq!(Status::DMA_MASK, [0b1100_1110, 0xFF]);
+
+    let mut status = Status::new()
+        .with_timer(true)
+        .with_channel(5)
+        .with_uart(true)
+        .with_enabled(true)
+        .with_length(0x3FF);
+    status.clear_group_irq();
+    assert!(!status.timer());
+    assert!(!status.uart());
+    assert_eq!(status.channel(), 5);
+    assert!(status.enabled());
+    status.clear_group_dma();
+    assert_eq!(status.channel(), 0);
+    assert_eq!(status.length(), 0);
+    assert_eq!(status.into_bytes(), [0b0010_0000, 0x00]);
+}
@@ -0,0 +1,50 @@
+use hash32::{
+    Hash,
+    Hasher,
+};
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Key {
+    bus: B4,
+    device: B12,
+}
+
+/// Collects the hashed bytes so that the test does not depend on a particular hash function.
+#[derive(Default)]
+struct Collect {
+    bytes: [u8; 8],
+    len: usize,
+}
+
+impl Hasher for Collect {
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+
+    fn finish(&self) -> u32 {
+        self.bytes[..self.len]
+            .iter()
+            .fold(0, |hash, &byte| hash.rotate_left(5) ^ u32::from(byte))
+    }
+}
+
+fn hash_of(key: &Key) -> u32 {
+    let mut hasher = Collect::default();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let a = Key::new().with_bus(3).with_device(0x123);
+    let b = Key::from_bytes(a.into_bytes());
+    let c = a.with_device(0x124);
+
+    let mut hasher = Collect::default();
+    a.hash(&mut hasher);
+    assert_eq!(&hasher.bytes[..hasher.len], &a.into_bytes()[..]);
+    assert_eq!(hash_of(&a), hash_of(&b));
+    assert_ne!(hash_of(&a), hash_of(&c));
+}
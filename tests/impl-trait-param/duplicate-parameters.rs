@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield_trait]
+trait RegisterOps {
+    fn a(&self) -> u8;
+}
+
+#[bitfield(impl_trait = "RegisterOps", impl_trait = "RegisterOps")]
+pub struct Base {
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use modular_bitfield::prelude::*;
+
+mod regs {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield_trait]
+    pub trait RegisterOps {
+        fn enabled(&self) -> bool;
+        fn set_enabled(&mut self, new_val: bool);
+        fn with_enabled(self, new_val: bool) -> Self;
+
+        fn toggle(&mut self) {
+            let enabled = self.enabled();
+            self.set_enabled(!enabled);
+        }
+    }
+}
+
+#[bitfield_trait]
+trait Mode {
+    fn mode(&self) -> u8;
+}
+
+#[bitfield(impl_trait = "regs::RegisterOps, Mode")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ControlV1 {
+    enabled: bool,
+    mode: B7,
+}
+
+#[bitfield(impl_trait = "regs::RegisterOps")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ControlV2 {
+    reserved: B15,
+    enabled: bool,
+}
+
+fn enable<T: regs::RegisterOps>(register: T) -> T {
+    register.with_enabled(true)
+}
+
+fn main() {
+    let mut v1 = enable(ControlV1::new().with_mode(5));
+    assert!(v1.enabled());
+    assert_eq!(Mode::mode(&v1), 5);
+    regs::RegisterOps::toggle(&mut v1);
+    assert!(!v1.enabled());
+
+    let v2 = enable(ControlV2::new());
+    assert_eq!(v2.into_bytes(), [0x00, 0x80]);
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Base {
+    #[inline(always)]
+    #[inline(never)]
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(inline = "sometimes")]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
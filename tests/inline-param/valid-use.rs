@@ -0,0 +1,36 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(inline = "always")]
+pub struct Hot {
+    a: B4,
+    #[inline(never)]
+    b: B12,
+    #[inline]
+    c: u16,
+}
+
+#[bitfield(inline = "never")]
+pub struct Cold {
+    #[inline(always)]
+    a: bool,
+    b: B7,
+}
+
+#[bitfield]
+pub struct Mixed(#[inline(always)] B8, #[inline(never)] B8);
+
+fn main() {
+    let hot = Hot::new().with_a(1).with_b(0x234).with_c(0xABCD);
+    assert_eq!(hot.a(), 1);
+    assert_eq!(hot.b(), 0x234);
+    assert_eq!(hot.c(), 0xABCD);
+
+    let mut cold = Cold::new();
+    cold.set_a(true);
+    cold.set_b(42);
+    assert!(cold.a());
+    assert_eq!(cold.b(), 42);
+
+    let mixed = Mixed::new().with_0(1).with_1(2);
+    assert_eq!(mixed.into_bytes(), [1, 2]);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(layout_docs = false, layout_docs = true)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
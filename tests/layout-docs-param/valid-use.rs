@@ -0,0 +1,41 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A = 0b00,
+    B = 0b01,
+    C = 0b10,
+    D = 0b11,
+}
+
+/// Documented with a layout table.
+#[bitfield]
+pub struct WithTable {
+    a: bool,
+    #[skip]
+    __: B3,
+    #[bits = 2]
+    mode: Mode,
+    b: B2,
+}
+
+/// Documented without a layout table since the width of `mode` is unknown to the macro.
+#[bitfield]
+pub struct WithoutTable {
+    a: bool,
+    mode: Mode,
+    b: B5,
+}
+
+/// Explicitly documented without a layout table.
+#[bitfield(layout_docs = false)]
+pub struct Disabled {
+    a: bool,
+    b: B7,
+}
+
+fn main() {
+    assert_eq!(WithTable::new().with_b(3).b(), 3);
+    assert_eq!(WithoutTable::new().with_b(31).b(), 31);
+    assert_eq!(Disabled::new().with_b(127).b(), 127);
+}
@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Other {
+    a: u8,
+}
+
+#[bitfield(migrate_from = "Other", migrate_from = "Other")]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
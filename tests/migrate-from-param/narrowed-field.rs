@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct ConfigV1 {
+    volume: B8,
+}
+
+#[bitfield(migrate_from = "ConfigV1")]
+pub struct ConfigV2 {
+    volume: B4,
+    brightness: B4,
+}
+
+fn main() {}
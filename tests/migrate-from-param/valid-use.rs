@@ -0,0 +1,59 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+#[bitfield]
+pub struct SaveV1 {
+    level: B6,
+    difficulty: Difficulty,
+    lives: B4,
+    legacy_flags: B4,
+}
+
+#[bitfield(migrate_from = "SaveV1")]
+pub struct SaveV2 {
+    difficulty: Difficulty,
+    level: B10,
+    lives: B4,
+    checkpoint: B8,
+    #[skip]
+    __: B8,
+}
+
+#[bitfield(default = 0xFF, migrate_from = "SaveV1, SaveV2")]
+pub struct SaveV3 {
+    checkpoint: B8,
+    lives: B4,
+    #[skip]
+    level: B4,
+}
+
+fn main() {
+    let v1 = SaveV1::new()
+        .with_level(42)
+        .with_difficulty(Difficulty::Hard)
+        .with_lives(9)
+        .with_legacy_flags(0xF);
+
+    let v2 = SaveV2::from(v1);
+    assert_eq!(v2.level(), 42);
+    assert_eq!(v2.difficulty(), Difficulty::Hard);
+    assert_eq!(v2.lives(), 9);
+    assert_eq!(v2.checkpoint(), 0);
+
+    // Fields missing in the old layout are zero filled instead of using `default`
+    // and skipped fields of the new layout are not migrated.
+    let v3 = SaveV3::from(SaveV1::new().with_lives(0b1110).with_level(7));
+    assert_eq!(v3.checkpoint(), 0);
+    assert_eq!(v3.lives(), 0b1110);
+    assert_eq!(v3.into_bytes(), [0x00, 0b1110]);
+
+    let v3 = SaveV3::from(v2.with_checkpoint(3));
+    assert_eq!(v3.checkpoint(), 3);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(min_code_size = true, min_code_size = false)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
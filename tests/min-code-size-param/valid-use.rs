@@ -0,0 +1,41 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+    D = 3,
+}
+
+#[bitfield(min_code_size = true)]
+#[derive(Debug)]
+pub struct Register {
+    enabled: bool,
+    mode: Mode,
+    counter: B13,
+    aligned: u16,
+    wide: B72,
+    #[skip]
+    __: B8,
+}
+
+fn main() {
+    let mut register = Register::new()
+        .with_enabled(true)
+        .with_mode(Mode::C)
+        .with_counter(0x1ABC)
+        .with_aligned(0xBEEF)
+        .with_wide(0xAB_CDEF_0123_4567_89AB);
+    assert!(register.enabled());
+    assert_eq!(register.mode(), Mode::C);
+    assert_eq!(register.counter(), 0x1ABC);
+    assert_eq!(register.aligned(), 0xBEEF);
+    assert_eq!(register.wide(), 0xAB_CDEF_0123_4567_89AB);
+
+    register.clear_counter();
+    assert_eq!(register.counter(), 0);
+    assert_eq!(register.mode(), Mode::C);
+    assert_eq!(register.aligned(), 0xBEEF);
+    assert_eq!(register.set_counter_checked(0x2000), Err(modular_bitfield::error::OutOfBounds::with_value(0x2000, 13)));
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(no_panic, atomic)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    A,
+    B,
+    C,
+}
+
+#[bitfield(no_panic)]
+#[derive(Debug)]
+pub struct Register {
+    enabled: bool,
+    mode: Mode,
+    counter: B5,
+}
+
+#[bitfield(no_panic = false)]
+pub struct Panicking {
+    a: B4,
+    b: B4,
+}
+
+fn main() {
+    let mut register = Register::new()
+        .with_enabled_checked(true)
+        .and_then(|register| register.with_mode_checked(Mode::B))
+        .unwrap();
+    assert_eq!(register.enabled_or_err(), Ok(true));
+    assert_eq!(register.mode_or_err(), Ok(Mode::B));
+    assert_eq!(register.set_counter_checked(31), Ok(()));
+    assert_eq!(register.counter_or_err(), Ok(31));
+    assert!(register.set_counter_checked(32).is_err());
+    register.clear_counter();
+    assert_eq!(register.counter_or_err(), Ok(0));
+
+    let register = Register::from_bytes([0b0000_0110]);
+    assert!(register.mode_or_err().is_err());
+
+    let panicking = Panicking::new().with_a(1).with_b(2);
+    assert_eq!(panicking.a(), 1);
+    assert!(!panicking.bit(7));
+}
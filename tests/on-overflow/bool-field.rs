@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Levels {
+    #[on_overflow = "saturate"]
+    enabled: bool,
+    counter: B7,
+}
+
+fn main() {}
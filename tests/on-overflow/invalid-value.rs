@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Levels {
+    #[on_overflow = "wrap"]
+    volume: B4,
+    counter: B4,
+}
+
+fn main() {}
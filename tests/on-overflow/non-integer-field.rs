@@ -0,0 +1,18 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield]
+pub struct Levels {
+    #[on_overflow = "truncate"]
+    mode: Mode,
+    counter: B6,
+}
+
+fn main() {}
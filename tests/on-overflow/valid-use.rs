@@ -0,0 +1,41 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Levels {
+    #[on_overflow = "saturate"]
+    volume: B4,
+    #[on_overflow = "truncate"]
+    counter: B4,
+    #[on_overflow = "error"]
+    checked: B6,
+    #[on_overflow = "saturate"]
+    wide: u16,
+    #[on_overflow = "truncate"]
+    flag: B2,
+}
+
+fn main() {
+    let mut levels = Levels::new();
+    levels.set_volume(100);
+    assert_eq!(levels.volume(), 0xF);
+    levels.set_volume(3);
+    assert_eq!(levels.volume(), 3);
+
+    levels.set_counter(0x1F);
+    assert_eq!(levels.counter(), 0xF);
+    levels.set_counter(0x12);
+    assert_eq!(levels.counter(), 0x2);
+
+    levels.set_wide(0xFFFF);
+    assert_eq!(levels.wide(), 0xFFFF);
+
+    let levels = levels.with_flag(0b110).with_volume_from(0xFF_u8);
+    assert_eq!(levels.flag(), 0b10);
+    assert_eq!(levels.volume(), 0xF);
+
+    // Checked setters still report out of bounds values.
+    assert!(Levels::new().with_volume_checked(100).is_err());
+    assert!(Levels::new().with_counter_checked(0x1F).is_err());
+    assert!(Levels::new().with_checked_checked(64).is_err());
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(ord_by = "sequence", ord_by = "kind")]
+#[derive(PartialEq, Eq)]
+pub struct Record {
+    kind: B4,
+    sequence: B12,
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(ord_by = "sequence")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Record {
+    kind: B4,
+    sequence: B12,
+}
+
+#[bitfield(ord_by = "priority, sequence")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Task {
+    sequence: B12,
+    priority: B3,
+    done: bool,
+}
+
+fn main() {
+    let mut records = [
+        Record::new().with_kind(1).with_sequence(30),
+        Record::new().with_kind(15).with_sequence(10),
+        Record::new().with_kind(0).with_sequence(20),
+    ];
+    records.sort();
+    let sequences = [records[0].sequence(), records[1].sequence(), records[2].sequence()];
+    assert_eq!(sequences, [10, 20, 30]);
+
+    // Ties of the key fields are broken by the remaining bits.
+    let a = Record::new().with_kind(1).with_sequence(5);
+    let b = Record::new().with_kind(2).with_sequence(5);
+    assert!(a < b);
+    assert_eq!(a.cmp(&a), core::cmp::Ordering::Equal);
+
+    let low = Task::new().with_priority(1).with_sequence(100);
+    let high_early = Task::new().with_priority(2).with_sequence(1);
+    let high_late = Task::new().with_priority(2).with_sequence(2);
+    assert!(low < high_early);
+    assert!(high_early < high_late);
+    assert_eq!(core::cmp::max(low, high_late), high_late);
+}
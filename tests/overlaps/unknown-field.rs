@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    #[overlaps(hi)]
+    word: u16,
+    lo: u8,
+    hi: u8,
+}
+
+fn main() {}
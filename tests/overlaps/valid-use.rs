@@ -0,0 +1,33 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    lo: u8,
+    hi: u8,
+    #[overlaps(lo)]
+    word: u16,
+    flags: B4,
+    #[overlaps(flags)]
+    enable: bool,
+    mode: B4,
+    #[overlaps(flags)]
+    control: u8,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Register>(), 3);
+
+    let mut register = Register::new().with_lo(0x34).with_hi(0x12);
+    assert_eq!(register.word(), 0x1234);
+    register.set_word(0xABCD);
+    assert_eq!(register.lo(), 0xCD);
+    assert_eq!(register.hi(), 0xAB);
+
+    register.set_control(0x51);
+    assert!(register.enable());
+    assert_eq!(register.flags(), 0x1);
+    assert_eq!(register.mode(), 0x5);
+    register.set_enable(false);
+    assert_eq!(register.control(), 0x50);
+    assert_eq!(register.into_bytes(), [0xCD, 0xAB, 0x50]);
+}
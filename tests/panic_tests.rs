@@ -37,3 +37,93 @@ fn invalid_access_d() {
     let mut bytes = EdgeCaseBytes::new();
     bytes.set_d(0b0001_0000_u8);
 }
+
+#[test]
+#[should_panic(
+    expected = "value out of bounds for field EdgeCaseBytes.c: \
+                encountered an out of bounds value: 8192 does not fit into 13 bits (max: 8191)"
+)]
+fn invalid_access_reports_value() {
+    let mut bytes = EdgeCaseBytes::new();
+    bytes.set_c(0x2000_u16);
+}
+
+#[test]
+fn invalid_access_checked_reports_value() {
+    let mut bytes = EdgeCaseBytes::new();
+    let details = bytes.set_c_checked(0x2000_u16).unwrap_err().details().unwrap();
+    assert_eq!(details.value, 0x2000);
+    assert_eq!(details.bits, 13);
+    assert_eq!(details.max_value, 0x1FFF);
+}
+
+#[bitfield(check_reserved = true)]
+pub struct Reserved {
+    a: B4,
+    #[skip]
+    reserved: B4,
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "reserved bits of field Reserved.reserved are not zero")]
+fn nonzero_reserved_from_bytes() {
+    Reserved::from_bytes([0b0001_0000]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "reserved bits of field Reserved.reserved are not zero")]
+fn nonzero_reserved_set_bit() {
+    let mut reserved = Reserved::new();
+    reserved.set_bit(7, true);
+}
+
+#[test]
+fn zero_reserved() {
+    let mut reserved = Reserved::from_bytes([0b0000_1010]);
+    reserved.set_a(0b0101);
+    reserved.set_bit(0, false);
+    assert_eq!(reserved.a(), 0b0100);
+}
+
+#[test]
+#[should_panic(expected = "bit index out of bounds for EdgeCaseBytes")]
+fn invalid_bit_index() {
+    let bytes = EdgeCaseBytes::new();
+    bytes.bit(32);
+}
+
+#[test]
+#[should_panic(expected = "bit index out of bounds for EdgeCaseBytes")]
+fn invalid_set_bit_index() {
+    let mut bytes = EdgeCaseBytes::new();
+    bytes.set_bit(32, true);
+}
+
+#[derive(Debug)]
+pub struct NotAMonth(u8);
+
+fn is_month(month: &u8) -> Result<(), NotAMonth> {
+    match (1..=12).contains(month) {
+        true => Ok(()),
+        false => Err(NotAMonth(*month)),
+    }
+}
+
+#[bitfield]
+pub struct Date {
+    #[validate(is_month, error = "NotAMonth")]
+    month: B4,
+    day: B4,
+}
+
+#[test]
+#[should_panic(
+    expected = "invalid value for field Date.month: \
+                encountered a value rejected by the validation function: NotAMonth(13)"
+)]
+fn invalid_validated_value() {
+    let mut date = Date::new();
+    date.set_month(13);
+}
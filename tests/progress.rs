@@ -1,3 +1,4 @@
+mod gen_tests;
 mod panic_tests;
 
 #[cfg(all(test, not(miri)))]
@@ -27,12 +28,99 @@ fn tests() {
     t.compile_fail("tests/26-invalid-struct-specifier.rs");
     t.compile_fail("tests/27-invalid-union-specifier.rs");
     t.pass("tests/28-single-bit-enum.rs");
+    t.pass("tests/29-clear.rs");
+    t.pass("tests/30-setters-from.rs");
+    t.pass("tests/31-bit-accessors.rs");
+    t.pass("tests/32-as-bytes.rs");
+    t.pass("tests/33-byte-array-from-impls.rs");
+    t.pass("tests/34-from-bytes-slice.rs");
+    t.pass("tests/35-bitfield-trait.rs");
+    t.pass("tests/36-endian-bytes.rs");
+    t.pass("tests/37-byte-aligned-fields.rs");
+    t.pass("tests/38-wide-fields.rs");
+    t.pass("tests/39-try-from-bytes.rs");
+    t.pass("tests/40-layout.rs");
+    t.pass("tests/41-field-access-by-name.rs");
+    t.pass("tests/42-bit-cursors.rs");
+    t.pass("tests/43-raw-bit-access.rs");
+    t.pass("tests/44-c-header.rs");
+    t.pass("tests/45-layout-hash.rs");
+    t.pass("tests/46-diff.rs");
+    t.pass("tests/47-packed-array.rs");
+    t.compile_fail("tests/47-packed-array-too-wide.rs");
+    t.pass("tests/48-set-flags.rs");
+    t.pass("tests/49-zero-predicates.rs");
+    t.pass("tests/50-cfg-fields.rs");
+    t.pass("tests/51-narrowed-primitives.rs");
+    t.pass("tests/52-zero-width-fields.rs");
+    t.pass("tests/53-marker-fields.rs");
+    t.pass("tests/54-byte-array-fields.rs");
+    t.compile_fail("tests/69-byte-array-too-large.rs");
+    t.pass("tests/55-exact-width-integers.rs");
+    t.pass("tests/56-unions.rs");
+    t.pass("tests/57-write-if-changed.rs");
+    t.pass("tests/58-const-unfilled-from-bytes.rs");
+    t.pass("tests/59-from-bytes-unchecked.rs");
+    t.pass("tests/60-bitfield-layout.rs");
+    t.compile_fail("tests/61-bitfield-layout-too-many-bits.rs");
+    t.pass("tests/62-dyn-bitfield.rs");
+    t.pass("tests/63-flags.rs");
+    t.pass("tests/64-extern-c-abi.rs");
+    t.pass("tests/65-swap-bytes-reverse-bits.rs");
+    t.pass("tests/66-bits-bytes-consts.rs");
+    t.pass("tests/67-replace-field.rs");
+    t.pass("tests/68-take-field.rs");
+    t.compile_fail("tests/68-take-field-collision.rs");
+
+    // Tests for the `std` crate feature:
+    #[cfg(feature = "std")]
+    t.pass("tests/std-feature/read-write.rs");
+
+    // Tests for the `alloc` crate feature:
+    #[cfg(feature = "alloc")]
+    t.pass("tests/alloc-feature/vec.rs");
+
+    // Tests for the `embedded-io` crate feature:
+    #[cfg(feature = "embedded-io")]
+    t.pass("tests/embedded-io-feature/read-write.rs");
+
+    // Tests for the `critical-section` crate feature:
+    #[cfg(feature = "critical-section")]
+    t.pass("tests/critical-section-feature/modify-cs.rs");
+
+    // Tests for the `ux` crate feature:
+    #[cfg(feature = "ux")]
+    t.pass("tests/ux-feature/specifiers.rs");
+
+    // Tests for the `zerocopy` crate feature:
+    #[cfg(feature = "zerocopy")]
+    t.pass("tests/zerocopy-feature/unaligned.rs");
+
+    // Tests for the `rand` crate feature:
+    #[cfg(feature = "rand")]
+    t.pass("tests/rand-feature/sample.rs");
+
+    // Tests for the `hash32` crate feature:
+    #[cfg(feature = "hash32")]
+    t.pass("tests/hash32-feature/hash.rs");
+
+    // Tests for the `vcell` crate feature:
+    #[cfg(feature = "vcell")]
+    t.pass("tests/vcell-feature/volatile-cell.rs");
+    #[cfg(feature = "vcell")]
+    t.compile_fail("tests/vcell-feature/conflicting-params.rs");
+    #[cfg(feature = "vcell")]
+    t.compile_fail("tests/vcell-feature/invalid-value.rs");
 
     // Tests specific to the `#[derive(BitfieldSpecifier)]` proc. macro:
     t.pass("tests/derive-bitfield-specifier/06-enums.rs");
     t.pass("tests/derive-bitfield-specifier/07-optional-discriminant.rs");
     t.compile_fail("tests/derive-bitfield-specifier/08-non-power-of-two.rs");
     t.compile_fail("tests/derive-bitfield-specifier/09-variant-out-of-range.rs");
+    t.pass("tests/derive-bitfield-specifier/10-match-decoding.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/11-wide-bits.rs");
+    t.pass("tests/derive-bitfield-specifier/12-signed-discriminants.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/13-signed-variant-out-of-range.rs");
 
     // Tests for regressions found in published versions:
     t.pass("tests/regressions/no-implicit-prelude.rs");
@@ -51,6 +139,144 @@ fn tests() {
     t.compile_fail("tests/bytes-param/invalid-int-value.rs");
     t.compile_fail("tests/bytes-param/invalid-type.rs");
 
+    // Tests for `bytes_vis: str` #[bitfield] parameter:
+    t.pass("tests/bytes-vis-param/valid-use.rs");
+    t.compile_fail("tests/bytes-vis-param/duplicate-parameters.rs");
+    t.compile_fail("tests/bytes-vis-param/invalid-value.rs");
+    t.compile_fail("tests/bytes-vis-param/private-bit-accessors.rs");
+
+    // Tests for `bytes_field: str` and `bytes_field_vis: str` #[bitfield] parameters:
+    t.pass("tests/bytes-field-param/valid-use.rs");
+    t.compile_fail("tests/bytes-field-param/duplicate-parameters.rs");
+
+    // Tests for `accessor_trait: str` #[bitfield] parameter:
+    t.pass("tests/accessor-trait-param/valid-use.rs");
+    t.compile_fail("tests/accessor-trait-param/duplicate-parameters.rs");
+
+    // Tests for `default: int` and `default: str` #[bitfield] parameter:
+    t.pass("tests/default-param/valid-use.rs");
+
+    // Tests for `reset: int` and `reset: str` #[bitfield] parameter:
+    t.pass("tests/reset-param/valid-use.rs");
+
+    // Tests for `byte_perm: str` #[bitfield] parameter:
+    t.pass("tests/byte-perm-param/valid-use.rs");
+    t.compile_fail("tests/byte-perm-param/invalid-value.rs");
+
+    // Tests for `unaligned: bool` #[bitfield] parameter:
+    t.pass("tests/unaligned-param/valid-use.rs");
+    t.compile_fail("tests/unaligned-param/duplicate-parameters.rs");
+
+    // Tests for `as_ref: bool` #[bitfield] parameter:
+    t.pass("tests/as-ref-param/valid-use.rs");
+    t.pass("tests/as-ref-param/valid-unfilled.rs");
+    t.compile_fail("tests/as-ref-param/duplicate-parameters.rs");
+
+    // Tests for `bit_ops: bool` #[bitfield] parameter:
+    t.pass("tests/bit-ops-param/valid-use.rs");
+    t.pass("tests/bit-ops-param/valid-unfilled.rs");
+    t.compile_fail("tests/bit-ops-param/duplicate-parameters.rs");
+
+    // Tests for `views: bool` #[bitfield] parameter:
+    t.pass("tests/views-param/valid-use.rs");
+    t.compile_fail("tests/views-param/duplicate-parameters.rs");
+
+    // Tests for `field_errors: bool` #[bitfield] parameter:
+    t.pass("tests/field-errors-param/valid-use.rs");
+    t.compile_fail("tests/field-errors-param/duplicate-parameters.rs");
+
+    // Tests for `gen_tests: bool` #[bitfield] parameter:
+    t.pass("tests/gen-tests-param/valid-use.rs");
+    t.compile_fail("tests/gen-tests-param/duplicate-parameters.rs");
+
+    // Tests for `text: bool` #[bitfield] parameter:
+    t.pass("tests/text-param/valid-use.rs");
+    t.compile_fail("tests/text-param/duplicate-parameters.rs");
+
+    // Tests for `ptr_view: bool` #[bitfield] parameter:
+    t.pass("tests/ptr-view-param/valid-use.rs");
+
+    // Tests for `inline: str` #[bitfield] parameter and `#[inline(..)]` field attribute:
+    t.pass("tests/inline-param/valid-use.rs");
+    t.compile_fail("tests/inline-param/duplicate-parameters.rs");
+    t.compile_fail("tests/inline-param/invalid-value.rs");
+    t.compile_fail("tests/inline-param/duplicate-field-attribute.rs");
+
+    // Tests for `min_code_size: bool` #[bitfield] parameter:
+    t.pass("tests/min-code-size-param/valid-use.rs");
+    t.compile_fail("tests/min-code-size-param/duplicate-parameters.rs");
+
+    // Tests for `accessors: str` #[bitfield] parameter:
+    t.pass("tests/accessors-param/valid-use.rs");
+    t.compile_fail("tests/accessors-param/duplicate-parameters.rs");
+    t.compile_fail("tests/accessors-param/invalid-value.rs");
+
+    // Tests for `debug: str` #[bitfield] parameter:
+    t.pass("tests/debug-param/valid-use.rs");
+    t.compile_fail("tests/debug-param/duplicate-parameters.rs");
+    t.compile_fail("tests/debug-param/invalid-value.rs");
+
+    // Tests for `atomic: bool` #[bitfield] parameter:
+    t.pass("tests/atomic-param/valid-use.rs");
+    t.compile_fail("tests/atomic-param/duplicate-parameters.rs");
+
+    // Tests for `export_c: bool` #[bitfield] parameter:
+    t.pass("tests/export-c-param/valid-use.rs");
+    t.compile_fail("tests/export-c-param/duplicate-parameters.rs");
+    t.compile_fail("tests/export-c-param/invalid-field-type.rs");
+    t.compile_fail("tests/export-c-param/prefix-without-export-c.rs");
+
+    // Tests for `reader_writer: bool` #[bitfield] parameter:
+    t.pass("tests/reader-writer-param/valid-use.rs");
+    t.compile_fail("tests/reader-writer-param/duplicate-parameters.rs");
+
+    // Tests for `no_panic: bool` #[bitfield] parameter:
+    t.pass("tests/no-panic-param/valid-use.rs");
+    t.compile_fail("tests/no-panic-param/duplicate-parameters.rs");
+    t.compile_fail("tests/no-panic-param/conflicting-params.rs");
+
+    // Tests for `validated: bool` #[bitfield] parameter:
+    t.pass("tests/validated-param/valid-use.rs");
+    t.compile_fail("tests/validated-param/duplicate-parameters.rs");
+
+    // Tests for `check_reserved: bool` #[bitfield] parameter:
+    t.pass("tests/check-reserved-param/valid-use.rs");
+    t.compile_fail("tests/check-reserved-param/duplicate-parameters.rs");
+
+    // Tests for `reflect: bool` #[bitfield] parameter:
+    t.pass("tests/reflect-param/valid-use.rs");
+    t.pass("tests/reflect-param/skipped-field.rs");
+    t.pass("tests/reflect-param/invalid-bit-pattern.rs");
+    t.compile_fail("tests/reflect-param/restricted-bytes.rs");
+    t.compile_fail("tests/reflect-param/duplicate-parameters.rs");
+
+    // Tests for `layout_docs: bool` #[bitfield] parameter:
+    t.pass("tests/layout-docs-param/valid-use.rs");
+    t.compile_fail("tests/layout-docs-param/duplicate-parameters.rs");
+
+    // Tests for `reinterpret: str` #[bitfield] parameter:
+    t.pass("tests/reinterpret-param/valid-use.rs");
+    t.compile_fail("tests/reinterpret-param/duplicate-parameters.rs");
+    t.compile_fail("tests/reinterpret-param/conflicting-filled.rs");
+
+    // Tests for `migrate_from: str` #[bitfield] parameter:
+    t.pass("tests/migrate-from-param/valid-use.rs");
+    t.compile_fail("tests/migrate-from-param/duplicate-parameters.rs");
+    t.compile_fail("tests/migrate-from-param/narrowed-field.rs");
+
+    // Tests for `impl_trait: str` #[bitfield] parameter:
+    t.pass("tests/impl-trait-param/valid-use.rs");
+    t.compile_fail("tests/impl-trait-param/duplicate-parameters.rs");
+
+    // Tests for `ord_by: str` #[bitfield] parameter:
+    t.pass("tests/ord-by-param/valid-use.rs");
+    t.compile_fail("tests/ord-by-param/unknown-field.rs");
+    t.compile_fail("tests/ord-by-param/duplicate-parameters.rs");
+
+    // Tests for the `svd_registers!` macro:
+    t.pass("tests/svd-registers/valid-use.rs");
+    t.compile_fail("tests/svd-registers/overlapping-fields.rs");
+
     // Tests for `filled: bool` #[bitfield] parameter:
     t.pass("tests/filled-param/valid-bitfield-1.rs");
     t.pass("tests/filled-param/valid-bitfield-2.rs");
@@ -67,6 +293,9 @@ fn tests() {
     t.pass("tests/repr/complex-use.rs");
     t.pass("tests/repr/multiple-valid-reprs-1.rs");
     t.pass("tests/repr/multiple-valid-reprs-2.rs");
+    t.pass("tests/repr/bit-counting.rs");
+    t.pass("tests/repr/oversized.rs");
+    t.pass("tests/repr/invalid-repr-unfilled.rs");
     t.compile_fail("tests/repr/duplicate-repr-1.rs");
     t.compile_fail("tests/repr/duplicate-repr-2.rs");
     t.compile_fail("tests/repr/duplicate-repr-3.rs");
@@ -75,7 +304,6 @@ fn tests() {
     t.compile_fail("tests/repr/invalid-repr-width-1.rs");
     t.compile_fail("tests/repr/invalid-repr-width-2.rs");
     t.compile_fail("tests/repr/conflicting-ignored-reprs.rs");
-    t.compile_fail("tests/repr/invalid-repr-unfilled.rs");
 
     // Tests for `#[derive(Debug)]`:
     t.pass("tests/derive-debug/valid-use.rs");
@@ -93,6 +321,7 @@ fn tests() {
     t.pass("tests/skip/skip-with-debug.rs");
     t.pass("tests/skip/double_wildcards-1.rs");
     t.pass("tests/skip/double_wildcards-2.rs");
+    t.pass("tests/skip/implicit_wildcards.rs");
     t.pass("tests/skip/skip-getters.rs");
     t.pass("tests/skip/skip-setters.rs");
     t.compile_fail("tests/skip/invalid-specifier.rs");
@@ -111,6 +340,42 @@ fn tests() {
     t.compile_fail("tests/skip/duplicate-setters-2.rs");
     t.compile_fail("tests/skip/duplicate-setters-3.rs");
 
+    // Tests for `#[access(..)]`:
+    t.pass("tests/access/valid-use.rs");
+    t.compile_fail("tests/access/invalid-specifier.rs");
+    t.compile_fail("tests/access/duplicate-attr.rs");
+
+    // Tests for `#[validate(..)]`:
+    t.pass("tests/validate/valid-use.rs");
+    t.compile_fail("tests/validate/invalid-format.rs");
+
+    // Tests for `#[on_overflow = ".."]`:
+    t.pass("tests/on-overflow/valid-use.rs");
+    t.compile_fail("tests/on-overflow/invalid-value.rs");
+    t.compile_fail("tests/on-overflow/non-integer-field.rs");
+    t.compile_fail("tests/on-overflow/bool-field.rs");
+
+    // Tests for `#[overlaps(..)]`:
+    t.pass("tests/overlaps/valid-use.rs");
+    t.compile_fail("tests/overlaps/unknown-field.rs");
+
+    // Tests for `#[variant(..)]`:
+    t.pass("tests/variant/valid-use.rs");
+    t.compile_fail("tests/variant/unknown-tag.rs");
+
+    // Tests for `#[flatten(..)]`:
+    t.pass("tests/flatten/valid-use.rs");
+    t.compile_fail("tests/flatten/invalid-format.rs");
+
+    // Tests for `#[flags]`:
+    t.pass("tests/flags/valid-use.rs");
+    t.compile_fail("tests/flags/not-adjacent.rs");
+
+    // Tests for `#[group = ".."]`:
+    t.pass("tests/group/valid-use.rs");
+    t.pass("tests/group/read-group.rs");
+    t.compile_fail("tests/group/invalid-name.rs");
+
     // Tests for `#[derive(BitfieldSpecifier)] using `#[bitfield]`:
     t.pass("tests/derive-specifier/valid-use.rs");
     t.pass("tests/derive-specifier/struct-in-struct.rs");
@@ -128,8 +393,10 @@ fn tests() {
     t.pass("tests/bits-param/bits-non-filled-2.rs");
     t.pass("tests/bits-param/low-bits-filled.rs");
     t.pass("tests/bits-param/complex-use-case.rs");
+    t.pass("tests/bits-param/oversized-repr.rs");
     t.compile_fail("tests/bits-param/conflicting-params.rs");
     t.compile_fail("tests/bits-param/conflicting-repr.rs");
+    t.compile_fail("tests/bits-param/too-small-repr.rs");
     t.compile_fail("tests/bits-param/duplicate-param-1.rs");
     t.compile_fail("tests/bits-param/duplicate-param-2.rs");
     t.compile_fail("tests/bits-param/invalid-param-value-1.rs");
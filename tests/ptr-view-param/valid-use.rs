@@ -0,0 +1,42 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(ptr_view = true)]
+pub struct Status {
+    ready: bool,
+    #[skip(setters)]
+    error: bool,
+    #[skip]
+    __: B6,
+    counter: B12,
+    mode: B4,
+}
+
+fn main() {
+    let mut register = [0b0000_0010_u8, 0x00, 0x00];
+    // SAFETY: `register` outlives `status` and is valid for reads and writes.
+    let status = unsafe { StatusPtr::from_ptr(register.as_mut_ptr()) };
+    assert!(!status.ready());
+    assert!(status.error());
+
+    status.set_counter(0xABC);
+    status.set_mode(0x5);
+    assert_eq!(status.counter(), 0xABC);
+    assert_eq!(status.mode(), 0x5);
+    assert!(status.set_mode_checked(0x10).is_err());
+
+    status.modify(|status| status.with_ready(true));
+    assert_eq!(status.read().into_bytes(), [0b0000_0011, 0xBC, 0x5A]);
+
+    status.write(Status::new());
+    assert_eq!(register, [0x00; 3]);
+
+    // Write-1-to-clear bits of other fields within the written bytes are written as zeros.
+    register = [0b0000_0011, 0xFF, 0xFF];
+    // SAFETY: `register` outlives `status` and is valid for reads and writes.
+    let status = unsafe { StatusPtr::from_ptr(register.as_mut_ptr()) };
+    status.write_mode(0x3);
+    assert_eq!(register, [0b0000_0011, 0xFF, 0x30]);
+    status.write_ready(false);
+    assert_eq!(register, [0b0000_0000, 0xFF, 0x30]);
+}
+
@@ -0,0 +1,74 @@
+use modular_bitfield::prelude::*;
+use rand::{
+    Error,
+    RngCore,
+};
+
+/// A xorshift generator so that the test does not depend on `rand`'s own generators.
+struct XorShift(u64);
+
+impl RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.next_u64() as u8;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield]
+pub struct Register {
+    enabled: bool,
+    mode: Mode,
+    #[skip]
+    __: B5,
+    level: B8,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    a: B3,
+    b: bool,
+}
+
+fn main() {
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    let mut seen = [false; 3];
+    let mut levels = 0u32;
+    for _ in 0..1000 {
+        let register = Register::sample(&mut rng);
+        // Only valid enum variants are sampled and reserved bits are zero.
+        let mode = register.mode_or_err().unwrap();
+        seen[mode as usize] = true;
+        levels |= 1 << (register.level() % 32);
+        assert_eq!(register.into_bytes()[0] & 0b1111_1000, 0);
+
+        let unfilled = Unfilled::sample(&mut rng);
+        assert_eq!(unfilled.into_bytes()[0] & 0xF0, 0);
+    }
+    assert_eq!(seen, [true; 3]);
+    assert_eq!(levels, u32::MAX);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(reader_writer = true, reader_writer = false)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(reader_writer = true)]
+pub struct Control {
+    enable: bool,
+    #[access(w1c)]
+    overflow: bool,
+    #[access(read_only)]
+    busy: bool,
+    #[skip]
+    __: B1,
+    prescaler: B4,
+}
+
+fn main() {
+    let mut control = Control::new();
+    control.write(|w| w.set_enable(true).set_prescaler(0xA));
+    assert!(control.read().enable());
+    assert_eq!(control.read().prescaler(), 0xA);
+    assert_eq!(control.as_bytes(), &[0b1010_0001]);
+
+    // `write` starts from all bits set to zero.
+    control.write(|w| w.set_prescaler(0x3));
+    assert!(!control.read().enable());
+    assert_eq!(control.as_bytes(), &[0b0011_0000]);
+
+    control.modify(|r, w| w.set_prescaler(r.prescaler() + 1).set_enable(!r.enable()));
+    assert_eq!(control.as_bytes(), &[0b0100_0001]);
+
+    let mut control = Control::from_bytes([0b0000_0110]);
+    assert!(control.read().overflow());
+    assert!(control.read().busy());
+    control.modify(|_, w| w.clear_overflow());
+    assert_eq!(control.as_bytes(), &[0b0000_0110]);
+
+    control.modify(|_, w| w.reset().set_enable(true));
+    assert_eq!(control.read().bits().as_bytes(), &[0b0000_0001]);
+
+    let mut out_of_bounds = false;
+    control.write(|w| {
+        out_of_bounds = w.set_prescaler_checked(0x10).is_err();
+        w
+    });
+    assert!(out_of_bounds);
+}
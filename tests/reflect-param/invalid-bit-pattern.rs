@@ -0,0 +1,28 @@
+use modular_bitfield::{
+    error::FieldAccessError,
+    layout::Reflect,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield]
+pub struct Header {
+    mode: Mode,
+    #[skip]
+    __: B6,
+}
+
+fn main() {
+    let mut header = Header::new();
+    assert_eq!(header.set_field("mode", 2), Ok(()));
+    assert_eq!(header.mode(), Mode::Fast);
+    assert_eq!(header.set_field("mode", 3), Err(FieldAccessError::InvalidBitPattern));
+    assert_eq!(header.mode(), Mode::Fast);
+}
@@ -0,0 +1,16 @@
+use modular_bitfield::layout::Reflect;
+
+mod status {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(bytes_vis = "pub(crate)")]
+    pub struct Status {
+        pub ready: bool,
+        pub level: B7,
+    }
+}
+
+fn main() {
+    let mut status = status::Status::new();
+    status.set_field("level", 100).unwrap();
+}
@@ -0,0 +1,30 @@
+use modular_bitfield::{
+    error::FieldAccessError,
+    layout::Reflect,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Header {
+    is_compact: bool,
+    #[skip]
+    reserved: B3,
+    #[skip(setters)]
+    version: B4,
+}
+
+fn main() {
+    let mut header = Header::from_bytes([0xFF]);
+    assert_eq!(header.get_field("reserved"), None);
+    assert_eq!(header.set_field("reserved", 0), Err(FieldAccessError::UnknownField));
+    assert_eq!(header.as_bytes(), &[0xFF]);
+
+    // Fields with a getter or setter are not skipped entirely.
+    assert_eq!(header.get_field("version"), Some(0xF));
+    assert_eq!(header.set_field("version", 1), Ok(()));
+    assert_eq!(header.version(), 1);
+    assert_eq!(
+        header.fields().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["is_compact", "version"]
+    );
+}
@@ -0,0 +1,45 @@
+use modular_bitfield::{
+    error::FieldAccessError,
+    layout::Reflect,
+    prelude::*,
+};
+
+mod status {
+    use modular_bitfield::prelude::*;
+
+    // The byte conversions are private but `reflect = true` opts into by-name access.
+    #[bitfield(bytes_vis = "", reflect = true)]
+    pub struct Status {
+        pub ready: bool,
+        pub level: B7,
+    }
+}
+
+// Public byte conversions provide by-name access by default.
+#[bitfield]
+pub struct Header {
+    is_compact: bool,
+    length: B7,
+}
+
+// `reflect = false` opts out of it.
+#[bitfield(reflect = false)]
+pub struct Opaque {
+    is_compact: bool,
+    length: B7,
+}
+
+fn main() {
+    let mut status = status::Status::new();
+    assert_eq!(status.set_field("level", 100), Ok(()));
+    assert_eq!(status.level(), 100);
+    assert_eq!(status.get_field("level"), Some(100));
+    assert_eq!(status.set_field("bytes", 0), Err(FieldAccessError::UnknownField));
+
+    let mut header = Header::new();
+    assert_eq!(header.set_field("length", 42), Ok(()));
+    assert_eq!(header.length(), 42);
+
+    let opaque = Opaque::new().with_length(1);
+    assert_eq!(opaque.length(), 1);
+}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Other {
+    a: B8,
+}
+
+#[bitfield(reinterpret = "Other", filled = false)]
+pub struct Base {
+    a: B7,
+}
+
+fn main() {}
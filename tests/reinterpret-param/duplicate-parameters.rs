@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Other {
+    a: u8,
+}
+
+#[bitfield(reinterpret = "Other", reinterpret = "Other")]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
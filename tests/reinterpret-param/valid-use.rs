@@ -0,0 +1,47 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(reinterpret = "Write")]
+pub struct Read {
+    ready: bool,
+    error: bool,
+    count: B14,
+}
+
+#[bitfield(reinterpret = "Write")]
+pub struct Debug {
+    raw: u16,
+}
+
+#[bitfield(reinterpret = "Read, Debug, Partial")]
+pub struct Write {
+    start: bool,
+    reset: bool,
+    limit: B14,
+}
+
+#[bitfield(filled = false)]
+pub struct Partial {
+    start: bool,
+    #[skip]
+    __: B1,
+    limit: B10,
+}
+
+fn main() {
+    let write = Write::new().with_start(true).with_limit(0x3FFF);
+    let read = Read::from(write);
+    assert!(read.ready());
+    assert!(!read.error());
+    assert_eq!(read.count(), 0x3FFF);
+    let write = Write::from(read);
+    assert!(write.start());
+    assert!(!write.reset());
+    let debug = Debug::from(write);
+    assert_eq!(debug.raw(), 0xFFFD);
+    assert_eq!(Write::from(debug).limit(), 0x3FFF);
+
+    let partial = Partial::new().with_start(true).with_limit(0x155);
+    let write = Write::from(partial);
+    assert!(write.start());
+    assert_eq!(write.limit(), 0x155);
+}
@@ -0,0 +1,23 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u32)]
+pub struct Pending {
+    low: B8,
+    mid: B16,
+    high: B8,
+}
+
+fn main() {
+    let pending = Pending::new();
+    assert_eq!(pending.count_ones(), 0);
+    assert_eq!(pending.count_zeros(), 32);
+    assert_eq!(pending.leading_zeros(), 32);
+    assert_eq!(pending.trailing_zeros(), 32);
+
+    let pending = Pending::new().with_low(0b1000_0000).with_high(0b0000_0110);
+    assert_eq!(pending.count_ones(), 3);
+    assert_eq!(pending.count_zeros(), 29);
+    assert_eq!(pending.leading_zeros(), 5);
+    assert_eq!(pending.trailing_zeros(), 7);
+}
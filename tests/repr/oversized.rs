@@ -0,0 +1,38 @@
+use core::convert::TryFrom;
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    opcode: B4,
+    operand: B16,
+}
+
+#[bitfield]
+#[repr(u64)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Header {
+    kind: B8,
+    len: B32,
+}
+
+fn main() {
+    let instr = Instruction::new().with_opcode(0b1010).with_operand(0x8001);
+    assert_eq!(u32::from(instr), 0x8001_a);
+    assert_eq!(Instruction::try_from(0x8001_a_u32), Ok(instr));
+    assert!(Instruction::try_from(1_u32 << 20).is_err());
+    assert_eq!(instr.count_ones(), 4);
+    assert_eq!(instr.count_zeros(), 16);
+    assert_eq!(instr.leading_zeros(), 0);
+    assert_eq!(instr.trailing_zeros(), 1);
+    assert_eq!(Instruction::new().leading_zeros(), 20);
+    assert_eq!(Instruction::new().trailing_zeros(), 20);
+
+    let header = Header::new().with_kind(0xff).with_len(1);
+    assert_eq!(u64::from(header), 0x0000_01ff);
+    assert_eq!(Header::try_from(0x0000_01ff_u64), Ok(header));
+    assert!(Header::try_from(u64::MAX).is_err());
+    assert_eq!(header.count_zeros(), 31);
+    assert_eq!(header.leading_zeros(), 31);
+}
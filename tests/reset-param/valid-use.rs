@@ -0,0 +1,34 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(reset = 0x1F)]
+#[derive(Debug)]
+pub struct Status {
+    flags: B5,
+    count: B3,
+}
+
+#[bitfield(reset = "[0x00, 0x80]", default = 0x01)]
+pub struct Both {
+    a: B8,
+    b: B8,
+}
+
+const POWER_ON: Status = Status::RESET;
+
+fn main() {
+    assert_eq!(POWER_ON.into_bytes(), [0x1F]);
+
+    let mut status = Status::new();
+    assert_eq!(status.as_bytes(), &[0x00]);
+    status.set_count(0b101);
+    status.reset();
+    assert_eq!(status.flags(), 0x1F);
+    assert_eq!(status.count(), 0);
+
+    let mut both = Both::new();
+    assert_eq!(both.a(), 0x01);
+    assert_eq!(both.b(), 0x00);
+    both.reset();
+    assert_eq!(both.a(), 0x00);
+    assert_eq!(both.b(), 0x80);
+}
@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sparse {
+    __: B10,
+    a: bool,
+    __: B10,
+    b: bool,
+    ___: B10,
+}
+
+fn main() {
+    let sparse = Sparse::new().with_a(true).with_b(true);
+    assert!(sparse.a());
+    assert!(sparse.b());
+    assert_eq!(sparse.into_bytes(), [0x00, 0b0000_0100, 0b0010_0000, 0x00]);
+}
@@ -0,0 +1,42 @@
+// Tests the `std::io` helpers enabled by the `std` crate feature.
+
+use modular_bitfield::{
+    prelude::*,
+    std_io::BitfieldIo as _,
+};
+use std::io;
+
+#[bitfield]
+pub struct MyTwoBytes {
+    a: bool,
+    b: B15,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    a: bool,
+    b: B6,
+}
+
+fn main() {
+    let mut buffer = Vec::new();
+    MyTwoBytes::new().with_a(true).with_b(2).write_to(&mut buffer).unwrap();
+    Unfilled::new().with_b(3).write_to(&mut buffer).unwrap();
+    assert_eq!(buffer, [0b0000_0101, 0x00, 0b0000_0110]);
+
+    let mut reader = &buffer[..];
+    let bitfield = MyTwoBytes::read_from(&mut reader).unwrap();
+    assert!(bitfield.a());
+    assert_eq!(bitfield.b(), 2);
+    let unfilled = Unfilled::read_from(&mut reader).unwrap();
+    assert!(!unfilled.a());
+    assert_eq!(unfilled.b(), 3);
+
+    // The reader has run out of bytes.
+    let error = MyTwoBytes::read_from(&mut reader).err().unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+    // The padding bit of `Unfilled` is set.
+    let error = Unfilled::read_from(&mut &[0b1000_0000][..]).err().unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
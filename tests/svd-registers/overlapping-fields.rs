@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+svd_registers!(
+    "<register><name>CR</name><fields>
+        <field><name>A</name><bitRange>[3:0]</bitRange></field>
+        <field><name>B</name><bitRange>[4:3]</bitRange></field>
+    </fields></register>"
+);
+
+fn main() {}
@@ -0,0 +1,63 @@
+use modular_bitfield::prelude::*;
+
+svd_registers!(
+    r#"
+    <registers>
+        <!-- <register><name>IGNORED</name></register> -->
+        <register>
+            <name>DMA_CR</name>
+            <description>DMA control register</description>
+            <fields>
+                <field>
+                    <name>EN</name>
+                    <description>Channel enable</description>
+                    <bitOffset>0</bitOffset>
+                    <bitWidth>1</bitWidth>
+                </field>
+                <field>
+                    <name>TYPE</name>
+                    <bitOffset>0x4</bitOffset>
+                    <bitWidth>#11</bitWidth>
+                    <enumeratedValues>
+                        <name>TransferType</name>
+                        <enumeratedValue><name>Single</name><value>0</value></enumeratedValue>
+                    </enumeratedValues>
+                </field>
+                <field>
+                    <name>PRIO</name>
+                    <lsb>8</lsb>
+                    <msb>11</msb>
+                    <access>read-write</access>
+                </field>
+                <field>
+                    <name>BUSY</name>
+                    <bitRange>[31:31]</bitRange>
+                    <access>read-only</access>
+                </field>
+                <field>
+                    <name>CLEAR</name>
+                    <bitRange>[16:16]</bitRange>
+                    <access>write-only</access>
+                </field>
+            </fields>
+        </register>
+        <register>
+            <name>STATUS</name>
+            <size>8</size>
+        </register>
+    </registers>
+    "#
+);
+
+fn main() {
+    let mut cr = DmaCr::new().with_en(true).with_type(0b101).with_prio(0xA);
+    cr.set_clear(true);
+    assert_eq!(cr.into_bytes(), [0b0101_0001, 0x0A, 0x01, 0x00]);
+    assert!(!cr.busy());
+
+    let cr = DmaCr::from_bytes([0x00, 0x00, 0x00, 0x80]);
+    assert!(cr.busy());
+    assert_eq!(cr, DmaCr::from_bytes([0x00, 0x00, 0x00, 0x80]));
+
+    assert_eq!(Status::new().into_bytes(), [0x00]);
+}
@@ -0,0 +1,59 @@
+use modular_bitfield::{
+    error::ParseTextError,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield(text = true)]
+#[derive(Debug, PartialEq)]
+pub struct Header {
+    ready: bool,
+    mode: Mode,
+    #[skip]
+    __: B5,
+    length: B16,
+}
+
+#[bitfield(filled = false, text = true)]
+#[derive(Debug, PartialEq)]
+pub struct Unfilled {
+    a: B3,
+    b: bool,
+}
+
+fn main() {
+    let header = Header::new()
+        .with_ready(true)
+        .with_mode(Mode::Fast)
+        .with_length(1234);
+    let text = header.to_string();
+    assert_eq!(text, "ready=true,mode=2,length=1234");
+    assert_eq!(text.parse::<Header>(), Ok(header));
+
+    // Pairs may come in any order, values may be hexadecimal and missing fields are zero.
+    assert_eq!(
+        " length = 0xFF ,mode=1".parse::<Header>(),
+        Ok(Header::new().with_mode(Mode::Slow).with_length(0xFF))
+    );
+    assert_eq!("".parse::<Header>(), Ok(Header::new()));
+
+    assert_eq!("ready".parse::<Header>(), Err(ParseTextError::MissingValue));
+    assert_eq!("flag=1".parse::<Header>(), Err(ParseTextError::UnknownField));
+    assert_eq!("__=1".parse::<Header>(), Err(ParseTextError::UnknownField));
+    assert_eq!("ready=1".parse::<Header>(), Err(ParseTextError::InvalidValue));
+    assert!(matches!(
+        "length=65536".parse::<Header>(),
+        Err(ParseTextError::OutOfBounds(_))
+    ));
+
+    let unfilled = Unfilled::new().with_a(5);
+    assert_eq!(unfilled.to_string(), "a=5,b=false");
+    assert_eq!("a=5".parse::<Unfilled>(), Ok(unfilled));
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(unaligned = true, unaligned = false)]
+pub struct SignInteger {
+    sign: bool,
+    value: B7,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(unaligned = true)]
+#[derive(Debug, Copy, Clone)]
+pub struct Header {
+    version: B4,
+    kind: B4,
+    length: u16,
+}
+
+fn main() {
+    assert_eq!(core::mem::align_of::<Header>(), 1);
+
+    let buffer = [0xFF, 0x21, 0x34, 0x12, 0xFF];
+    let mut bytes = [0u8; 3];
+    bytes.copy_from_slice(&buffer[1..4]);
+    let header = Header::from_bytes(bytes);
+    assert_eq!(header.version(), 1);
+    assert_eq!(header.kind(), 2);
+    assert_eq!(header.length(), 0x1234);
+}
@@ -0,0 +1,29 @@
+use modular_bitfield::prelude::*;
+use ux::{
+    i5,
+    u3,
+    u24,
+};
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Sample {
+    channel: u3,
+    offset: i5,
+    value: u24,
+}
+
+fn main() {
+    let sample = Sample::new()
+        .with_channel(u3::new(5))
+        .with_offset(i5::new(-3))
+        .with_value(u24::new(0xAB_CDEF));
+    assert_eq!(sample.channel(), u3::new(5));
+    assert_eq!(sample.offset(), i5::new(-3));
+    assert_eq!(sample.value(), u24::new(0xAB_CDEF));
+    assert_eq!(sample.as_bytes(), &[0b1110_1101, 0xEF, 0xCD, 0xAB]);
+
+    let sample = Sample::new().with_offset(i5::MIN);
+    assert_eq!(sample.offset(), i5::new(-16));
+    assert_eq!(<i5 as Specifier>::BITS, 5);
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Date {
+    #[validate("is_month")]
+    month: B4,
+    day: B4,
+}
+
+fn main() {}
@@ -0,0 +1,61 @@
+use modular_bitfield::{
+    error::{
+        DecodeError,
+        OutOfBounds,
+        ValidateError,
+    },
+    prelude::*,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct NotAMonth(u8);
+
+fn is_month(month: &u8) -> Result<(), NotAMonth> {
+    match (1..=12).contains(month) {
+        true => Ok(()),
+        false => Err(NotAMonth(*month)),
+    }
+}
+
+mod checks {
+    pub fn is_even(value: &u8) -> Result<(), ()> {
+        match value % 2 {
+            0 => Ok(()),
+            _ => Err(()),
+        }
+    }
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Date {
+    #[validate(is_month, error = "NotAMonth")]
+    month: B4,
+    #[validate(checks::is_even)]
+    even: B4,
+}
+
+fn main() {
+    let mut date = Date::new();
+    assert_eq!(date.set_month_checked(12), Ok(()));
+    assert_eq!(date.month(), 12);
+    assert_eq!(date.set_month_checked(0), Err(ValidateError::Invalid(NotAMonth(0))));
+    assert_eq!(date.set_month_checked(13), Err(ValidateError::Invalid(NotAMonth(13))));
+    assert_eq!(date.month(), 12);
+    assert_eq!(Date::new().with_even_checked(3).unwrap_err(), ValidateError::Invalid(()));
+    assert_eq!(
+        Date::new().with_even_checked(18).unwrap_err(),
+        ValidateError::OutOfBounds(OutOfBounds::with_value(18, 4)),
+    );
+    assert_eq!(Date::new().with_even_checked(4).unwrap().even(), 4);
+
+    assert!(Date::try_from_bytes([0x2C]).is_ok());
+    assert_eq!(
+        Date::try_from_bytes([0x20]).unwrap_err(),
+        DecodeError::InvalidValue { field: "month" },
+    );
+    assert_eq!(
+        Date::try_from_bytes([0x3C]).unwrap_err(),
+        DecodeError::InvalidValue { field: "even" },
+    );
+}
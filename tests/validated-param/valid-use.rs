@@ -0,0 +1,54 @@
+use modular_bitfield::{
+    error::DecodeError,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq)]
+#[bits = 2]
+pub enum Status {
+    Red,
+    Green,
+    Yellow,
+}
+
+#[bitfield(validated = true)]
+pub struct Package {
+    status: Status,
+    contents: B5,
+    #[skip(getters)]
+    is_alive: bool,
+}
+
+#[bitfield(filled = false, validated = true)]
+pub struct Unfilled {
+    status: Status,
+    contents: B4,
+}
+
+fn main() {
+    let valid = Package::new()
+        .with_status(Status::Yellow)
+        .with_contents(0b1_0101)
+        .validated()
+        .unwrap();
+    assert_eq!(valid.status(), Status::Yellow);
+    assert_eq!(valid.contents(), 0b1_0101);
+    assert_eq!(valid.as_inner().status(), Status::Yellow);
+
+    let package: Package = valid.into();
+    assert_eq!(package.contents(), 0b1_0101);
+    let package = Package::from_bytes([0b1000_0011]);
+    assert_eq!(
+        package.validated().err(),
+        Some(DecodeError::InvalidField {
+            field: "status",
+            invalid_bits: 0b11,
+        }),
+    );
+
+    assert!(Unfilled::from_bytes([0b0000_0110]).unwrap().validated().is_ok());
+    assert_eq!(
+        Unfilled::new().with_contents(3).validated().unwrap().into_inner().contents(),
+        3
+    );
+}
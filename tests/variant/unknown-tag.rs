@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Instruction {
+    #[variant(tag = "opcode", 0 => B4, 1 => B8)]
+    operands: B12,
+    kind: B4,
+}
+
+fn main() {}
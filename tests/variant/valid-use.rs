@@ -0,0 +1,53 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 12)]
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub struct Immediate {
+    rd: B4,
+    imm: B8,
+}
+
+#[bitfield(bits = 8)]
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub struct Jump {
+    offset: B8,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Kind {
+    Immediate,
+    Jump,
+    Halt,
+}
+
+#[bitfield]
+pub struct Instruction {
+    #[variant(tag = "kind", 0 => Immediate, 1 => Jump, 2 => B12)]
+    operands: B12,
+    #[skip]
+    __: B2,
+    kind: Kind,
+}
+
+fn main() {
+    let mut instruction = Instruction::new();
+    assert_eq!(instruction.immediate(), Some(Immediate::new()));
+    assert_eq!(instruction.jump(), None);
+
+    instruction.set_immediate(Immediate::new().with_rd(0xA).with_imm(0x42));
+    assert_eq!(instruction.kind(), Kind::Immediate);
+    assert_eq!(instruction.operands(), 0x42A);
+
+    instruction.set_jump(Jump::new().with_offset(0x7F));
+    assert_eq!(instruction.kind(), Kind::Jump);
+    assert_eq!(instruction.immediate(), None);
+    assert_eq!(instruction.jump().map(|jump| jump.offset()), Some(0x7F));
+    // Bits of the field not covered by the variant are cleared.
+    assert_eq!(instruction.operands(), 0x07F);
+
+    instruction.set_b12(0xFFF);
+    assert_eq!(instruction.kind(), Kind::Halt);
+    assert_eq!(instruction.b12(), Some(0xFFF));
+    assert_eq!(instruction.jump(), None);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(cell = "volatile", views = true)]
+pub struct Control {
+    enable: bool,
+    prescaler: B7,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(cell = "atomic")]
+pub struct Control {
+    enable: bool,
+    prescaler: B7,
+}
+
+fn main() {}
@@ -0,0 +1,65 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(cell = "volatile")]
+#[derive(Debug)]
+pub struct Control {
+    enable: bool,
+    #[access(w1c)]
+    overflow: bool,
+    #[access(read_only)]
+    busy: bool,
+    #[skip]
+    __: B6,
+    prescaler: B7,
+}
+
+#[bitfield(cell = "volatile", filled = false)]
+pub struct Status {
+    ready: bool,
+    code: B6,
+}
+
+/// A peripheral register block using the bitfields directly.
+#[repr(C)]
+pub struct RegisterBlock {
+    control: Control,
+    status: Status,
+}
+
+fn main() {
+    let block = RegisterBlock {
+        control: Control::new(),
+        status: Status::new(),
+    };
+    assert_eq!(core::mem::size_of::<RegisterBlock>(), 3);
+
+    // All accessors take `&self`.
+    let control = &block.control;
+    control.set_enable(true);
+    control.set_prescaler(0x55);
+    assert!(control.enable());
+    assert_eq!(control.prescaler(), 0x55);
+    assert!(control.set_prescaler_checked(0x80).is_err());
+    assert_eq!(control.get_bytes(), [0b0000_0001, 0xAA]);
+
+    control.set_bytes([0b0000_0110, 0x00]);
+    assert!(control.overflow());
+    assert!(control.busy());
+    control.clear_overflow();
+    assert_eq!(control.get_bytes(), [0b0000_0110, 0x00]);
+    assert_eq!(
+        format!("{:?}", control),
+        "Control { enable: false, overflow: true, busy: true, prescaler: 0 }",
+    );
+
+    let status = &block.status;
+    status.set_code(42);
+    assert_eq!(status.code(), 42);
+    assert!(status.set_bytes([0b1000_0000]).is_err());
+    assert!(status.set_bytes([0b0000_0001]).is_ok());
+    assert!(status.ready());
+    assert!(Status::from_bytes([0xFF]).is_err());
+
+    control.clear();
+    assert_eq!(block.control.into_bytes(), [0x00; 2]);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(views = true, views = true)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
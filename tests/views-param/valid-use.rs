@@ -0,0 +1,39 @@
+use core::convert::TryFrom;
+use modular_bitfield::prelude::*;
+
+#[bitfield(views = true)]
+pub struct Header {
+    is_compact: bool,
+    is_secure: bool,
+    status: B6,
+    length: u8,
+}
+
+fn main() {
+    let mut packets = [0u8; 6];
+
+    // Write the second record in place.
+    {
+        let record: &mut [u8; 2] = <&mut [u8; 2]>::try_from(&mut packets[2..4]).unwrap();
+        let mut view = HeaderViewMut::new(record);
+        view.set_is_secure(true);
+        view.set_status(5);
+        view.set_length(42);
+        assert!(view.is_secure());
+        assert_eq!(view.as_view().length(), 42);
+    }
+    assert_eq!(packets, [0x00, 0x00, 0b0001_0110, 42, 0x00, 0x00]);
+
+    // Read the second record in place.
+    let record: &[u8; 2] = <&[u8; 2]>::try_from(&packets[2..4]).unwrap();
+    let view = HeaderView::new(record);
+    assert!(!view.is_compact());
+    assert!(view.is_secure());
+    assert_eq!(view.status(), 5);
+    assert_eq!(view.length(), 42);
+    assert_eq!(view.as_bytes(), &[0b0001_0110, 42]);
+
+    // The owned bitfield agrees with the view.
+    let header = Header::from_bytes(*record);
+    assert_eq!(header.status(), view.status());
+}
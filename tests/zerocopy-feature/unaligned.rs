@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+use zerocopy::Unaligned;
+
+#[bitfield(unaligned = true)]
+pub struct Header {
+    version: B4,
+    kind: B4,
+    length: u16,
+}
+
+fn assert_unaligned<T: Unaligned>() {}
+
+fn main() {
+    assert_unaligned::<Header>();
+}